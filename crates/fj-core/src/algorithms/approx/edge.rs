@@ -10,7 +10,7 @@ use std::collections::BTreeMap;
 use fj_math::Point;
 
 use crate::{
-    geometry::{CurveBoundary, GlobalPath, SurfacePath},
+    geometry::{CurveBoundary, GeometryContext, GlobalPath, SurfacePath},
     objects::{Curve, Edge, Surface, Vertex},
     storage::{Handle, HandleWrapper},
 };
@@ -39,6 +39,7 @@ impl Approx for (&Edge, &Surface) {
                     let position_global = surface
                         .geometry()
                         .point_from_surface_coords(start_position_surface);
+                    cache.count_surface_point_conversions(1);
                     cache.insert_start_position_approx(
                         edge.start_vertex(),
                         position_global,
@@ -70,14 +71,27 @@ impl Approx for (&Edge, &Surface) {
                         // For now, let's just approximate *all* we need and
                         // insert that into the cache. The cache takes care of
                         // merging that with whatever is already there.
-                        cache.insert_curve_approx(
+                        let approx = approx_curve(
+                            &edge.path(),
+                            surface,
+                            edge.boundary(),
+                            tolerance,
+                        );
+                        cache.count_surface_point_conversions(
+                            approx.points.len(),
+                        );
+                        cache.insert_curve_approx(edge.curve().clone(), approx);
+
+                        // The cache stores approximations in normalized form,
+                        // and might have merged our new approximation with
+                        // others. Get it back from the cache, to make sure it
+                        // covers exactly our boundary, in the right direction.
+                        let mut cached = cache.get_curve_approx(
                             edge.curve().clone(),
-                            approx_curve(
-                                &edge.path(),
-                                surface,
-                                edge.boundary(),
-                                tolerance,
-                            ),
+                            edge.boundary(),
+                        );
+                        cached.segments.pop().expect(
+                            "Just inserted approximation of the boundary",
                         )
                     }
                 }
@@ -110,6 +124,16 @@ pub struct EdgeApprox {
 }
 
 impl EdgeApprox {
+    /// Construct an approximation that consists only of the edge's first point
+    ///
+    /// This is only a valid approximation of an edge that is a line segment.
+    pub(crate) fn from_first_point(first: ApproxPoint<2>) -> Self {
+        Self {
+            first,
+            rest: Vec::new(),
+        }
+    }
+
     /// Compute the points that approximate the edge
     pub fn points(&self) -> Vec<ApproxPoint<2>> {
         let mut points = Vec::new();
@@ -127,19 +151,27 @@ fn approx_curve(
     boundary: CurveBoundary<Point<1>>,
     tolerance: impl Into<Tolerance>,
 ) -> CurveApproxSegment {
-    // There are different cases of varying complexity. Circles are the hard
-    // part here, as they need to be approximated, while lines don't need to be.
+    // There are different cases of varying complexity. Circles and ellipses
+    // are the hard part here, as they need to be approximated, while lines
+    // don't need to be.
     //
     // This will probably all be unified eventually, as `SurfacePath` and
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
     let points = match (path, surface.geometry().u) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
-            todo!(
-                "Approximating a circle on a curved surface not supported yet."
-            )
-        }
-        (SurfacePath::Circle(_), GlobalPath::Line(_)) => {
+        (
+            SurfacePath::Circle(_) | SurfacePath::Ellipse(_),
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_),
+        ) => approx_curved_path_on_curved_surface(
+            path,
+            surface,
+            boundary,
+            tolerance.into(),
+        ),
+        (
+            SurfacePath::Circle(_) | SurfacePath::Ellipse(_),
+            GlobalPath::Line(_),
+        ) => {
             (path, boundary)
                 .approx_with_cache(tolerance, &mut ())
                 .into_iter()
@@ -177,11 +209,15 @@ fn approx_curve(
 
             let mut points = Vec::new();
             for (u, _) in approx_u {
-                let t = (u.t - line.origin().u) / line.direction().u;
-                let point_surface = path.point_from_path_coords([t]);
+                // The approximation is in terms of the surface's u-axis, but
+                // we need curve coordinates. Those only happen to be the same,
+                // if the line starts at `u = 0` and goes along the u-axis.
+                let t =
+                    Point::from([(u.t - line.origin().u) / line.direction().u]);
+                let point_surface = path.point_from_path_coords(t);
                 let point_global =
                     surface.geometry().point_from_surface_coords(point_surface);
-                points.push((u, point_global));
+                points.push((t, point_global));
             }
 
             points
@@ -197,14 +233,125 @@ fn approx_curve(
     CurveApproxSegment { boundary, points }
 }
 
+/// Approximate a curved path on a curved surface
+///
+/// The approximation of the path in surface coordinates doesn't take the
+/// curvature of the surface into account. Its segments are subdivided further,
+/// until the midpoint of each segment is within the tolerance of the curve in
+/// global coordinates.
+///
+/// Like all curve approximations, the result doesn't include the points at the
+/// boundary.
+fn approx_curved_path_on_curved_surface(
+    path: &SurfacePath,
+    surface: &Surface,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    // Limits the number of subdivisions of each segment to `2^MAX_DEPTH`, in
+    // case the tolerance can't be reached due to numerical issues.
+    const MAX_DEPTH: u32 = 16;
+
+    let to_global = |point_curve: Point<1>| {
+        let point_surface = path.point_from_path_coords(point_curve);
+        surface.geometry().point_from_surface_coords(point_surface)
+    };
+
+    let mut points_curve = vec![boundary.inner[0]];
+    points_curve.extend(
+        (path, boundary)
+            .approx_with_cache(tolerance, &mut ())
+            .into_iter()
+            .map(|(point_curve, _)| point_curve),
+    );
+    points_curve.push(boundary.inner[1]);
+
+    let mut points = Vec::new();
+    for segment in points_curve.windows(2) {
+        let [a, b] = [segment[0], segment[1]].map(|t| (t, to_global(t)));
+
+        let mut stack = vec![(b, 0)];
+        let mut start = a;
+
+        while let Some((end, depth)) = stack.pop() {
+            let t = Point::from([(start.0.t + end.0.t) / 2.]);
+            let point_global = to_global(t);
+            let chord_midpoint = start.1 + (end.1 - start.1) / 2.;
+
+            if depth < MAX_DEPTH
+                && point_global.distance_to(&chord_midpoint) > tolerance.inner()
+            {
+                stack.push((end, depth + 1));
+                stack.push(((t, point_global), depth + 1));
+                continue;
+            }
+
+            if end != b {
+                points.push(end);
+            }
+            start = end;
+        }
+
+        if b.0 != boundary.inner[1] {
+            points.push(b);
+        }
+    }
+
+    points
+}
+
 /// Cache for edge approximations
+///
+/// The cache makes sure that every point on a surface is converted into global
+/// coordinates only once. Edges that share a start vertex share its global
+/// position, and edges that share a curve share its approximation. Face
+/// approximation and triangulation then only ever refer to those global
+/// positions.
+///
+/// The cache also carries the [`GeometryContext`] that approximations which
+/// use it are checked against. Use [`EdgeApproxCache::with_context`] to
+/// provide a context other than the default one.
 #[derive(Default)]
 pub struct EdgeApproxCache {
     start_position_approx: BTreeMap<HandleWrapper<Vertex>, Point<3>>,
     curve_approx: CurveApproxCache,
+    context: GeometryContext,
+
+    #[cfg(debug_assertions)]
+    num_surface_point_conversions: usize,
 }
 
 impl EdgeApproxCache {
+    /// Construct an empty cache that uses the provided context
+    pub fn with_context(context: &GeometryContext) -> Self {
+        Self {
+            context: *context,
+            ..Self::default()
+        }
+    }
+
+    /// Access the context that the cache uses
+    pub fn context(&self) -> &GeometryContext {
+        &self.context
+    }
+
+    /// Access the number of surface points that were converted into global
+    /// coordinates while filling this cache
+    ///
+    /// Only available in debug builds. Intended for verifying that no point is
+    /// converted more than once.
+    #[cfg(debug_assertions)]
+    pub fn num_surface_point_conversions(&self) -> usize {
+        self.num_surface_point_conversions
+    }
+
+    fn count_surface_point_conversions(&mut self, _num: usize) {
+        #[cfg(debug_assertions)]
+        {
+            self.num_surface_point_conversions += _num;
+        }
+    }
+
     fn get_start_position_approx(
         &self,
         handle: &Handle<Vertex>,
@@ -236,25 +383,33 @@ impl EdgeApproxCache {
         &mut self,
         handle: Handle<Curve>,
         approx: CurveApproxSegment,
-    ) -> CurveApproxSegment {
-        self.curve_approx.insert(handle, approx)
+    ) {
+        self.curve_approx.insert(handle, approx);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{f64::consts::TAU, ops::Deref};
+    use std::{collections::BTreeSet, f64::consts::TAU, ops::Deref};
 
+    use fj_math::{Point, Scalar};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::approx::{Approx, ApproxPoint},
+        algorithms::{
+            approx::{Approx, ApproxPoint},
+            sweep::Sweep,
+        },
         geometry::{CurveBoundary, GlobalPath, SurfaceGeometry},
-        objects::{Edge, Surface},
-        operations::BuildEdge,
+        objects::{Edge, Region, Sketch, Surface, Vertex},
+        operations::{
+            BuildEdge, BuildRegion, BuildSketch, Insert, UpdateSketch,
+        },
         services::Services,
     };
 
+    use super::EdgeApproxCache;
+
     #[test]
     fn approx_line_on_flat_surface() {
         let mut services = Services::new();
@@ -320,6 +475,87 @@ mod tests {
         assert_eq!(approx.rest, expected_approx);
     }
 
+    #[test]
+    fn approx_reversed_edge() {
+        let mut services = Services::new();
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(1.),
+            v: [0., 0., 1.].into(),
+        });
+
+        // The line goes against the direction of the surface's u-axis, so its
+        // curve coordinates differ from the u-coordinates.
+        let edge = Edge::line_segment(
+            [[TAU, 1.], [0., 1.]],
+            Some([[0.], [TAU]].map(Point::from)),
+            &mut services,
+        );
+        let reversed = Edge::new(
+            edge.path(),
+            edge.boundary().reverse(),
+            edge.curve().clone(),
+            Vertex::new().insert(&mut services),
+        );
+
+        let tolerance = 1.;
+
+        // The order must be the same, regardless of whether the reversed edge
+        // is approximated from scratch, or from the cached approximation of
+        // the original edge.
+        let mut cache = EdgeApproxCache::default();
+        let approx = (&edge, &surface).approx_with_cache(tolerance, &mut cache);
+        let approx_reversed_cached =
+            (&reversed, &surface).approx_with_cache(tolerance, &mut cache);
+        let approx_reversed = (&reversed, &surface).approx(tolerance);
+
+        let mut expected = approx.rest.clone();
+        expected.reverse();
+        assert!(!expected.is_empty());
+        assert_eq!(approx_reversed.rest, expected);
+        assert_eq!(approx_reversed_cached.rest, expected);
+    }
+
+    #[test]
+    fn approx_circle_on_curved_surface() {
+        let mut services = Services::new();
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(1.),
+            v: [0., 0., 1.].into(),
+        });
+        let edge = Edge::circle([0., 0.], 1., &mut services);
+
+        let tolerance = 0.01;
+        let approx = (&edge, &surface).approx(tolerance);
+
+        let mut points = vec![approx.first.global_form];
+        points.extend(approx.rest.iter().map(|point| point.global_form));
+        points.push(approx.first.global_form);
+
+        // Every point on the curve must be close to the approximation.
+        for i in 0..1000 {
+            let t = Point::from([TAU * i as f64 / 1000.]);
+            let point = surface.geometry().point_from_surface_coords(
+                edge.path().point_from_path_coords(t),
+            );
+
+            let distance = points
+                .windows(2)
+                .map(|segment| {
+                    let [a, b] = [segment[0], segment[1]];
+                    let ab = b - a;
+                    let s = ((point - a).dot(&ab) / ab.dot(&ab))
+                        .max(Scalar::ZERO)
+                        .min(Scalar::ONE);
+                    (a + ab * s).distance_to(&point)
+                })
+                .min()
+                .unwrap();
+            assert!(distance <= Scalar::from(tolerance));
+        }
+    }
+
     #[test]
     fn approx_circle_on_flat_surface() {
         let mut services = Services::new();
@@ -343,4 +579,31 @@ mod tests {
                 .collect::<Vec<_>>();
         assert_eq!(approx.rest, expected_approx);
     }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn convert_each_surface_point_once() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle([0., 0.], 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let cylinder = (sketch, services.objects.surfaces.xy_plane())
+            .sweep([0., 0., 1.], &mut services);
+
+        let tolerance = 0.1;
+        let mut cache = EdgeApproxCache::default();
+        let approx = cylinder.deref().approx_with_cache(tolerance, &mut cache);
+
+        let unique_points = approx
+            .iter()
+            .flat_map(|face| face.points())
+            .map(|point| point.global_form)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(cache.num_surface_point_conversions(), unique_points.len());
+    }
 }
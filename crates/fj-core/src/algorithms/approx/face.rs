@@ -7,8 +7,9 @@ use std::{collections::BTreeSet, ops::Deref};
 use fj_interop::mesh::Color;
 
 use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
+    geometry::GeometryContext,
     objects::{Face, Handedness, Handles},
-    validate::ValidationConfig,
 };
 
 use super::{
@@ -31,33 +32,56 @@ impl Approx for &Handles<Face> {
             .map(|face| face.approx_with_cache(tolerance, cache))
             .collect();
 
-        let min_distance = ValidationConfig::default().distinct_min_distance;
-        let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
-
-        // Run some validation code on the approximation.
-        for approx in &approx {
-            let approx: &FaceApprox = approx;
-
-            for a in &approx.points() {
-                for b in &all_points {
-                    let distance = (b.global_form - a.global_form).magnitude();
-
-                    if b.global_form != a.global_form && distance < min_distance
-                    {
-                        panic!(
-                            "Invalid approximation: \
-                            Distinct points are too close \
-                            (a: {:?}, b: {:?}, distance: {distance})",
-                            a.global_form, b.global_form,
-                        );
-                    }
-                }
+        check_approx(&approx, cache.context());
+        approx
+    }
 
-                all_points.insert(*a);
-            }
+    fn approx_with_cache_cancellable(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        token: Option<&CancellationToken>,
+    ) -> Result<Self::Approximation, Cancelled> {
+        let tolerance = tolerance.into();
+
+        let mut approx = BTreeSet::new();
+        for face in self {
+            CancellationToken::check_optional(token)?;
+            approx.insert(face.approx_with_cache(tolerance, cache));
         }
 
-        approx
+        check_approx(&approx, cache.context());
+        Ok(approx)
+    }
+}
+
+/// Run some validation code on the approximation of multiple faces
+///
+/// # Panics
+///
+/// Panics, if distinct points of the approximation are closer than
+/// [`GeometryContext::distinct_min_distance`].
+fn check_approx(approx: &BTreeSet<FaceApprox>, context: &GeometryContext) {
+    let min_distance = context.distinct_min_distance;
+    let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
+
+    for approx in approx {
+        for a in &approx.points() {
+            for b in &all_points {
+                let distance = (b.global_form - a.global_form).magnitude();
+
+                if b.global_form != a.global_form && distance < min_distance {
+                    panic!(
+                        "Invalid approximation: \
+                        Distinct points are too close \
+                        (a: {:?}, b: {:?}, distance: {distance})",
+                        a.global_form, b.global_form,
+                    );
+                }
+            }
+
+            all_points.insert(*a);
+        }
     }
 }
 
@@ -135,3 +159,38 @@ impl FaceApprox {
         points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::{edge::EdgeApproxCache, Approx},
+            fixtures,
+        },
+        geometry::GeometryContext,
+        services::Services,
+    };
+
+    #[test]
+    #[should_panic(expected = "Distinct points are too close")]
+    fn check_with_context_from_cache() {
+        let mut services = Services::new();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+        let shell = cube.shells().nth(0).unwrap();
+
+        // With the default context, the approximation is fine.
+        let _ = shell.faces().approx(0.1);
+
+        // A context that considers the corners of the cube too close to be
+        // distinct makes the check fail.
+        let context = GeometryContext {
+            distinct_min_distance: Scalar::from(2.),
+            ..GeometryContext::default()
+        };
+        let mut cache = EdgeApproxCache::with_context(&context);
+        let _ = shell.faces().approx_with_cache(0.1, &mut cache);
+    }
+}
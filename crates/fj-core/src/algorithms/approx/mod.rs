@@ -18,6 +18,8 @@ use std::{
 
 use fj_math::Point;
 
+use super::cancellation::{CancellationToken, Cancelled};
+
 pub use self::tolerance::{InvalidTolerance, Tolerance};
 
 /// Approximate an object
@@ -46,6 +48,34 @@ pub trait Approx: Sized {
         tolerance: impl Into<Tolerance>,
         cache: &mut Self::Cache,
     ) -> Self::Approximation;
+
+    /// Approximate the object, unless cancelled
+    ///
+    /// Like [`Approx::approx`], but checks the provided token and returns
+    /// [`Cancelled`], if it has been cancelled.
+    fn approx_cancellable(
+        self,
+        tolerance: impl Into<Tolerance>,
+        token: Option<&CancellationToken>,
+    ) -> Result<Self::Approximation, Cancelled> {
+        let mut cache = Self::Cache::default();
+        self.approx_with_cache_cancellable(tolerance, &mut cache, token)
+    }
+
+    /// Approximate the object using the provided cache, unless cancelled
+    ///
+    /// The default implementation checks the token once, before approximating.
+    /// Implementations that approximate multiple faces should override it, to
+    /// check the token once per face.
+    fn approx_with_cache_cancellable(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        token: Option<&CancellationToken>,
+    ) -> Result<Self::Approximation, Cancelled> {
+        CancellationToken::check_optional(token)?;
+        Ok(self.approx_with_cache(tolerance, cache))
+    }
 }
 
 /// A point from an approximation, with local and global forms
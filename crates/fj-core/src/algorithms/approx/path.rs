@@ -30,7 +30,7 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, Ellipse, Point, Scalar, Sign};
 
 use crate::geometry::{CurveBoundary, GlobalPath, SurfacePath};
 
@@ -51,6 +51,9 @@ impl Approx for (&SurfacePath, CurveBoundary<Point<1>>) {
             SurfacePath::Circle(circle) => {
                 approx_circle(circle, range, tolerance.into())
             }
+            SurfacePath::Ellipse(ellipse) => {
+                approx_ellipse(ellipse, range, tolerance.into())
+            }
             SurfacePath::Line(_) => vec![],
         }
     }
@@ -71,6 +74,9 @@ impl Approx for (GlobalPath, CurveBoundary<Point<1>>) {
             GlobalPath::Circle(circle) => {
                 approx_circle(&circle, range, tolerance.into())
             }
+            GlobalPath::Ellipse(ellipse) => {
+                approx_ellipse(&ellipse, range, tolerance.into())
+            }
             GlobalPath::Line(_) => vec![],
         }
     }
@@ -98,6 +104,106 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// Approximate an ellipse
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the ellipse.
+///
+/// Unlike circles, ellipses are not approximated using a uniform increment, as
+/// that would waste a lot of points on the flat parts of eccentric ellipses.
+/// The points of a full turn are computed by adaptive subdivision instead. The
+/// same points are then used for every turn, which keeps the approximation
+/// deterministic, as described in the module documentation.
+fn approx_ellipse<const D: usize>(
+    ellipse: &Ellipse<D>,
+    boundary: impl Into<CurveBoundary<Point<1>>>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+
+    let full_turn = ellipse_approx_coords(ellipse, tolerance);
+
+    let [a, b] = boundary.inner.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    // Like for circles, the boundaries of the range are not part of the
+    // approximation.
+    let first_turn = (min / Scalar::TAU).floor().into_f64() as i64;
+    let last_turn = (max / Scalar::TAU).floor().into_f64() as i64;
+
+    let mut points = Vec::new();
+    for turn in first_turn..=last_turn {
+        let offset = Scalar::TAU * turn as f64;
+
+        for &coord in &full_turn {
+            let t = offset + coord;
+            if t <= min || t >= max {
+                continue;
+            }
+
+            let point_curve = Point::from([t]);
+            let point_global = ellipse.point_from_ellipse_coords(point_curve);
+            points.push((point_curve, point_global));
+        }
+    }
+
+    if a > b {
+        points.reverse();
+    }
+
+    points
+}
+
+/// Compute the ellipse coordinates that approximate a full turn of an ellipse
+///
+/// Returns coordinates in the range `[0, TAU)`, in ascending order.
+fn ellipse_approx_coords<const D: usize>(
+    ellipse: &Ellipse<D>,
+    tolerance: Tolerance,
+) -> Vec<Scalar> {
+    // Limit the depth of the subdivision, to make sure it terminates, even if
+    // the tolerance is unreasonably small compared to the ellipse.
+    const MAX_DEPTH: u32 = 24;
+
+    fn subdivide<const D: usize>(
+        ellipse: &Ellipse<D>,
+        [start, end]: [Scalar; 2],
+        tolerance: Scalar,
+        depth: u32,
+        coords: &mut Vec<Scalar>,
+    ) {
+        let middle = start.midpoint(end);
+
+        let [a, b, m] = [start, end, middle]
+            .map(|t| ellipse.point_from_ellipse_coords([t]));
+        let chord = b - a;
+        let to_middle = m - a;
+        let deviation = (to_middle
+            - chord * (to_middle.dot(&chord) / chord.dot(&chord)))
+        .magnitude();
+
+        if deviation > tolerance && depth < MAX_DEPTH {
+            subdivide(ellipse, [start, middle], tolerance, depth + 1, coords);
+            subdivide(ellipse, [middle, end], tolerance, depth + 1, coords);
+        } else {
+            coords.push(start);
+        }
+    }
+
+    let num_initial_segments = tolerance.min_segments_per_full_turn().max(4);
+    let increment = Scalar::TAU / f64::from(num_initial_segments);
+
+    let mut coords = Vec::new();
+    for i in 0..num_initial_segments {
+        let start = increment * f64::from(i);
+        let end = increment * f64::from(i + 1);
+
+        subdivide(ellipse, [start, end], tolerance.inner(), 0, &mut coords);
+    }
+
+    coords
+}
+
 struct PathApproxParams {
     increment: Scalar,
 }
@@ -107,14 +213,18 @@ impl PathApproxParams {
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
+        let tolerance = tolerance.into();
         let radius = circle.a().magnitude();
 
-        let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
-            3.,
-        )
-        .ceil();
+        // For circles that are small compared to the tolerance, the ratio can
+        // exceed the domain of `acos`. Any such circle is approximated with
+        // the minimum number of vertices anyway.
+        let ratio = (tolerance.inner() / radius).min(Scalar::ONE);
+
+        let num_vertices_to_approx_full_circle =
+            Scalar::max(Scalar::PI / (Scalar::ONE - ratio).acos(), 3.)
+                .max(tolerance.min_segments_per_full_turn() as f64)
+                .ceil();
 
         let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
 
@@ -166,20 +276,94 @@ impl PathApproxParams {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::TAU;
+    use std::f64::consts::{FRAC_PI_2, TAU};
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{Circle, Ellipse, Point, Scalar};
 
-    use crate::algorithms::approx::{path::CurveBoundary, Tolerance};
+    use crate::{
+        algorithms::approx::{path::CurveBoundary, Approx, Tolerance},
+        geometry::SurfacePath,
+    };
 
     use super::PathApproxParams;
 
+    #[test]
+    fn approx_ellipse() {
+        let ellipse = Ellipse::new([1., 2.], [10., 0.], [0., 1.]);
+        let path = SurfacePath::Ellipse(ellipse);
+        let tolerance = 0.01;
+
+        let approx = (&path, CurveBoundary::from([[0.], [TAU]]))
+            .approx(tolerance)
+            .into_iter()
+            .map(|(point_curve, _)| point_curve.t)
+            .collect::<Vec<_>>();
+
+        let coords = [Scalar::ZERO]
+            .into_iter()
+            .chain(approx.iter().copied())
+            .chain([Scalar::TAU])
+            .collect::<Vec<_>>();
+
+        // Check the deviation between each approximated segment and the
+        // ellipse, at a number of points.
+        for segment in coords.windows(2) {
+            let [start, end] = [segment[0], segment[1]];
+            let [a, b] =
+                [start, end].map(|t| ellipse.point_from_ellipse_coords([t]));
+
+            for i in 1..10 {
+                let t = start + (end - start) * (f64::from(i) / 10.);
+                let point = ellipse.point_from_ellipse_coords([t]);
+
+                let chord = b - a;
+                let to_point = point - a;
+                let deviation = (to_point
+                    - chord * (to_point.dot(&chord) / chord.dot(&chord)))
+                .magnitude();
+                assert!(deviation <= Scalar::from(tolerance));
+            }
+        }
+
+        // The approximation is adaptive, so it needs fewer points than an
+        // approximation that uses the smallest increment everywhere.
+        let min_increment = coords
+            .windows(2)
+            .map(|segment| segment[1] - segment[0])
+            .min()
+            .unwrap();
+        assert!(
+            Scalar::from((coords.len() - 1) as f64) * 2.
+                < Scalar::TAU / min_increment
+        );
+
+        // The same points are generated, regardless of the range.
+        let partial = (&path, CurveBoundary::from([[TAU + 2.], [TAU + 1.]]))
+            .approx(tolerance)
+            .into_iter()
+            .map(|(point_curve, _)| point_curve.t - Scalar::TAU)
+            .collect::<Vec<_>>();
+        let mut expected = approx
+            .iter()
+            .copied()
+            .filter(|&t| t > Scalar::ONE && t < Scalar::TWO)
+            .collect::<Vec<_>>();
+        expected.reverse();
+        assert_eq!(partial.len(), expected.len());
+        for (a, b) in partial.into_iter().zip(expected) {
+            assert!((a - b).abs() < Scalar::from(1e-12));
+        }
+    }
+
     #[test]
     fn increment_for_circle() {
         test_increment(1., 0.5, 3.);
         test_increment(1., 0.1, 7.);
         test_increment(1., 0.01, 23.);
 
+        // The tolerance might be larger than the circle.
+        test_increment(1e-4, 1e-3, 3.);
+
         fn test_increment(
             radius: impl Into<Scalar>,
             tolerance: impl Into<Tolerance>,
@@ -193,6 +377,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn min_segments_per_full_turn() {
+        let small_arc = (
+            &SurfacePath::circle_from_center_and_radius([0., 0.], 0.5),
+            CurveBoundary::from([[0.], [FRAC_PI_2]]),
+        );
+        let tolerance = Tolerance::from(0.4);
+
+        // The boundary points are not part of the approximation, so the number
+        // of segments is one more than the number of points.
+        assert_eq!(small_arc.approx(tolerance).len() + 1, 1);
+        assert_eq!(
+            small_arc
+                .approx(tolerance.with_min_segments_per_full_turn(16))
+                .len()
+                + 1,
+            4
+        );
+
+        let large_circle = Circle::from_center_and_radius([0., 0.], 100.);
+        let tolerance = Tolerance::from(0.01);
+        assert_eq!(
+            PathApproxParams::for_circle(
+                &large_circle,
+                tolerance.with_min_segments_per_full_turn(16)
+            )
+            .increment(),
+            PathApproxParams::for_circle(&large_circle, tolerance).increment(),
+        );
+    }
+
     #[test]
     fn points_for_circle() {
         // At the chosen values for radius and tolerance (see below), the
@@ -2,7 +2,10 @@
 
 use std::collections::BTreeSet;
 
-use crate::objects::Shell;
+use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
+    objects::Shell,
+};
 
 use super::{edge::EdgeApproxCache, face::FaceApprox, Approx, Tolerance};
 
@@ -17,4 +20,14 @@ impl Approx for &Shell {
     ) -> Self::Approximation {
         self.faces().approx_with_cache(tolerance, cache)
     }
+
+    fn approx_with_cache_cancellable(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        token: Option<&CancellationToken>,
+    ) -> Result<Self::Approximation, Cancelled> {
+        self.faces()
+            .approx_with_cache_cancellable(tolerance, cache, token)
+    }
 }
@@ -2,19 +2,74 @@
 
 use std::collections::BTreeSet;
 
-use crate::objects::Sketch;
+use crate::{
+    objects::{Face, Sketch, Surface},
+    storage::Handle,
+};
 
 use super::{edge::EdgeApproxCache, face::FaceApprox, Approx, Tolerance};
 
-impl Approx for &Sketch {
+impl Approx for (&Sketch, &Handle<Surface>) {
     type Approximation = BTreeSet<FaceApprox>;
     type Cache = EdgeApproxCache;
 
+    /// Approximate the sketch's regions, as faces on the provided surface
     fn approx_with_cache(
         self,
-        _tolerance: impl Into<Tolerance>,
-        _cache: &mut Self::Cache,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
     ) -> Self::Approximation {
-        todo!()
+        let (sketch, surface) = self;
+        let tolerance = tolerance.into();
+
+        sketch
+            .regions()
+            .iter()
+            .map(|region| {
+                let face = Face::new(surface.clone(), region.clone());
+                (&face).approx_with_cache(tolerance, cache)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Approx,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn approx_sketch() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .add_region(
+                Region::circle([3., 0.], 1., &mut services)
+                    .insert(&mut services),
+            );
+        let surface = services.objects.surfaces.xy_plane();
+
+        let approx = (&sketch, &surface).approx(0.1);
+        assert_eq!(approx.len(), 2);
+
+        // The square is approximated by its corners, the circle needs more
+        // points.
+        let num_edges = approx
+            .iter()
+            .map(|face| face.exterior.segments().len())
+            .collect::<Vec<_>>();
+        assert!(num_edges.contains(&4));
+        assert!(num_edges.iter().any(|&num| num > 4));
     }
 }
@@ -1,8 +1,12 @@
 //! Solid approximation
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 
-use crate::objects::Solid;
+use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
+    objects::Solid,
+    storage::ObjectId,
+};
 
 use super::{edge::EdgeApproxCache, face::FaceApprox, Approx, Tolerance};
 
@@ -22,4 +26,156 @@ impl Approx for &Solid {
             .flat_map(|shell| shell.approx_with_cache(tolerance, cache))
             .collect()
     }
+
+    fn approx_with_cache_cancellable(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        token: Option<&CancellationToken>,
+    ) -> Result<Self::Approximation, Cancelled> {
+        let tolerance = tolerance.into();
+
+        let mut approx = BTreeSet::new();
+        for shell in self.shells() {
+            approx.extend(
+                shell.approx_with_cache_cancellable(tolerance, cache, token)?,
+            );
+        }
+
+        Ok(approx)
+    }
+}
+
+/// Approximate a solid, using a different tolerance for selected faces
+///
+/// Faces whose ID is a key in `overrides` are approximated with the respective
+/// tolerance. All other faces are approximated with `default_tolerance`.
+///
+/// Edges that are shared between faces with different tolerances are
+/// approximated with the finer of them, for all of those faces. This keeps the
+/// resulting mesh free of cracks, but means that a face next to an overridden
+/// one might end up with a finer approximation than its own tolerance asks
+/// for.
+pub fn approx_with_overrides(
+    solid: &Solid,
+    default_tolerance: impl Into<Tolerance>,
+    overrides: &HashMap<ObjectId, Tolerance>,
+) -> BTreeSet<FaceApprox> {
+    approx_faces_with_overrides(solid, default_tolerance.into(), overrides)
+        .into_iter()
+        .map(|(_, _, approx)| approx)
+        .collect()
+}
+
+/// Like [`approx_with_overrides`], but keep track of the faces
+///
+/// Returns the ID of each face, along with the tolerance that was used for it,
+/// and its approximation.
+pub(crate) fn approx_faces_with_overrides(
+    solid: &Solid,
+    default_tolerance: Tolerance,
+    overrides: &HashMap<ObjectId, Tolerance>,
+) -> Vec<(ObjectId, Tolerance, FaceApprox)> {
+    let mut faces = solid
+        .shells()
+        .iter()
+        .flat_map(|shell| shell.faces().iter())
+        .map(|face| {
+            let tolerance = overrides
+                .get(&face.id())
+                .copied()
+                .unwrap_or(default_tolerance);
+            (tolerance, face)
+        })
+        .collect::<Vec<_>>();
+
+    // Faces share the approximations of their edges through the cache. By
+    // approximating the faces with the finest tolerance first, shared edges end
+    // up approximated with that, and faces with coarser tolerances reuse it.
+    faces.sort_by_key(|(tolerance, _)| tolerance.inner());
+
+    let mut cache = EdgeApproxCache::default();
+    faces
+        .into_iter()
+        .map(|(tolerance, face)| {
+            let approx = face.approx_with_cache(tolerance, &mut cache);
+            (face.id(), tolerance, approx)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use fj_interop::mesh::Mesh;
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::{
+            approx::{face::FaceApprox, Tolerance},
+            fixtures::{cylinder, cylinder_side, tolerance},
+            triangulate::Triangulate,
+        },
+        services::Services,
+    };
+
+    use super::approx_with_overrides;
+
+    #[test]
+    fn override_side_of_cylinder() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = cylinder(&mut services);
+        let side = cylinder_side(&solid);
+
+        let tolerance = tolerance();
+        let fine = Tolerance::from_scalar(0.01)?;
+
+        let coarse = approx_with_overrides(&solid, tolerance, &HashMap::new());
+        let overridden = approx_with_overrides(
+            &solid,
+            tolerance,
+            &HashMap::from([(side.id(), fine)]),
+        );
+
+        let (coarse_side, _) = triangle_counts(coarse);
+        let (overridden_side, mesh) = triangle_counts(overridden);
+        assert!(overridden_side > coarse_side * 2);
+
+        // The rims of the caps are shared with the side face, so there must
+        // be no gaps between them.
+        assert!(mesh
+            .build_half_edge_structure()?
+            .boundary_loops()
+            .is_empty());
+
+        Ok(())
+    }
+
+    /// Count the triangles of the side face, and triangulate all faces
+    fn triangle_counts(
+        approx: BTreeSet<FaceApprox>,
+    ) -> (usize, Mesh<Point<3>>) {
+        let mut side = 0;
+        let mut mesh = Mesh::new();
+
+        for face in approx {
+            // Only the side face of the cylinder spans both caps.
+            let heights = face
+                .points()
+                .into_iter()
+                .map(|point| point.global_form.z)
+                .collect::<BTreeSet<_>>();
+            let is_side = heights.len() > 1;
+
+            let num_triangles_before = mesh.triangles().count();
+            face.triangulate_into_mesh(&mut mesh);
+            if is_side {
+                side = mesh.triangles().count() - num_triangles_before;
+            }
+        }
+
+        (side, mesh)
+    }
 }
@@ -12,6 +12,11 @@ use fj_math::Scalar;
 /// The `Tolerance` type enforces that the tolerance value is always larger than
 /// zero, which is an attribute that the approximation code relies on.
 ///
+/// In addition, a tolerance can define a minimum number of segments that a
+/// full turn of a circle is approximated with. This makes sure that small
+/// circles and arcs don't end up looking polygonal, even if the tolerance value
+/// would allow that. By default, there is no such minimum.
+///
 /// # Failing [`From`]/[`Into`] implementation
 ///
 /// The [`From`]/[`Into`] implementations of tolerance are fallible, which goes
@@ -22,7 +27,10 @@ use fj_math::Scalar;
 /// documentation doesn't provide any actual reasoning for this requirement, I'm
 /// feeling free to just ignore it.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct Tolerance(Scalar);
+pub struct Tolerance {
+    inner: Scalar,
+    min_segments_per_full_turn: u32,
+}
 
 impl Tolerance {
     /// Construct a `Tolerance` from a [`Scalar`]
@@ -37,12 +45,33 @@ impl Tolerance {
             return Err(InvalidTolerance(scalar));
         }
 
-        Ok(Self(scalar))
+        Ok(Self {
+            inner: scalar,
+            min_segments_per_full_turn: 0,
+        })
+    }
+
+    /// Set the minimum number of segments to approximate a full turn with
+    ///
+    /// Any circle is approximated with at least this many segments per full
+    /// turn, regardless of the tolerance value. Arcs are approximated with the
+    /// proportional number of segments.
+    pub fn with_min_segments_per_full_turn(
+        mut self,
+        min_segments_per_full_turn: u32,
+    ) -> Self {
+        self.min_segments_per_full_turn = min_segments_per_full_turn;
+        self
     }
 
     /// Return the [`Scalar`] that defines the tolerance
     pub fn inner(&self) -> Scalar {
-        self.0
+        self.inner
+    }
+
+    /// Return the minimum number of segments to approximate a full turn with
+    pub fn min_segments_per_full_turn(&self) -> u32 {
+        self.min_segments_per_full_turn
     }
 }
 
@@ -57,6 +86,31 @@ where
 }
 
 /// Error converting scalar to tolerance
-#[derive(Debug, thiserror::Error)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
 #[error("Invalid tolerance ({0}); must be above zero")]
 pub struct InvalidTolerance(Scalar);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::{InvalidTolerance, Tolerance};
+
+    #[test]
+    fn from_scalar() {
+        assert_eq!(
+            Tolerance::from_scalar(0.),
+            Err(InvalidTolerance(Scalar::ZERO))
+        );
+        assert!(Tolerance::from_scalar(-1.).is_err());
+
+        let tolerance = Tolerance::from_scalar(1e-12).unwrap();
+        assert_eq!(tolerance.inner(), Scalar::from(1e-12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_invalid_f64() {
+        let _: Tolerance = 0.0.into();
+    }
+}
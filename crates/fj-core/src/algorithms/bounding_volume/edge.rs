@@ -17,6 +17,10 @@ impl super::BoundingVolume<2> for Edge {
                     max: circle.center() + center_to_min_max,
                 })
             }
+            SurfacePath::Ellipse(ellipse) => {
+                // Like for circles, calculate the AABB of the whole ellipse.
+                Some(ellipse.aabb())
+            }
             SurfacePath::Line(_) => {
                 let points = self.boundary().inner.map(|point_curve| {
                     self.path().point_from_path_coords(point_curve)
@@ -7,19 +7,22 @@ impl super::BoundingVolume<3> for Face {
         self.region().exterior().aabb().map(|aabb2| {
             let surface = self.surface().geometry();
 
+            let swept = |aabb_bottom: Aabb<3>| {
+                let aabb_top = Aabb {
+                    min: aabb_bottom.min + surface.v,
+                    max: aabb_bottom.max + surface.v,
+                };
+
+                aabb_bottom.merged(&aabb_top)
+            };
+
             match surface.u {
                 GlobalPath::Circle(circle) => {
                     // This is not the most precise way to calculate the AABB,
                     // doing it for the whole circle, but it should do.
-
-                    let aabb_bottom = circle.aabb();
-                    let aabb_top = Aabb {
-                        min: aabb_bottom.min + surface.v,
-                        max: aabb_bottom.max + surface.v,
-                    };
-
-                    aabb_bottom.merged(&aabb_top)
+                    swept(circle.aabb())
                 }
+                GlobalPath::Ellipse(ellipse) => swept(ellipse.aabb()),
                 GlobalPath::Line(_) => Aabb {
                     min: surface.point_from_surface_coords(aabb2.min),
                     max: surface.point_from_surface_coords(aabb2.max),
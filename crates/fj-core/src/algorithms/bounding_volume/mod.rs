@@ -3,7 +3,9 @@
 mod cycle;
 mod edge;
 mod face;
+mod region;
 mod shell;
+mod sketch;
 mod solid;
 
 use fj_math::Aabb;
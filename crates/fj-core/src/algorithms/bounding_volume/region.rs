@@ -0,0 +1,11 @@
+use fj_math::Aabb;
+
+use crate::objects::Region;
+
+impl super::BoundingVolume<2> for Region {
+    fn aabb(&self) -> Option<Aabb<2>> {
+        // The interiors are contained within the exterior, so they don't need
+        // to be taken into account.
+        self.exterior().aabb()
+    }
+}
@@ -0,0 +1,71 @@
+use fj_math::Aabb;
+
+use crate::objects::Sketch;
+
+impl super::BoundingVolume<2> for Sketch {
+    fn aabb(&self) -> Option<Aabb<2>> {
+        let mut aabb: Option<Aabb<2>> = None;
+
+        for region in self.regions() {
+            let new_aabb = region.aabb();
+            aabb = aabb.map_or(new_aabb, |aabb| match new_aabb {
+                Some(new_aabb) => Some(aabb.merged(&new_aabb)),
+                None => Some(aabb),
+            });
+        }
+
+        aabb
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn triangle() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty().add_region(
+            Region::polygon([[1., 1.], [3., 1.], [2., 4.]], &mut services)
+                .insert(&mut services),
+        );
+
+        assert_eq!(
+            sketch.aabb(),
+            Some(Aabb {
+                min: Point::from([1., 1.]),
+                max: Point::from([3., 4.]),
+            })
+        );
+    }
+
+    #[test]
+    fn circle() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty().add_region(
+            Region::circle([1., 2.], 1., &mut services).insert(&mut services),
+        );
+
+        assert_eq!(
+            sketch.aabb(),
+            Some(Aabb {
+                min: Point::from([0., 1.]),
+                max: Point::from([2., 3.]),
+            })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Sketch::empty().aabb(), None);
+    }
+}
@@ -0,0 +1,139 @@
+//! Cooperative cancellation of long-running algorithms
+//!
+//! See [`CancellationToken`].
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// A token that can be used to cancel a long-running algorithm
+///
+/// Algorithms that support cancellation accept an optional token, and check it
+/// at regular intervals, for example once per face. Once the token has been
+/// cancelled, the algorithm stops at the next check and returns [`Cancelled`].
+///
+/// Clones of a token share their state. Cancelling one of them cancels all of
+/// them, which makes it possible to cancel an algorithm from another thread.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    num_checks: Arc<AtomicUsize>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// Construct a new token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a token that is cancelled automatically at the deadline
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..Self::default()
+        }
+    }
+
+    /// Construct a token that is cancelled automatically after the timeout
+    ///
+    /// Convenience wrapper around [`CancellationToken::with_deadline`].
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::with_deadline(Instant::now() + timeout)
+    }
+
+    /// Cancel the token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Determine whether the token has been cancelled
+    ///
+    /// A token with a deadline counts as cancelled, once the deadline has
+    /// passed.
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Check the token, returning an error if it has been cancelled
+    ///
+    /// This is what algorithms that support cancellation call at regular
+    /// intervals.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        self.num_checks.fetch_add(1, Ordering::Relaxed);
+
+        if self.is_cancelled() {
+            return Err(Cancelled);
+        }
+
+        Ok(())
+    }
+
+    /// Check an optional token
+    ///
+    /// Does nothing, if there is no token. Otherwise, this is equivalent to
+    /// [`CancellationToken::check`].
+    pub fn check_optional(token: Option<&Self>) -> Result<(), Cancelled> {
+        match token {
+            Some(token) => token.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Access the number of times the token has been checked
+    ///
+    /// This can be used to verify how far an algorithm got, before it noticed
+    /// that it was cancelled.
+    pub fn num_checks(&self) -> usize {
+        self.num_checks.load(Ordering::Relaxed)
+    }
+}
+
+/// Error returned by algorithms that have been cancelled
+///
+/// See [`CancellationToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Operation was cancelled")]
+pub struct Cancelled;
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{CancellationToken, Cancelled};
+
+    #[test]
+    fn cancel() {
+        let token = CancellationToken::new();
+        assert_eq!(token.check(), Ok(()));
+
+        token.clone().cancel();
+        assert_eq!(token.check(), Err(Cancelled));
+        assert_eq!(token.num_checks(), 2);
+
+        assert_eq!(CancellationToken::check_optional(None), Ok(()));
+    }
+
+    #[test]
+    fn deadline() {
+        let token = CancellationToken::with_deadline(Instant::now());
+        assert!(token.is_cancelled());
+
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,62 @@
+//! Fixtures that are shared between the tests of the algorithms
+
+use fj_math::{Point, Vector};
+
+use crate::{
+    geometry::GlobalPath,
+    objects::{Face, Region, Sketch, Solid},
+    operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+    services::Services,
+    storage::Handle,
+};
+
+use super::{approx::Tolerance, sweep::Sweep};
+
+/// The tolerance that tests approximate the fixtures with
+pub fn tolerance() -> Tolerance {
+    Tolerance::from_scalar(0.1).unwrap()
+}
+
+/// Create a unit cube, standing on the xy-plane
+///
+/// `origin` is the corner of the cube with the lowest x and y coordinates.
+pub fn cube(
+    origin: impl Into<Point<2>>,
+    services: &mut Services,
+) -> Handle<Solid> {
+    let origin = origin.into();
+    let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]
+        .map(|corner| origin + Vector::from(corner));
+
+    let region = Region::polygon(square, services).insert(services);
+    let sketch = Sketch::empty().add_region(region).insert(services);
+    let surface = services.objects.surfaces.xy_plane();
+
+    (sketch, surface).sweep([0., 0., 1.], services)
+}
+
+/// Create a cylinder with radius and height 1, standing on the xy-plane
+pub fn cylinder(services: &mut Services) -> Handle<Solid> {
+    let region = Region::circle([0., 0.], 1., services).insert(services);
+    let sketch = Sketch::empty().add_region(region).insert(services);
+    let surface = services.objects.surfaces.xy_plane();
+
+    (sketch, surface).sweep([0., 0., 1.], services)
+}
+
+/// Find the curved side face of a swept circle
+///
+/// # Panics
+///
+/// Panics, if the solid has no face on a cylindrical surface.
+pub fn cylinder_side(solid: &Solid) -> Handle<Face> {
+    solid
+        .shells()
+        .iter()
+        .flat_map(|shell| shell.faces().iter())
+        .find(|face| {
+            matches!(face.surface().geometry().u, GlobalPath::Circle(_))
+        })
+        .cloned()
+        .expect("Solid has no cylindrical face")
+}
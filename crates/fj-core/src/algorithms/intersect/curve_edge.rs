@@ -1,8 +1,8 @@
 use fj_math::{Point, Segment};
 
-use crate::{geometry::SurfacePath, objects::Edge};
+use crate::{geometry::SurfacePath, objects::Edge, storage::Handle};
 
-use super::LineSegmentIntersection;
+use super::{IntersectError, LineSegmentIntersection};
 
 /// The intersection between a curve and an [`Edge`]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -23,21 +23,27 @@ pub enum CurveEdgeIntersection {
 impl CurveEdgeIntersection {
     /// Compute the intersection
     ///
-    /// # Panics
-    ///
     /// Currently, only intersections between lines and line segments can be
-    /// computed. Panics, if a different type of curve or [`Edge`] is passed.
-    pub fn compute(path: &SurfacePath, edge: &Edge) -> Option<Self> {
+    /// computed. Returns an error, if a different type of curve or [`Edge`] is
+    /// passed.
+    pub fn compute(
+        path: &SurfacePath,
+        edge: &Handle<Edge>,
+    ) -> Result<Option<Self>, IntersectError> {
         let path_as_line = match path {
             SurfacePath::Line(line) => line,
-            _ => todo!("Curve-edge intersection only supports lines"),
+            SurfacePath::Circle(_) | SurfacePath::Ellipse(_) => {
+                return Err(IntersectError::CurvedPath { path: *path });
+            }
         };
 
         let edge_as_segment = {
             let edge_path_as_line = match edge.path() {
                 SurfacePath::Line(line) => line,
-                _ => {
-                    todo!("Curve-edge intersection only supports line segments")
+                SurfacePath::Circle(_) | SurfacePath::Ellipse(_) => {
+                    return Err(IntersectError::CurvedEdge {
+                        edge: edge.clone(),
+                    });
                 }
             };
 
@@ -49,8 +55,11 @@ impl CurveEdgeIntersection {
             Segment::from_points(edge_vertices)
         };
 
-        let intersection =
-            LineSegmentIntersection::compute(path_as_line, &edge_as_segment)?;
+        let Some(intersection) =
+            LineSegmentIntersection::compute(path_as_line, &edge_as_segment)
+        else {
+            return Ok(None);
+        };
 
         let intersection = match intersection {
             LineSegmentIntersection::Point { point_on_line } => Self::Point {
@@ -63,7 +72,7 @@ impl CurveEdgeIntersection {
             }
         };
 
-        Some(intersection)
+        Ok(Some(intersection))
     }
 }
 
@@ -72,7 +81,10 @@ mod tests {
     use fj_math::Point;
 
     use crate::{
-        geometry::SurfacePath, objects::Edge, operations::BuildEdge,
+        algorithms::intersect::IntersectError,
+        geometry::SurfacePath,
+        objects::Edge,
+        operations::{BuildEdge, Insert},
         services::Services,
     };
 
@@ -84,15 +96,16 @@ mod tests {
 
         let path = SurfacePath::u_axis();
         let edge =
-            Edge::line_segment([[1., -1.], [1., 1.]], None, &mut services);
+            Edge::line_segment([[1., -1.], [1., 1.]], None, &mut services)
+                .insert(&mut services);
 
         let intersection = CurveEdgeIntersection::compute(&path, &edge);
 
         assert_eq!(
             intersection,
-            Some(CurveEdgeIntersection::Point {
+            Ok(Some(CurveEdgeIntersection::Point {
                 point_on_curve: Point::from([1.])
-            })
+            }))
         );
     }
 
@@ -102,15 +115,16 @@ mod tests {
 
         let path = SurfacePath::u_axis();
         let edge =
-            Edge::line_segment([[-1., -1.], [-1., 1.]], None, &mut services);
+            Edge::line_segment([[-1., -1.], [-1., 1.]], None, &mut services)
+                .insert(&mut services);
 
         let intersection = CurveEdgeIntersection::compute(&path, &edge);
 
         assert_eq!(
             intersection,
-            Some(CurveEdgeIntersection::Point {
+            Ok(Some(CurveEdgeIntersection::Point {
                 point_on_curve: Point::from([-1.])
-            })
+            }))
         );
     }
 
@@ -120,11 +134,12 @@ mod tests {
 
         let path = SurfacePath::u_axis();
         let edge =
-            Edge::line_segment([[-1., -1.], [1., -1.]], None, &mut services);
+            Edge::line_segment([[-1., -1.], [1., -1.]], None, &mut services)
+                .insert(&mut services);
 
         let intersection = CurveEdgeIntersection::compute(&path, &edge);
 
-        assert!(intersection.is_none());
+        assert_eq!(intersection, Ok(None));
     }
 
     #[test]
@@ -133,15 +148,38 @@ mod tests {
 
         let path = SurfacePath::u_axis();
         let edge =
-            Edge::line_segment([[-1., 0.], [1., 0.]], None, &mut services);
+            Edge::line_segment([[-1., 0.], [1., 0.]], None, &mut services)
+                .insert(&mut services);
 
         let intersection = CurveEdgeIntersection::compute(&path, &edge);
 
         assert_eq!(
             intersection,
-            Some(CurveEdgeIntersection::Coincident {
+            Ok(Some(CurveEdgeIntersection::Coincident {
                 points_on_curve: [Point::from([-1.]), Point::from([1.]),]
-            })
+            }))
+        );
+    }
+
+    #[test]
+    fn compute_curved() {
+        let mut services = Services::new();
+
+        let circle = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let line =
+            Edge::line_segment([[-1., 0.], [1., 0.]], None, &mut services)
+                .insert(&mut services);
+        assert_eq!(
+            CurveEdgeIntersection::compute(&circle, &line),
+            Err(IntersectError::CurvedPath { path: circle }),
         );
+
+        let edge =
+            Edge::circle([0., 0.], 1., &mut services).insert(&mut services);
+        let error =
+            CurveEdgeIntersection::compute(&SurfacePath::u_axis(), &edge)
+                .unwrap_err();
+        assert_eq!(error, IntersectError::CurvedEdge { edge });
+        assert!(error.to_string().contains("curved edge"));
     }
 }
@@ -1,11 +1,11 @@
 use std::vec;
 
 use fj_interop::ext::SliceExt;
-use fj_math::Point;
+use fj_math::{Aabb, Point, Scalar};
 
 use crate::{geometry::SurfacePath, objects::Face};
 
-use super::CurveEdgeIntersection;
+use super::{CurveEdgeIntersection, IntersectError};
 
 /// The intersections between a curve and a [`Face`], in curve coordinates
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -28,13 +28,19 @@ impl CurveFaceIntersection {
     }
 
     /// Compute the intersection
-    pub fn compute(path: &SurfacePath, face: &Face) -> Self {
+    ///
+    /// Returns an error, if the intersection between `path` and any of the
+    /// face's edges can't be computed. See [`CurveEdgeIntersection::compute`].
+    pub fn compute(
+        path: &SurfacePath,
+        face: &Face,
+    ) -> Result<Self, IntersectError> {
         let edges = face.region().all_cycles().flat_map(|cycle| cycle.edges());
 
         let mut intersections = Vec::new();
 
         for edge in edges {
-            let intersection = CurveEdgeIntersection::compute(path, edge);
+            let intersection = CurveEdgeIntersection::compute(path, edge)?;
 
             if let Some(intersection) = intersection {
                 match intersection {
@@ -58,7 +64,7 @@ impl CurveFaceIntersection {
             .map(|&[start, end]| CurveFaceIntersectionInterval { start, end })
             .collect();
 
-        Self { intervals }
+        Ok(Self { intervals })
     }
 
     /// Merge this intersection list with another
@@ -79,20 +85,22 @@ impl CurveFaceIntersection {
             // interval available from both `self` and `other` each. Only if
             // that's the case, is there a chance for an overlap.
 
-            // Build the overlap of the two next intervals, by comparing them.
-            // At this point we don't know yet, if this is a valid interval.
-            let overlap_start = self_.start.max(other.start);
-            let overlap_end = self_.end.min(other.end);
-
-            if overlap_start < overlap_end {
+            // Build the overlap of the two next intervals. Intervals that only
+            // touch have an overlap of zero length, which we don't consider a
+            // valid overlap.
+            let overlap = self_.to_aabb().intersection(&other.to_aabb());
+            if let Some(overlap) =
+                overlap.filter(|overlap| overlap.length() > Scalar::ZERO)
+            {
                 // This is indeed a valid overlap. Add it to our list of
                 // results.
-                intervals.push(CurveFaceIntersectionInterval {
-                    start: overlap_start,
-                    end: overlap_end,
-                });
+                intervals.push(overlap.into());
             }
 
+            // Even if there's no overlap, this is where the earlier of the two
+            // intervals ends.
+            let overlap_end = self_.end.min(other.end);
+
             // Only if the end of the overlap interval has overtaken one of the
             // input ones are we done with it. An input interval that hasn't
             // been overtaken by the overlap, could still overlap with another
@@ -137,6 +145,22 @@ pub struct CurveFaceIntersectionInterval {
     pub end: Point<1>,
 }
 
+impl CurveFaceIntersectionInterval {
+    /// Convert the interval into a 1-dimensional AABB
+    pub fn to_aabb(self) -> Aabb<1> {
+        Aabb::<1>::from_points([self.start, self.end])
+    }
+}
+
+impl From<Aabb<1>> for CurveFaceIntersectionInterval {
+    fn from(aabb: Aabb<1>) -> Self {
+        Self {
+            start: aabb.min,
+            end: aabb.max,
+        }
+    }
+}
+
 impl<P> From<[P; 2]> for CurveFaceIntersectionInterval
 where
     P: Into<Point<1>>,
@@ -197,7 +221,7 @@ mod tests {
 
         let expected =
             CurveFaceIntersection::from_intervals([[[1.], [2.]], [[4.], [5.]]]);
-        assert_eq!(CurveFaceIntersection::compute(&path, &face), expected);
+        assert_eq!(CurveFaceIntersection::compute(&path, &face), Ok(expected));
 
         services.only_validate(face);
     }
@@ -271,4 +295,22 @@ mod tests {
         ]);
         assert_eq!(merged, expected);
     }
+
+    #[test]
+    fn merge_touching() {
+        let a = CurveFaceIntersection::from_intervals([
+            [[0.], [1.]], // touches `b` at its end
+            [[3.], [4.]], // touches `b` at its start
+            [[6.], [7.]], // touches two of `b`
+        ]);
+        let b = CurveFaceIntersection::from_intervals([
+            [[1.], [2.]],
+            [[2.], [3.]],
+            [[5.], [6.]],
+            [[7.], [8.]],
+        ]);
+
+        assert!(a.merge(&b).is_empty());
+        assert!(b.merge(&a).is_empty());
+    }
 }
@@ -0,0 +1,226 @@
+use fj_interop::ext::ArrayExt;
+use fj_math::{Aabb, Line, Point, Scalar};
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Edge, Surface},
+    storage::Handle,
+};
+
+use super::IntersectError;
+
+/// The overlap of two coincident [`Edge`]s
+///
+/// Two edges overlap, if they lie on the same curve in global space, and their
+/// boundaries on that curve overlap. This is the case for the edges that two
+/// faces share, but it can also happen for only a part of two edges.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct EdgeOverlap {
+    /// The overlapping interval on each edge, in the edge's curve coordinates
+    ///
+    /// The interval on the first edge goes in the direction of the first edge.
+    /// The points of the interval on the second edge correspond to the points
+    /// of the first, meaning it goes in the direction of the second edge, only
+    /// if both edges point in the same direction.
+    pub intervals: [[Point<1>; 2]; 2],
+}
+
+impl EdgeOverlap {
+    /// Compute the overlap of two edges
+    ///
+    /// Each edge is passed along with the surface it is defined on. The edges
+    /// are considered to lie on the same curve, if the end points of each edge
+    /// are within `tolerance` of the other edge's line. Returns `None`, if the
+    /// edges are not coincident, or if they are, but don't overlap by more than
+    /// `tolerance`.
+    ///
+    /// Currently, only edges that are line segments on planes are supported.
+    /// Returns an error, if any other edge or surface is passed.
+    pub fn compute(
+        edges: [(&Handle<Edge>, &Handle<Surface>); 2],
+        tolerance: impl Into<Scalar>,
+    ) -> Result<Option<Self>, IntersectError> {
+        let tolerance = tolerance.into();
+
+        let [a, b] = edges.try_map_ext(|(edge, surface)| {
+            if let SurfacePath::Circle(_) | SurfacePath::Ellipse(_) =
+                edge.path()
+            {
+                return Err(IntersectError::CurvedEdge { edge: edge.clone() });
+            }
+            if let GlobalPath::Circle(_) | GlobalPath::Ellipse(_) =
+                surface.geometry().u
+            {
+                return Err(IntersectError::CurvedSurface {
+                    surface: surface.clone(),
+                });
+            }
+
+            // The edge is a line segment on a plane, so it's a line segment in
+            // global space too.
+            let boundary = edge.boundary().inner;
+            let points = boundary.map(|point| edge.point_at(point, surface));
+            let line = Line::from_points_with_line_coords([
+                (boundary[0], points[0]),
+                (boundary[1], points[1]),
+            ]);
+
+            Ok((line, points))
+        })?;
+
+        let (line_a, points_a) = a;
+        let (line_b, points_b) = b;
+
+        let is_coincident = points_b
+            .into_iter()
+            .map(|point| (point, &line_a))
+            .chain(points_a.into_iter().map(|point| (point, &line_b)))
+            .all(|(point, line)| {
+                let on_line =
+                    line.point_from_line_coords([line.parameter_at(point)]);
+                point.distance_to(&on_line) <= tolerance
+            });
+        if !is_coincident {
+            return Ok(None);
+        }
+
+        // Compute the overlap in the coordinates of the first edge. Those
+        // might go in either direction along the line, but `Aabb` takes care
+        // of that.
+        let [interval_a, interval_b] = [points_a, points_b].map(|points| {
+            Aabb::<1>::from_points(
+                points.map(|point| line_a.point_to_line_coords(point)),
+            )
+        });
+        let Some(overlap) = interval_a.intersection(&interval_b) else {
+            return Ok(None);
+        };
+        if overlap.length() * line_a.direction().magnitude() <= tolerance {
+            return Ok(None);
+        }
+
+        // The overlap needs to go in the direction of the first edge.
+        let [start_a, end_a] = edges[0].0.boundary().inner;
+        let interval_a = if start_a <= end_a {
+            [overlap.min, overlap.max]
+        } else {
+            [overlap.max, overlap.min]
+        };
+
+        let overlap =
+            interval_a.map(|point| line_a.point_from_line_coords(point));
+        let interval_b =
+            overlap.map(|point| line_b.point_to_line_coords(point));
+
+        Ok(Some(Self {
+            intervals: [interval_a, interval_b],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Point};
+
+    use crate::{
+        algorithms::intersect::IntersectError,
+        objects::Edge,
+        operations::{BuildEdge, Insert},
+        services::Services,
+    };
+
+    use super::EdgeOverlap;
+
+    #[test]
+    fn partial_overlap() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let xy_plane = services.objects.surfaces.xy_plane();
+        let xz_plane = services.objects.surfaces.xz_plane();
+
+        let a = Edge::line_segment([[0., 0.], [2., 0.]], None, &mut services)
+            .insert(&mut services);
+        let b = Edge::line_segment([[1., 0.], [3., 0.]], None, &mut services)
+            .insert(&mut services);
+
+        // Both edges lie on the x-axis, even though they're defined on
+        // different surfaces.
+        let overlap =
+            EdgeOverlap::compute([(&a, &xy_plane), (&b, &xz_plane)], 1e-9)?
+                .expect("Expected overlap");
+
+        let [interval_a, interval_b] = overlap.intervals;
+        for (interval, expected) in
+            [(interval_a, [0.5, 1.]), (interval_b, [0., 0.5])]
+        {
+            for (point, expected) in interval.into_iter().zip(expected) {
+                assert_abs_diff!(point, Point::from([expected]));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn identical() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+
+        let a = Edge::line_segment([[0., 0.], [1., 1.]], None, &mut services)
+            .insert(&mut services);
+        let b = Edge::line_segment([[1., 1.], [0., 0.]], None, &mut services)
+            .insert(&mut services);
+
+        let overlap =
+            EdgeOverlap::compute([(&a, &surface), (&b, &surface)], 1e-9)?
+                .expect("Expected overlap");
+
+        let [interval_a, interval_b] = overlap.intervals;
+        for (interval, expected) in
+            [(interval_a, [0., 1.]), (interval_b, [1., 0.])]
+        {
+            for (point, expected) in interval.into_iter().zip(expected) {
+                assert_abs_diff!(point, Point::from([expected]));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_overlap() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+
+        let a = Edge::line_segment([[0., 0.], [2., 0.]], None, &mut services)
+            .insert(&mut services);
+        let perpendicular =
+            Edge::line_segment([[1., -1.], [1., 1.]], None, &mut services)
+                .insert(&mut services);
+        let disjoint =
+            Edge::line_segment([[3., 0.], [4., 0.]], None, &mut services)
+                .insert(&mut services);
+        let touching =
+            Edge::line_segment([[2., 0.], [4., 0.]], None, &mut services)
+                .insert(&mut services);
+
+        for other in [perpendicular, disjoint, touching] {
+            let overlap = EdgeOverlap::compute(
+                [(&a, &surface), (&other, &surface)],
+                1e-9,
+            )?;
+            assert_eq!(overlap, None);
+        }
+
+        let circle =
+            Edge::circle([0., 0.], 1., &mut services).insert(&mut services);
+        assert!(matches!(
+            EdgeOverlap::compute([(&a, &surface), (&circle, &surface)], 1e-9),
+            Err(IntersectError::CurvedEdge { .. })
+        ));
+
+        Ok(())
+    }
+}
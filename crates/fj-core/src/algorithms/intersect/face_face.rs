@@ -3,7 +3,9 @@ use iter_fixed::IntoIteratorFixed;
 
 use crate::{geometry::SurfacePath, objects::Face};
 
-use super::{CurveFaceIntersection, SurfaceSurfaceIntersection};
+use super::{
+    CurveFaceIntersection, IntersectError, SurfaceSurfaceIntersection,
+};
 
 /// An intersection between two faces
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -24,35 +26,36 @@ pub struct FaceFaceIntersection {
 
 impl FaceFaceIntersection {
     /// Compute the intersections between two faces
-    pub fn compute(faces: [&Face; 2]) -> Option<Self> {
+    ///
+    /// Returns an error, if the intersection between the faces can't be
+    /// computed. See [`SurfaceSurfaceIntersection::compute`] and
+    /// [`CurveFaceIntersection::compute`].
+    pub fn compute(faces: [&Face; 2]) -> Result<Option<Self>, IntersectError> {
         let surfaces = faces.map(|face| face.surface().clone());
 
         let intersection_curves =
-            match SurfaceSurfaceIntersection::compute(surfaces) {
+            match SurfaceSurfaceIntersection::compute(surfaces)? {
                 Some(intersection) => intersection.intersection_curves,
-                None => return None,
+                None => return Ok(None),
             };
 
-        let curve_face_intersections = intersection_curves
+        let [a, b] = intersection_curves
             .each_ref_ext()
             .into_iter_fixed()
             .zip(faces)
             .map(|(curve, face)| CurveFaceIntersection::compute(curve, face))
             .collect::<[_; 2]>();
 
-        let intersection_intervals = {
-            let [a, b] = curve_face_intersections;
-            a.merge(&b)
-        };
+        let intersection_intervals = a?.merge(&b?);
 
         if intersection_intervals.is_empty() {
-            return None;
+            return Ok(None);
         }
 
-        Some(Self {
+        Ok(Some(Self {
             intersection_curves,
             intersection_intervals,
-        })
+        }))
     }
 }
 
@@ -97,7 +100,7 @@ mod tests {
         });
 
         let intersection = FaceFaceIntersection::compute([&a, &b]);
-        assert!(intersection.is_none());
+        assert_eq!(intersection, Ok(None));
 
         services.only_validate([a, b]);
     }
@@ -138,10 +141,10 @@ mod tests {
             CurveFaceIntersection::from_intervals([[[-1.], [1.]]]);
         assert_eq!(
             intersection,
-            Some(FaceFaceIntersection {
+            Ok(Some(FaceFaceIntersection {
                 intersection_curves: expected_curves,
                 intersection_intervals: expected_intervals
-            })
+            }))
         );
 
         services.only_validate([a, b]);
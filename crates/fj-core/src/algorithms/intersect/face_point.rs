@@ -9,12 +9,19 @@ use crate::{
 
 use super::{
     ray_segment::RaySegmentIntersection, HorizontalRayToTheRight, Intersect,
+    IntersectError,
 };
 
 impl Intersect for (&Face, &Point<2>) {
     type Intersection = FacePointIntersection;
 
     fn intersect(self) -> Option<Self::Intersection> {
+        self.try_intersect().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_intersect(
+        self,
+    ) -> Result<Option<Self::Intersection>, IntersectError> {
         let (face, point) = self;
 
         let ray = HorizontalRayToTheRight { origin: *point };
@@ -27,14 +34,13 @@ impl Intersect for (&Face, &Point<2>) {
             // first segment. The logic in the loop properly takes care of that,
             // as long as we initialize the `previous_hit` variable with the
             // result of the last segment.
-            let mut previous_hit = cycle
-                .edges()
-                .iter()
-                .last()
-                .and_then(|edge| (&ray, edge).intersect());
+            let mut previous_hit = match cycle.edges().iter().last() {
+                Some(edge) => (&ray, edge).try_intersect()?,
+                None => None,
+            };
 
             for (edge, next_edge) in cycle.edges().pairs() {
-                let hit = (&ray, edge).intersect();
+                let hit = (&ray, edge).try_intersect()?;
 
                 let count_hit = match (hit, previous_hit) {
                     (
@@ -43,21 +49,21 @@ impl Intersect for (&Face, &Point<2>) {
                     ) => {
                         // If the ray starts on the boundary of the face,
                         // there's nothing to else check.
-                        return Some(FacePointIntersection::PointIsOnEdge(
+                        return Ok(Some(FacePointIntersection::PointIsOnEdge(
                             edge.clone()
-                        ));
+                        )));
                     }
                     (Some(RaySegmentIntersection::RayStartsOnOnFirstVertex), _) => {
                         let vertex = edge.start_position();
-                        return Some(
+                        return Ok(Some(
                             FacePointIntersection::PointIsOnVertex(vertex)
-                        );
+                        ));
                     }
                     (Some(RaySegmentIntersection::RayStartsOnSecondVertex), _) => {
                         let vertex = next_edge.start_position();
-                        return Some(
+                        return Ok(Some(
                             FacePointIntersection::PointIsOnVertex(vertex)
-                        );
+                        ));
                     }
                     (Some(RaySegmentIntersection::RayHitsSegment), _) => {
                         // We're hitting a segment right-on. Clear case.
@@ -108,9 +114,9 @@ impl Intersect for (&Face, &Point<2>) {
         }
 
         if num_hits % 2 == 1 {
-            Some(FacePointIntersection::PointIsInsideFace)
+            Ok(Some(FacePointIntersection::PointIsInsideFace))
         } else {
-            None
+            Ok(None)
         }
     }
 }
@@ -134,7 +140,9 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::intersect::{face_point::FacePointIntersection, Intersect},
+        algorithms::intersect::{
+            face_point::FacePointIntersection, Intersect, IntersectError,
+        },
         objects::{Cycle, Face},
         operations::{BuildCycle, BuildFace, Insert, UpdateFace, UpdateRegion},
         services::Services,
@@ -383,4 +391,29 @@ mod tests {
 
         services.only_validate(face);
     }
+
+    #[test]
+    fn face_with_circle() {
+        let mut services = Services::new();
+
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            Cycle::circle([0., 0.], 1., &mut services)
+                                .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                });
+        let point = Point::from([0., 0.]);
+
+        let edge = face.region().exterior().edges().nth(0).unwrap().clone();
+        assert_eq!(
+            (&face, &point).try_intersect(),
+            Err(IntersectError::CurvedEdge { edge })
+        );
+
+        services.only_validate(face);
+    }
 }
@@ -1,4 +1,9 @@
 //! Intersection algorithms
+//!
+//! The algorithms in this module don't insert any objects. They return plain
+//! geometry, like paths and intervals in curve coordinates, and only need
+//! shared access to the objects they operate on. Callers that need objects
+//! can build them from the results.
 
 pub mod face_point;
 pub mod ray_edge;
@@ -7,15 +12,23 @@ pub mod ray_segment;
 
 mod curve_edge;
 mod curve_face;
+mod edge_edge;
 mod face_face;
 mod line_segment;
 mod surface_surface;
 
 use fj_math::{Point, Vector};
 
+use crate::{
+    geometry::SurfacePath,
+    objects::{Edge, Surface},
+    storage::Handle,
+};
+
 pub use self::{
     curve_edge::CurveEdgeIntersection,
     curve_face::{CurveFaceIntersection, CurveFaceIntersectionInterval},
+    edge_edge::EdgeOverlap,
     face_face::FaceFaceIntersection,
     line_segment::LineSegmentIntersection,
     surface_surface::SurfaceSurfaceIntersection,
@@ -32,7 +45,59 @@ pub trait Intersect {
     type Intersection;
 
     /// Compute the intersection between a tuple of objects
+    ///
+    /// # Panics
+    ///
+    /// Implementations might panic, if they don't support computing the
+    /// intersection between the provided objects. Use
+    /// [`Intersect::try_intersect`] to get an error instead.
     fn intersect(self) -> Option<Self::Intersection>;
+
+    /// Compute the intersection, if supported for the provided objects
+    ///
+    /// Returns an error, if computing the intersection between the provided
+    /// objects is not supported. Implementations that can't fail don't need to
+    /// override this method.
+    fn try_intersect(self) -> Result<Option<Self::Intersection>, IntersectError>
+    where
+        Self: Sized,
+    {
+        Ok(self.intersect())
+    }
+}
+
+/// Error computing an intersection
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum IntersectError {
+    /// Intersecting with a curved surface is not supported yet
+    #[error(
+        "Computing intersection with curved surface is not supported yet\n\
+        - Surface: {surface:#?}"
+    )]
+    CurvedSurface {
+        /// The curved surface
+        surface: Handle<Surface>,
+    },
+
+    /// Intersecting with a curved edge is not supported yet
+    #[error(
+        "Computing intersection with curved edge is not supported yet\n\
+        - Edge: {edge:#?}"
+    )]
+    CurvedEdge {
+        /// The curved edge
+        edge: Handle<Edge>,
+    },
+
+    /// Intersecting with a curved path is not supported yet
+    #[error(
+        "Computing intersection with curved path is not supported yet\n\
+        - Path: {path:?}"
+    )]
+    CurvedPath {
+        /// The curved path
+        path: SurfacePath,
+    },
 }
 
 /// A horizontal ray that goes to the right
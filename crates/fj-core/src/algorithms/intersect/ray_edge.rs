@@ -9,18 +9,24 @@ use crate::{
     storage::Handle,
 };
 
-use super::ray_segment::RaySegmentIntersection;
+use super::{ray_segment::RaySegmentIntersection, IntersectError};
 
 impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<Edge>) {
     type Intersection = RaySegmentIntersection;
 
     fn intersect(self) -> Option<Self::Intersection> {
+        self.try_intersect().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_intersect(
+        self,
+    ) -> Result<Option<Self::Intersection>, IntersectError> {
         let (ray, edge) = self;
 
         let line = match edge.path() {
             SurfacePath::Line(line) => line,
-            SurfacePath::Circle(_) => {
-                todo!("Casting rays against circles is not supported yet")
+            SurfacePath::Circle(_) | SurfacePath::Ellipse(_) => {
+                return Err(IntersectError::CurvedEdge { edge: edge.clone() });
             }
         };
 
@@ -30,6 +36,6 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<Edge>) {
             .map(|point| line.point_from_line_coords(point));
         let segment = Segment::from_points(points);
 
-        (ray, &segment).intersect()
+        Ok((ray, &segment).intersect())
     }
 }
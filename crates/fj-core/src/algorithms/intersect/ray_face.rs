@@ -9,18 +9,26 @@ use crate::{
     storage::Handle,
 };
 
-use super::{HorizontalRayToTheRight, Intersect};
+use super::{HorizontalRayToTheRight, Intersect, IntersectError};
 
 impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
     type Intersection = RayFaceIntersection;
 
     fn intersect(self) -> Option<Self::Intersection> {
+        self.try_intersect().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_intersect(
+        self,
+    ) -> Result<Option<Self::Intersection>, IntersectError> {
         let (ray, face) = self;
 
         let plane = match face.surface().geometry().u {
-            GlobalPath::Circle(_) => todo!(
-                "Casting a ray against a swept circle is not supported yet"
-            ),
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                return Err(IntersectError::CurvedSurface {
+                    surface: face.surface().clone(),
+                });
+            }
             GlobalPath::Line(line) => Plane::from_parametric(
                 line.origin(),
                 line.direction(),
@@ -40,9 +48,11 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
                 .map(|[x, y, z]| robust::Coord3D { x, y, z });
 
             if robust::orient3d(a, b, c, d) == 0. {
-                return Some(RayFaceIntersection::RayHitsFaceAndAreParallel);
+                return Ok(Some(
+                    RayFaceIntersection::RayHitsFaceAndAreParallel,
+                ));
             } else {
-                return None;
+                return Ok(None);
             }
         }
 
@@ -104,11 +114,14 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
 
         if t < Scalar::ZERO {
             // Ray points away from plane.
-            return None;
+            return Ok(None);
         }
 
         let point = Point::from([u, v]);
-        let intersection = match (face, &point).intersect()? {
+        let Some(intersection) = (face, &point).try_intersect()? else {
+            return Ok(None);
+        };
+        let intersection = match intersection {
             FacePointIntersection::PointIsInsideFace => {
                 RayFaceIntersection::RayHitsFace
             }
@@ -120,7 +133,7 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
             }
         };
 
-        Some(intersection)
+        Ok(Some(intersection))
     }
 }
 
@@ -148,10 +161,12 @@ mod tests {
         algorithms::{
             intersect::{
                 ray_face::RayFaceIntersection, HorizontalRayToTheRight,
-                Intersect,
+                Intersect, IntersectError,
             },
+            sweep::Sweep,
             transform::TransformObject,
         },
+        geometry::SurfacePath,
         objects::{Cycle, Face},
         operations::{BuildCycle, BuildFace, Insert, UpdateFace, UpdateRegion},
         services::Services,
@@ -364,4 +379,37 @@ mod tests {
 
         services.only_validate(face);
     }
+
+    #[test]
+    fn ray_against_curved_face() {
+        let mut services = Services::new();
+
+        let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
+
+        let surface = (
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.),
+            &*services.objects.surfaces.xy_plane(),
+        )
+            .sweep([0., 0., 1.], &mut services);
+        let face = Face::unbound(surface.clone(), &mut services).update_region(
+            |region| {
+                region
+                    .update_exterior(|_| {
+                        Cycle::polygon(
+                            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                            &mut services,
+                        )
+                        .insert(&mut services)
+                    })
+                    .insert(&mut services)
+            },
+        );
+
+        assert_eq!(
+            (&ray, &face).try_intersect(),
+            Err(IntersectError::CurvedSurface { surface })
+        );
+
+        services.only_validate(face);
+    }
 }
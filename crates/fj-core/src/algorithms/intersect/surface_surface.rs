@@ -1,11 +1,13 @@
-use fj_math::{Line, Plane, Point, Scalar};
+use fj_math::{Line, Plane, Point};
 
 use crate::{
-    geometry::{GlobalPath, SurfacePath},
+    geometry::{GeometryContext, GlobalPath, SurfacePath},
     objects::Surface,
     storage::Handle,
 };
 
+use super::IntersectError;
+
 /// The intersection between two surfaces
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct SurfaceSurfaceIntersection {
@@ -15,31 +17,44 @@ pub struct SurfaceSurfaceIntersection {
 
 impl SurfaceSurfaceIntersection {
     /// Compute the intersection between two surfaces
-    pub fn compute(surfaces: [Handle<Surface>; 2]) -> Option<Self> {
+    ///
+    /// Currently, only intersections between planes can be computed. Returns an
+    /// error, if any of the surfaces is curved.
+    ///
+    /// Uses the default [`GeometryContext`]. See
+    /// [`SurfaceSurfaceIntersection::compute_with_context`].
+    pub fn compute(
+        surfaces: [Handle<Surface>; 2],
+    ) -> Result<Option<Self>, IntersectError> {
+        Self::compute_with_context(surfaces, &GeometryContext::default())
+    }
+
+    /// Compute the intersection between two surfaces, using a custom context
+    ///
+    /// Surfaces whose normals are parallel, according to
+    /// [`GeometryContext::are_parallel`], don't have an intersection curve.
+    pub fn compute_with_context(
+        surfaces: [Handle<Surface>; 2],
+        context: &GeometryContext,
+    ) -> Result<Option<Self>, IntersectError> {
         // Algorithm from Real-Time Collision Detection by Christer Ericson. See
         // section 5.4.4, Intersection of Two Planes.
         //
         // Adaptations were made to get the intersection curves in local
         // coordinates for each surface.
 
-        let planes = surfaces.map(|surface| plane_from_surface(&surface));
+        let [a, b] = surfaces.map(|surface| plane_from_surface(&surface));
+        let planes = [a?, b?];
 
         let [(a_distance, a_normal), (b_distance, b_normal)] =
             planes.map(|plane| plane.constant_normal_form());
 
-        let direction = a_normal.cross(&b_normal);
+        if context.are_parallel(&a_normal, &b_normal) {
+            return Ok(None);
+        }
 
+        let direction = a_normal.cross(&b_normal);
         let denom = direction.dot(&direction);
-        if denom == Scalar::ZERO {
-            // Comparing `denom` against zero looks fishy. It's probably better
-            // to compare it against an epsilon value, but I don't know how
-            // large that epsilon should be.
-            //
-            // I'll just leave it like that, until we had the opportunity to
-            // collect some experience with this code.
-            // - @hannobraun
-            return None;
-        }
 
         let origin = (b_normal * a_distance - a_normal * b_distance)
             .cross(&direction)
@@ -51,32 +66,45 @@ impl SurfaceSurfaceIntersection {
         let curves =
             planes.map(|plane| SurfacePath::Line(plane.project_line(&line)));
 
-        Some(Self {
+        Ok(Some(Self {
             intersection_curves: curves,
-        })
+        }))
     }
 }
 
-fn plane_from_surface(surface: &Surface) -> Plane {
+fn plane_from_surface(
+    surface: &Handle<Surface>,
+) -> Result<Plane, IntersectError> {
     let (line, path) = {
         let line = match surface.geometry().u {
             GlobalPath::Line(line) => line,
-            _ => todo!("Only plane-plane intersection is currently supported."),
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                return Err(IntersectError::CurvedSurface {
+                    surface: surface.clone(),
+                });
+            }
         };
 
         (line, surface.geometry().v)
     };
 
-    Plane::from_parametric(line.origin(), line.direction(), path)
+    Ok(Plane::from_parametric(
+        line.origin(),
+        line.direction(),
+        path,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Transform;
+    use fj_math::{Scalar, Transform};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::transform::TransformObject, geometry::SurfacePath,
+        algorithms::{
+            intersect::IntersectError, sweep::Sweep, transform::TransformObject,
+        },
+        geometry::{GeometryContext, SurfacePath},
         services::Services,
     };
 
@@ -98,17 +126,88 @@ mod tests {
                     &mut services
                 )
             ],),
-            None,
+            Ok(None),
         );
 
         let expected_xy = SurfacePath::u_axis();
         let expected_xz = SurfacePath::u_axis();
 
         assert_eq!(
-            SurfaceSurfaceIntersection::compute([xy, xz],),
-            Some(SurfaceSurfaceIntersection {
+            SurfaceSurfaceIntersection::compute([xy.clone(), xz],),
+            Ok(Some(SurfaceSurfaceIntersection {
                 intersection_curves: [expected_xy, expected_xz],
-            })
+            }))
         );
+
+        // Intersections with curved surfaces are not supported.
+        let cylinder = (
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.),
+            &*xy,
+        )
+            .sweep([0., 0., 1.], &mut services);
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute([xy, cylinder.clone()]),
+            Err(IntersectError::CurvedSurface { surface: cylinder }),
+        );
+    }
+
+    #[test]
+    fn nearly_parallel_planes() {
+        let mut services = Services::new();
+
+        let xy = services.objects.surfaces.xy_plane();
+        let tilted = xy
+            .clone()
+            .transform(&Transform::rotation([1e-6, 0., 0.]), &mut services);
+
+        // An angle below the default tolerance is treated as parallel.
+        let barely_tilted = xy
+            .clone()
+            .transform(&Transform::rotation([1e-14, 0., 0.]), &mut services);
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute([xy.clone(), barely_tilted]),
+            Ok(None),
+        );
+
+        let surfaces = [xy, tilted];
+
+        assert!(SurfaceSurfaceIntersection::compute(surfaces.clone())
+            .unwrap()
+            .is_some());
+
+        let context = GeometryContext {
+            parallel_max_sine: Scalar::from(1e-3),
+            ..GeometryContext::default()
+        };
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute_with_context(
+                surfaces, &context
+            ),
+            Ok(None),
+        );
+    }
+
+    #[test]
+    fn compute_concurrently() {
+        let services = Services::new();
+
+        let objects = &services.objects;
+        let surfaces =
+            [objects.surfaces.xy_plane(), objects.surfaces.xz_plane()];
+
+        let expected = SurfaceSurfaceIntersection::compute(surfaces.clone());
+
+        std::thread::scope(|scope| {
+            let threads = [0, 1].map(|_| {
+                let surfaces = surfaces.clone();
+                scope.spawn(move || {
+                    SurfaceSurfaceIntersection::compute(surfaces)
+                })
+            });
+
+            for thread in threads {
+                assert_eq!(thread.join().unwrap(), expected);
+            }
+        });
     }
 }
@@ -0,0 +1,589 @@
+//! Measure distances between objects, and lengths of objects
+//!
+//! The functions in this module work on the approximations of the objects
+//! they measure. The accuracy of the results depends on the tolerance that is
+//! used to compute those approximations.
+
+use fj_math::{Plane, Point, Scalar, Segment, Triangle};
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Cycle, Edge, Face, Surface},
+};
+
+use super::{
+    approx::{Approx, Tolerance},
+    triangulate::Triangulate,
+};
+
+/// The result of a distance measurement
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct DistanceResult {
+    /// The minimum distance between the two measured objects
+    pub distance: Scalar,
+
+    /// The closest points on the two measured objects
+    ///
+    /// The first point is on the first object, the second point is on the
+    /// second object. The distance between these points is the distance that
+    /// was measured.
+    pub closest_points: [Point<3>; 2],
+}
+
+impl DistanceResult {
+    fn from_points(closest_points: [Point<3>; 2]) -> Self {
+        let [a, b] = closest_points;
+
+        Self {
+            distance: a.distance_to(&b),
+            closest_points,
+        }
+    }
+
+    fn min(self, other: Option<Self>) -> Self {
+        match other {
+            Some(other) if other.distance <= self.distance => other,
+            _ => self,
+        }
+    }
+}
+
+/// Measure the distance between a point and a face
+///
+/// Returns `None`, if the face is empty.
+pub fn distance_point_face(
+    point: impl Into<Point<3>>,
+    face: &Face,
+    tolerance: impl Into<Tolerance>,
+) -> Option<DistanceResult> {
+    let point = point.into();
+
+    let mut result = None;
+
+    for triangle in triangles(face, tolerance.into()) {
+        let closest =
+            DistanceResult::from_points([point, triangle.closest_point(point)]);
+        result = Some(closest.min(result));
+    }
+
+    result
+}
+
+/// Measure the distance between two edges
+///
+/// Each edge must be provided along with the surface it is defined on.
+pub fn distance_edge_edge(
+    a: (&Edge, &Surface),
+    b: (&Edge, &Surface),
+    tolerance: impl Into<Tolerance>,
+) -> DistanceResult {
+    let tolerance = tolerance.into();
+
+    let segments_a = segments(a, tolerance);
+    let segments_b = segments(b, tolerance);
+
+    let mut result = None;
+
+    for segment_a in &segments_a {
+        for segment_b in &segments_b {
+            let closest = DistanceResult::from_points(
+                segment_a.closest_points(segment_b),
+            );
+            result = Some(closest.min(result));
+        }
+    }
+
+    result.expect("Approximation of an edge always has at least one segment")
+}
+
+/// Measure the distance between two faces
+///
+/// If both faces are planar and parallel, and the closest points are directly
+/// across from one another, the exact distance between the planes is returned,
+/// regardless of the tessellation of the faces. If the faces are offset against
+/// each other, such that no point of one is directly across from the other,
+/// the closest points are on their boundaries.
+///
+/// Returns `None`, if either face is empty.
+pub fn distance_face_face(
+    a: &Face,
+    b: &Face,
+    tolerance: impl Into<Tolerance>,
+) -> Option<DistanceResult> {
+    let tolerance = tolerance.into();
+
+    let triangles_a = triangles(a, tolerance);
+    let triangles_b = triangles(b, tolerance);
+
+    let mut result = None;
+
+    for triangle_a in &triangles_a {
+        for triangle_b in &triangles_b {
+            let closest = distance_triangle_triangle(triangle_a, triangle_b);
+            result = Some(closest.min(result));
+        }
+    }
+
+    let mut result = result?;
+
+    if let (Some(plane_a), Some(plane_b)) = (plane(a), plane(b)) {
+        let normal = plane_a.normal();
+
+        let are_parallel = plane_a.is_parallel_to_vector(&plane_b.u())
+            && plane_a.is_parallel_to_vector(&plane_b.v());
+
+        if are_parallel {
+            let distance = (plane_b.origin() - plane_a.origin()).dot(&normal);
+
+            if (result.distance - distance.abs()).abs() <= tolerance.inner() {
+                let [closest_a, _] = result.closest_points;
+                let closest_b = closest_a + normal * distance;
+
+                // The point across from `closest_a` might not be part of face
+                // b, if the faces are offset against each other. In that case,
+                // the closest points are on their edges or vertices, and we
+                // keep the result we already have.
+                let point = plane_b.project_point(closest_b);
+                let b_contains_point = triangles_b.iter().any(|triangle| {
+                    Triangle::from_points(
+                        triangle
+                            .points()
+                            .map(|point| plane_b.project_point(point)),
+                    )
+                    .is_ok_and(|triangle| triangle.contains(point))
+                });
+
+                if b_contains_point {
+                    result = DistanceResult {
+                        distance: distance.abs(),
+                        closest_points: [closest_a, closest_b],
+                    };
+                }
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Measure the length of an edge in model coordinates
+///
+/// `surface` must be the surface that the edge is defined on. If the surface
+/// is a plane and the edge is a line segment or a circular arc, the length is
+/// computed exactly. Otherwise, the edge is approximated by polylines of
+/// increasing resolution, until their lengths differ by less than `tolerance`.
+///
+/// # Errors
+///
+/// Returns an error, if the length doesn't converge before the polyline has
+/// [`MAX_LENGTH_SEGMENTS`] segments.
+pub fn edge_length(
+    edge: &Edge,
+    surface: &Surface,
+    tolerance: impl Into<Tolerance>,
+) -> Result<Scalar, LengthError> {
+    let tolerance = tolerance.into();
+    let geometry = surface.geometry();
+
+    let [a, b] = edge.boundary().inner;
+    let range = (b.t - a.t).abs();
+
+    if let GlobalPath::Line(_) = geometry.u {
+        // The surface is a plane, which means the conversion from surface to
+        // model coordinates is affine. Lines stay lines, and circles stay
+        // circles, unless the plane's axes distort them.
+        match edge.path() {
+            SurfacePath::Line(line) => {
+                let direction =
+                    geometry.vector_from_surface_coords(line.direction());
+                return Ok(direction.magnitude() * range);
+            }
+            SurfacePath::Circle(circle) => {
+                let [a, b] = [circle.a(), circle.b()]
+                    .map(|v| geometry.vector_from_surface_coords(v));
+
+                let is_circle = (a.magnitude() - b.magnitude()).abs()
+                    <= tolerance.inner()
+                    && a.dot(&b).abs() <= tolerance.inner();
+                if is_circle {
+                    return Ok(a.magnitude() * range);
+                }
+            }
+            SurfacePath::Ellipse(_) => {
+                // There's no closed-form solution for the arc length of an
+                // ellipse.
+            }
+        }
+    }
+
+    // If we make it here, there's no exact solution available. Approximate the
+    // edge with polylines of increasing resolution, until their length
+    // converges.
+    let polyline_length = |num_segments: u32| {
+        let points = (0..=num_segments).map(|i| {
+            let t = a.t + (b.t - a.t) * f64::from(i) / f64::from(num_segments);
+            edge.point_at([t], surface)
+        });
+
+        Scalar::sum_stable(
+            points
+                .clone()
+                .zip(points.skip(1))
+                .map(|(a, b)| a.distance_to(&b)),
+        )
+    };
+
+    let mut num_segments = 4;
+    let mut length = polyline_length(num_segments);
+
+    while num_segments < MAX_LENGTH_SEGMENTS {
+        num_segments *= 2;
+        let refined_length = polyline_length(num_segments);
+
+        if (refined_length - length).abs() < tolerance.inner() {
+            return Ok(refined_length);
+        }
+
+        length = refined_length;
+    }
+
+    Err(LengthError::NotConverged {
+        edge: Box::new(edge.clone()),
+        num_segments,
+    })
+}
+
+/// Measure the perimeter of a cycle in model coordinates
+///
+/// `surface` must be the surface that the cycle is defined on. This is the sum
+/// of the lengths of the cycle's edges. See [`edge_length`] for how
+/// `tolerance` is used, and which errors can occur.
+pub fn cycle_perimeter(
+    cycle: &Cycle,
+    surface: &Surface,
+    tolerance: impl Into<Tolerance>,
+) -> Result<Scalar, LengthError> {
+    let tolerance = tolerance.into();
+
+    let lengths = cycle
+        .edges()
+        .iter()
+        .map(|edge| edge_length(edge, surface, tolerance))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Scalar::sum_stable(lengths))
+}
+
+/// The maximum number of segments used to compute the length of an edge
+///
+/// See [`edge_length`].
+pub const MAX_LENGTH_SEGMENTS: u32 = 1 << 16;
+
+/// Error measuring the length of an object
+///
+/// Returned by [`edge_length`] and [`cycle_perimeter`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LengthError {
+    /// The numerical computation of an edge's length didn't converge
+    #[error(
+        "Length of edge did not converge within {num_segments} segments\n\
+        - Edge: {edge:#?}"
+    )]
+    NotConverged {
+        /// The edge whose length was computed
+        edge: Box<Edge>,
+
+        /// The number of segments of the last approximation
+        num_segments: u32,
+    },
+}
+
+fn distance_triangle_triangle(
+    a: &Triangle<3>,
+    b: &Triangle<3>,
+) -> DistanceResult {
+    // This doesn't handle triangles that intersect each other. That is fine,
+    // as the faces of a valid model don't intersect.
+
+    let mut result = None;
+
+    for point in a.points() {
+        let closest =
+            DistanceResult::from_points([point, b.closest_point(point)]);
+        result = Some(closest.min(result));
+    }
+    for point in b.points() {
+        let closest =
+            DistanceResult::from_points([a.closest_point(point), point]);
+        result = Some(closest.min(result));
+    }
+
+    let segments = |triangle: &Triangle<3>| {
+        let [p0, p1, p2] = triangle.points();
+        [[p0, p1], [p1, p2], [p2, p0]].map(Segment::from_points)
+    };
+
+    for segment_a in segments(a) {
+        for segment_b in segments(b) {
+            let closest = DistanceResult::from_points(
+                segment_a.closest_points(&segment_b),
+            );
+            result = Some(closest.min(result));
+        }
+    }
+
+    result.expect("Just computed distances between triangles")
+}
+
+fn triangles(face: &Face, tolerance: Tolerance) -> Vec<Triangle<3>> {
+    face.approx(tolerance)
+        .triangulate()
+        .triangles()
+        .map(|triangle| triangle.inner)
+        .collect()
+}
+
+fn segments(
+    (edge, surface): (&Edge, &Surface),
+    tolerance: Tolerance,
+) -> Vec<Segment<3>> {
+    let mut points = (edge, surface)
+        .approx(tolerance)
+        .points()
+        .into_iter()
+        .map(|point| point.global_form)
+        .collect::<Vec<_>>();
+
+    let [_, end] = edge.boundary().inner;
+    points.push(
+        surface
+            .geometry()
+            .point_from_surface_coords(edge.path().point_from_path_coords(end)),
+    );
+
+    points
+        .windows(2)
+        .map(|points| Segment::from_points([points[0], points[1]]))
+        .collect()
+}
+
+fn plane(face: &Face) -> Option<Plane> {
+    let surface = face.surface().geometry();
+
+    match surface.u {
+        GlobalPath::Line(line) => Some(Plane::from_parametric(
+            line.origin(),
+            line.direction(),
+            surface.v,
+        )),
+        GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{assert_abs_diff, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        geometry::{GlobalPath, SurfaceGeometry},
+        objects::{Cycle, Edge, Face, Surface},
+        operations::{BuildCycle, BuildEdge, BuildFace, Insert},
+        services::Services,
+    };
+
+    use super::{
+        cycle_perimeter, distance_edge_edge, distance_face_face,
+        distance_point_face, edge_length, LengthError, MAX_LENGTH_SEGMENTS,
+    };
+
+    #[test]
+    fn parallel_faces() {
+        let mut services = Services::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+        let surface_a = services.objects.surfaces.xy_plane();
+        let surface_b =
+            surface_a.clone().translate([0., 0., 2.5], &mut services);
+
+        let a = Face::polygon(surface_a, square, &mut services);
+        let b = Face::polygon(surface_b, square, &mut services);
+
+        let result = distance_face_face(&a, &b, 0.001).unwrap();
+        assert_eq!(result.distance, Scalar::from(2.5));
+
+        let [closest_a, closest_b] = result.closest_points;
+        assert_eq!(closest_a.z, Scalar::ZERO);
+        assert_eq!(closest_b.z, Scalar::from(2.5));
+
+        services.only_validate([a, b]);
+    }
+
+    #[test]
+    fn parallel_faces_offset() {
+        let mut services = Services::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+        let surface_a = services.objects.surfaces.xy_plane();
+        let surface_b =
+            surface_a.clone().translate([1.05, 0., 0.05], &mut services);
+
+        let a = Face::polygon(surface_a, square, &mut services);
+        let b = Face::polygon(surface_b, square, &mut services);
+
+        // The distance between the faces is within the tolerance of the
+        // distance between their planes, but they don't overlap.
+        let result = distance_face_face(&a, &b, 0.1).unwrap();
+        assert_abs_diff!(result.distance, Scalar::from(0.05 * 2f64.sqrt()));
+
+        let [closest_a, closest_b] = result.closest_points;
+        assert_abs_diff!(closest_a.x, Scalar::ONE);
+        assert_eq!(closest_a.z, Scalar::ZERO);
+        assert_abs_diff!(closest_b.x, Scalar::from(1.05));
+        assert_eq!(closest_b.z, Scalar::from(0.05));
+
+        services.only_validate([a, b]);
+    }
+
+    #[test]
+    fn perpendicular_faces_sharing_edge() {
+        let mut services = Services::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+        let a = Face::polygon(
+            services.objects.surfaces.xy_plane(),
+            square,
+            &mut services,
+        );
+        let b = Face::polygon(
+            services.objects.surfaces.xz_plane(),
+            square,
+            &mut services,
+        );
+
+        let result = distance_face_face(&a, &b, 0.001).unwrap();
+        assert_eq!(result.distance, Scalar::ZERO);
+
+        for point in result.closest_points {
+            assert_eq!(point.y, Scalar::ZERO);
+            assert_eq!(point.z, Scalar::ZERO);
+        }
+
+        services.only_validate([a, b]);
+    }
+
+    #[test]
+    fn point_face() {
+        let mut services = Services::new();
+
+        let face = Face::polygon(
+            services.objects.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+
+        let result = distance_point_face([0.5, 0.5, 2.], &face, 0.001).unwrap();
+        assert_eq!(result.distance, Scalar::from(2.));
+        assert_eq!(result.closest_points[1], Point::from([0.5, 0.5, 0.]));
+
+        services.only_validate(face);
+    }
+
+    #[test]
+    fn edge_edge() {
+        let mut services = Services::new();
+
+        let face = Face::polygon(
+            services.objects.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+
+        let edges = face.region().exterior().edges();
+        let surface = face.surface();
+
+        let result = distance_edge_edge(
+            (edges.nth(0).unwrap(), surface),
+            (edges.nth(2).unwrap(), surface),
+            0.001,
+        );
+        assert_eq!(result.distance, Scalar::from(1.));
+
+        let result = distance_edge_edge(
+            (edges.nth(0).unwrap(), surface),
+            (edges.nth(1).unwrap(), surface),
+            0.001,
+        );
+        assert_eq!(result.distance, Scalar::ZERO);
+    }
+
+    #[test]
+    fn length_of_circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let edge = Edge::circle([1., 2.], 3., &mut services);
+
+        assert_abs_diff!(
+            edge_length(&edge, &surface, 0.001)?,
+            Scalar::TAU * 3.
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn length_on_curved_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(2.),
+            v: Vector::from([0., 0., 1.]),
+        });
+        let edge =
+            Edge::line_segment([[0., 0.], [PI, 0.]], None, &mut services);
+
+        let tolerance = 0.001;
+        assert_abs_diff!(
+            edge_length(&edge, &surface, tolerance)?,
+            Scalar::PI * 2.,
+            epsilon = tolerance
+        );
+
+        // A tolerance this small can't be reached with floating-point
+        // numbers, so the computation must give up at some point.
+        assert!(matches!(
+            edge_length(&edge, &surface, 1e-30),
+            Err(LengthError::NotConverged {
+                num_segments: MAX_LENGTH_SEGMENTS,
+                ..
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn perimeter() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+
+        assert_eq!(
+            cycle_perimeter(&square, &surface, 0.001)?,
+            Scalar::from(4.)
+        );
+
+        Ok(())
+    }
+}
@@ -12,7 +12,14 @@
 
 pub mod approx;
 pub mod bounding_volume;
+pub mod cancellation;
 pub mod intersect;
+pub mod measure;
+pub mod silhouette;
+pub mod slice;
 pub mod sweep;
 pub mod transform;
 pub mod triangulate;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
@@ -0,0 +1,311 @@
+//! Silhouettes of solids, for generating 2D drawings
+//!
+//! Like slicing, this works on the triangulation of the solid. The accuracy of
+//! the silhouette of curved faces depends on the tolerance that is used to
+//! compute that triangulation.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::{
+    half_edge::NonManifoldError,
+    mesh::{Index, Mesh},
+};
+use fj_math::{Plane, PolyChain, Scalar, Vector};
+
+use crate::{geometry::GeometryContext, objects::Solid};
+
+use super::{approx::Tolerance, triangulate::Triangulate};
+
+/// Compute the silhouette of a solid, as seen from the provided direction
+///
+/// `view_direction` points from the viewer towards the solid. Each triangle of
+/// the solid's triangulation is classified as front-facing, if its normal
+/// points against the view direction, or back-facing otherwise. Triangles that
+/// are seen exactly edge-on count as back-facing. The silhouette consists of
+/// the edges between front-facing and back-facing triangles.
+///
+/// Flat faces are classified as a whole, as all of their triangles share a
+/// normal. Curved faces are classified per triangle, which means their part of
+/// the silhouette runs along the edges of their triangulation.
+///
+/// The silhouette edges are chained into connected polylines. Each chain goes
+/// counter-clockwise around the front-facing part of the solid, as seen by the
+/// viewer. Closed chains repeat their first point at the end. See
+/// [`PolyChain::is_closed`].
+///
+/// Vertices of the triangulation that are within
+/// [`GeometryContext::identical_max_distance`] of each other are considered
+/// identical, when chaining the edges.
+///
+/// # Errors
+///
+/// Returns an error, if the triangulation of the solid is not manifold, or if
+/// its triangles are not oriented consistently. Either of those means that a
+/// directed edge is shared by more than one triangle, and the triangles on
+/// either side of that edge can't be determined.
+///
+/// # Panics
+///
+/// Panics, if `view_direction` has zero length.
+pub fn silhouette(
+    solid: &Solid,
+    view_direction: impl Into<Vector<3>>,
+    tolerance: impl Into<Tolerance>,
+    context: &GeometryContext,
+) -> Result<Vec<PolyChain<3>>, NonManifoldError> {
+    let view_direction = view_direction.into();
+    assert!(
+        view_direction.magnitude() > Scalar::ZERO,
+        "View direction has zero length"
+    );
+
+    // Weld vertices, so triangles that share an edge in the solid also share
+    // the exact same points in the mesh. The silhouette edges are chained
+    // based on that.
+    let mut mesh = Mesh::with_weld_tolerance(context.identical_max_distance);
+    (solid, tolerance.into()).triangulate_into_mesh(&mut mesh);
+
+    // Adjacent triangles are found through the half-edge structure. It only
+    // pairs up edges unambiguously, if every directed edge belongs to exactly
+    // one triangle.
+    let half_edges = mesh.build_half_edge_structure()?;
+
+    let vertices = mesh.vertices().collect::<Vec<_>>();
+    let is_front_facing = mesh
+        .indices()
+        .collect::<Vec<_>>()
+        .chunks_exact(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+            let normal = (b - a).cross(&(c - a));
+            normal.dot(&view_direction) < Scalar::ZERO
+        })
+        .collect::<Vec<_>>();
+
+    // Silhouette edges keep the direction they have in their front-facing
+    // triangle. That makes them form chains that go around the front-facing
+    // part of the solid.
+    let mut edges = BTreeMap::<Index, BTreeSet<Index>>::new();
+    for (index, half_edge) in half_edges.half_edges().iter().enumerate() {
+        if !is_front_facing[half_edge.face] {
+            continue;
+        }
+
+        let twin_is_front_facing = half_edge
+            .twin
+            .map(|twin| is_front_facing[half_edges.half_edges()[twin].face])
+            .unwrap_or(false);
+        if !twin_is_front_facing {
+            edges
+                .entry(half_edge.origin)
+                .or_default()
+                .insert(half_edges.target(index));
+        }
+    }
+
+    let chains = chain_edges(edges)
+        .into_iter()
+        .map(|chain| {
+            PolyChain::from_points(
+                chain.into_iter().map(|index| vertices[index as usize]),
+            )
+        })
+        .collect();
+
+    Ok(chains)
+}
+
+/// Project polygonal chains into a plane
+///
+/// Uses an orthographic projection along the plane's normal, and returns the
+/// points in the plane's coordinates. Consecutive points that end up at the
+/// same position are merged.
+///
+/// Projecting the result of [`silhouette`] into a plane that is perpendicular
+/// to the view direction results in a 2D drawing of the solid.
+pub fn project_to_plane(
+    chains: &[PolyChain<3>],
+    plane: &Plane,
+) -> Vec<PolyChain<2>> {
+    chains
+        .iter()
+        .map(|chain| {
+            let mut points = chain
+                .points()
+                .map(|point| plane.project_point(point))
+                .collect::<Vec<_>>();
+            points.dedup();
+
+            PolyChain::from_points(points)
+        })
+        .collect()
+}
+
+/// Chain directed edges into sequences of vertices
+///
+/// Chains that have open ends are started first, at vertices without any
+/// incoming edges. All remaining edges form closed chains, which end with the
+/// vertex they started with.
+fn chain_edges(mut edges: BTreeMap<Index, BTreeSet<Index>>) -> Vec<Vec<Index>> {
+    let targets = edges.values().flatten().copied().collect::<BTreeSet<_>>();
+    let open_starts = edges
+        .keys()
+        .copied()
+        .filter(|origin| !targets.contains(origin))
+        .collect::<Vec<_>>();
+
+    let mut chains = Vec::new();
+
+    for start in open_starts {
+        while edges.contains_key(&start) {
+            chains.push(follow_chain(start, &mut edges));
+        }
+    }
+    while let Some(&start) = edges.keys().next() {
+        chains.push(follow_chain(start, &mut edges));
+    }
+
+    chains
+}
+
+fn follow_chain(
+    start: Index,
+    edges: &mut BTreeMap<Index, BTreeSet<Index>>,
+) -> Vec<Index> {
+    let mut chain = vec![start];
+    let mut current = start;
+
+    while let Some(targets) = edges.get_mut(&current) {
+        let Some(next) = targets.pop_first() else {
+            break;
+        };
+        if targets.is_empty() {
+            edges.remove(&current);
+        }
+
+        chain.push(next);
+        current = next;
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Plane, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::fixtures, geometry::GeometryContext, objects::Solid,
+        services::Services,
+    };
+
+    use super::{project_to_plane, silhouette};
+
+    #[test]
+    fn cube_along_diagonal() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let chains =
+            silhouette(&cube, [1., 1., 1.], 0.01, &GeometryContext::default())?;
+        assert_eq!(chains.len(), 1);
+
+        // The silhouette runs through all corners of the cube, except for the
+        // one that is closest to the viewer, and the one opposite of it.
+        let chain = &chains[0];
+        assert!(chain.is_closed());
+        assert_eq!(chain.len(), 7);
+
+        let corners = chain.points().collect::<BTreeSet<_>>();
+        let expected = [
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.],
+            [0., 1., 1.],
+            [0., 0., 1.],
+            [1., 0., 1.],
+        ]
+        .map(Point::from);
+        assert_eq!(corners, BTreeSet::from(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cube_along_z() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let chains =
+            silhouette(&cube, [0., 0., 1.], 0.01, &GeometryContext::default())?;
+
+        let plane = Plane::from_parametric(
+            Point::origin(),
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+        let outlines = project_to_plane(&chains, &plane);
+        assert_eq!(outlines.len(), 1);
+
+        let outline = &outlines[0];
+        assert!(outline.is_closed());
+        assert_eq!(outline.len(), 5);
+
+        let corners = outline.points().collect::<BTreeSet<_>>();
+        let expected =
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(Point::from);
+        assert_eq!(corners, BTreeSet::from(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder_along_x() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let cylinder = fixtures::cylinder(&mut services);
+
+        let chains = silhouette(
+            &cylinder,
+            [1., 0., 0.],
+            fixtures::tolerance(),
+            &GeometryContext::default(),
+        )?;
+        assert_eq!(chains.len(), 1);
+
+        // The silhouette goes around the half of the cylinder's side that faces
+        // the viewer: Down one side, along the bottom edge, up the other side,
+        // and back along the top edge.
+        let chain = &chains[0];
+        assert!(chain.is_closed());
+        assert!(chain.len() > 5);
+
+        for point in chain.points() {
+            let radius = point.coords.xy().magnitude();
+            assert!((radius - Scalar::ONE).abs() < Scalar::from(1e-12));
+            assert!(point.x <= Scalar::ZERO);
+            assert!(point.z == Scalar::ZERO || point.z == Scalar::ONE);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_manifold() {
+        let mut services = Services::new();
+
+        // Two cubes that share an edge. Both of them have triangles on either
+        // side of that edge.
+        let a = fixtures::cube([0., 0.], &mut services);
+        let b = fixtures::cube([1., 1.], &mut services);
+        let solid = Solid::new(a.shells().iter().chain(b.shells()).cloned());
+
+        let err =
+            silhouette(&solid, [1., 0., 0.], 0.01, &GeometryContext::default())
+                .unwrap_err();
+
+        // The shared edge, in both directions.
+        assert_eq!(err.edges.len(), 2);
+    }
+}
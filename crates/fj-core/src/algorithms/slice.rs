@@ -0,0 +1,87 @@
+//! Slice solids into stacks of parallel cross-sections
+//!
+//! The functions in this module work on the triangulation of the objects they
+//! slice. The accuracy of the results depends on the tolerance that is used to
+//! compute that triangulation.
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Scalar, Vector};
+
+use crate::{geometry::GeometryContext, objects::Solid};
+
+use super::{approx::Tolerance, triangulate::Triangulate};
+
+pub use fj_interop::mesh::SliceLayer;
+
+/// Slice a solid into a stack of parallel cross-sections
+///
+/// The solid is triangulated once, using the provided tolerance. Vertices of the
+/// triangulation that are within [`GeometryContext::identical_max_distance`]
+/// of each other are merged. That triangulation is then sliced using
+/// [`Mesh::slice_stack`]. See there for details on the layers.
+///
+/// # Panics
+///
+/// Panics, if `axis` has zero length, or if `spacing` is not positive.
+pub fn slice_stack(
+    solid: &Solid,
+    axis: impl Into<Vector<3>>,
+    spacing: impl Into<Scalar>,
+    tolerance: impl Into<Tolerance>,
+    context: &GeometryContext,
+) -> Vec<SliceLayer> {
+    // Weld vertices, so triangles that share an edge in the solid also share
+    // the exact same points in the mesh. The contours are stitched together
+    // based on that.
+    let mut mesh = Mesh::with_weld_tolerance(context.identical_max_distance);
+    (solid, tolerance.into()).triangulate_into_mesh(&mut mesh);
+
+    mesh.slice_stack(axis, spacing)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Scalar};
+
+    use crate::{
+        algorithms::fixtures, geometry::GeometryContext, services::Services,
+    };
+
+    use super::slice_stack;
+
+    #[test]
+    fn slice_cube() {
+        let mut services = Services::new();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let layers = slice_stack(
+            &cube,
+            [0., 0., 1.],
+            0.1,
+            0.01,
+            &GeometryContext::default(),
+        );
+        assert_eq!(layers.len(), 10);
+
+        for (i, layer) in layers.iter().enumerate() {
+            assert_abs_diff!(layer.height, Scalar::from(0.05 + 0.1 * i as f64));
+            assert!(layer.open_contours.is_empty());
+            assert_eq!(layer.contours.len(), 1);
+
+            let contour = &layer.contours[0];
+            for point in contour {
+                assert!(point.u >= Scalar::ZERO && point.u <= Scalar::ONE);
+                assert!(point.v >= Scalar::ZERO && point.v <= Scalar::ONE);
+            }
+
+            // The contour winds counter-clockwise and encloses the whole
+            // cross-section of the cube.
+            let area = Scalar::sum_stable((0..contour.len()).map(|i| {
+                let [a, b] = [contour[i], contour[(i + 1) % contour.len()]];
+                a.coords.cross2d(&b.coords)
+            })) / 2.;
+            assert_abs_diff!(area, Scalar::ONE);
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepError};
 
 impl Sweep for (&Edge, &Handle<Vertex>, &Surface, Option<Color>) {
     type Swept = (Handle<Face>, Handle<Edge>);
@@ -18,21 +18,21 @@ impl Sweep for (&Edge, &Handle<Vertex>, &Surface, Option<Color>) {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept {
+    ) -> Result<Self::Swept, SweepError> {
         let (edge, next_vertex, surface, color) = self;
         let path = path.into();
 
         let surface =
-            (edge.path(), surface).sweep_with_cache(path, cache, services);
+            (edge.path(), surface).sweep_with_cache(path, cache, services)?;
 
         // Next, we need to define the boundaries of the face. Let's start with
         // the global vertices and edges.
         let (vertices, curves) = {
             let [a, b] = [edge.start_vertex(), next_vertex].map(Clone::clone);
             let (curve_up, [_, c]) =
-                b.clone().sweep_with_cache(path, cache, services);
+                b.clone().sweep_with_cache(path, cache, services)?;
             let (curve_down, [_, d]) =
-                a.clone().sweep_with_cache(path, cache, services);
+                a.clone().sweep_with_cache(path, cache, services)?;
 
             (
                 [a, b, c, d],
@@ -111,6 +111,6 @@ impl Sweep for (&Edge, &Handle<Vertex>, &Surface, Option<Color>) {
         // And we're done creating the face! All that's left to do is build our
         // return values.
         let face = face.insert(services);
-        (face, edge_top)
+        Ok((face, edge_top))
     }
 }
@@ -11,7 +11,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepError};
 
 impl Sweep for Handle<Face> {
     type Swept = Handle<Shell>;
@@ -21,17 +21,18 @@ impl Sweep for Handle<Face> {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept {
+    ) -> Result<Self::Swept, SweepError> {
         let path = path.into();
 
         let mut faces = Vec::new();
 
         let is_negative_sweep = {
             let u = match self.surface().geometry().u {
-                GlobalPath::Circle(_) => todo!(
-                    "Sweeping from faces defined in round surfaces is not \
-                    supported"
-                ),
+                GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                    return Err(SweepError::CurvedSurface {
+                        surface: self.surface().geometry(),
+                    });
+                }
                 GlobalPath::Line(line) => line.direction(),
             };
             let v = self.surface().geometry().v;
@@ -68,7 +69,7 @@ impl Sweep for Handle<Face> {
                     self.surface().deref(),
                     self.region().color(),
                 )
-                    .sweep_with_cache(path, cache, services);
+                    .sweep_with_cache(path, cache, services)?;
 
                 faces.push(face);
 
@@ -95,6 +96,6 @@ impl Sweep for Handle<Face> {
         let top_face = top_face.insert(services);
         faces.push(top_face);
 
-        Shell::new(faces).insert(services)
+        Ok(Shell::new(faces).insert(services))
     }
 }
@@ -3,25 +3,35 @@
 mod edge;
 mod face;
 mod path;
+mod poly_chain;
 mod sketch;
+mod twist;
 mod vertex;
 
 use std::collections::BTreeMap;
 
-use fj_math::Vector;
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
-    objects::{Curve, Vertex},
+    geometry::{GeometryContext, SurfaceGeometry},
+    objects::{Curve, Edge, Vertex},
     services::Services,
     storage::{Handle, ObjectId},
 };
 
+pub use self::{poly_chain::SweepAlongPolyChain, twist::SweepWithTwist};
+
 /// Sweep an object along a path to create another object
 pub trait Sweep: Sized {
     /// The object that is created by sweeping the implementing object
     type Swept;
 
     /// Sweep the object along the given path
+    ///
+    /// # Panics
+    ///
+    /// Panics, if sweeping the object is not supported. Use
+    /// [`Sweep::try_sweep`] to get an error instead.
     fn sweep(
         self,
         path: impl Into<Vector<3>>,
@@ -29,15 +39,146 @@ pub trait Sweep: Sized {
     ) -> Self::Swept {
         let mut cache = SweepCache::default();
         self.sweep_with_cache(path, &mut cache, services)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Sweep the object along the given path, using the provided cache
+    ///
+    /// Returns an error, if sweeping the object is not supported.
     fn sweep_with_cache(
         self,
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept;
+    ) -> Result<Self::Swept, SweepError>;
+
+    /// Sweep the object along the given path, if the path is long enough
+    ///
+    /// Uses [`GeometryContext::distinct_min_distance`] as the minimum path
+    /// length. Any path shorter than that would result in coincident vertices,
+    /// which would fail validation anyway.
+    ///
+    /// See [`Sweep::try_sweep_with_min_length`].
+    fn try_sweep(
+        self,
+        path: impl Into<Vector<3>>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError> {
+        self.try_sweep_with_min_length(
+            path,
+            context.distinct_min_distance,
+            services,
+        )
+    }
+
+    /// Sweep the object along the given path, if the path is long enough
+    ///
+    /// Returns [`SweepError::PathTooShort`], if the length of the path is less
+    /// than `min_length`.
+    fn try_sweep_with_min_length(
+        self,
+        path: impl Into<Vector<3>>,
+        min_length: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError> {
+        let path = path.into();
+        let min_length = min_length.into();
+
+        let length = path.magnitude();
+        if length < min_length {
+            return Err(SweepError::PathTooShort {
+                path,
+                length,
+                min_length,
+            });
+        }
+
+        let mut cache = SweepCache::default();
+        self.sweep_with_cache(path, &mut cache, services)
+    }
+}
+
+/// Error sweeping an object
+///
+/// See [`Sweep::try_sweep`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepError {
+    /// The sweep path is too short
+    #[error(
+        "Sweep path is too short\n\
+        - Path: {path:?}\n\
+        - Length: {length}\n\
+        - Minimum length: {min_length}"
+    )]
+    PathTooShort {
+        /// The path that was passed to the sweep operation
+        path: Vector<3>,
+
+        /// The length of the path
+        length: Scalar,
+
+        /// The minimum length that the path must have
+        min_length: Scalar,
+    },
+
+    /// The path has too few points
+    #[error(
+        "Sweep path must have at least two points\n\
+        - Number of points: {num_points}"
+    )]
+    PathTooFewPoints {
+        /// The number of points of the path
+        num_points: usize,
+    },
+
+    /// The path doubles back on itself
+    #[error(
+        "Sweep path doubles back on itself\n\
+        - Joint: {joint:?}"
+    )]
+    PathDoublesBack {
+        /// The joint of the path, at which it doubles back
+        joint: Point<3>,
+    },
+
+    /// The path has a corner that is sharper than allowed
+    #[error(
+        "Sweep path has a corner that is too sharp\n\
+        - Joint: {joint:?}\n\
+        - Angle: {angle}\n\
+        - Maximum angle: {max_angle}"
+    )]
+    PathCornerTooSharp {
+        /// The joint of the path, at which the corner is located
+        joint: Point<3>,
+
+        /// The angle between the directions of the adjacent segments
+        angle: Scalar,
+
+        /// The maximum angle that was allowed
+        max_angle: Scalar,
+    },
+
+    /// Sweeping from a curved surface is not supported yet
+    #[error(
+        "Sweeping from curved surface is not supported yet\n\
+        - Surface: {surface:?}"
+    )]
+    CurvedSurface {
+        /// The geometry of the curved surface
+        surface: SurfaceGeometry,
+    },
+
+    /// Sweeping a curved edge is not supported by this operation
+    #[error(
+        "Sweeping curved edge is not supported by this operation\n\
+        - Edge: {edge:#?}"
+    )]
+    CurvedEdge {
+        /// The curved edge
+        edge: Handle<Edge>,
+    },
 }
 
 /// A cache used for sweeping
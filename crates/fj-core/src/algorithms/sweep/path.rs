@@ -1,4 +1,4 @@
-use fj_math::{Circle, Line, Vector};
+use fj_math::{Circle, Ellipse, Line, Vector};
 
 use crate::{
     geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
@@ -8,7 +8,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepError};
 
 impl Sweep for (SurfacePath, &Surface) {
     type Swept = Handle<Surface>;
@@ -18,11 +18,11 @@ impl Sweep for (SurfacePath, &Surface) {
         path: impl Into<Vector<3>>,
         _: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept {
+    ) -> Result<Self::Swept, SweepError> {
         let (curve, surface) = self;
 
         match surface.geometry().u {
-            GlobalPath::Circle(_) => {
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -34,10 +34,9 @@ impl Sweep for (SurfacePath, &Surface) {
                 // flat surface). But is the surface we're sweeping from is
                 // curved, there's simply no way to represent the curve of the
                 // resulting bottom edge.
-                todo!(
-                    "Sweeping a curve that is defined on a curved surface is \
-                    not supported yet."
-                )
+                return Err(SweepError::CurvedSurface {
+                    surface: surface.geometry(),
+                });
             }
             GlobalPath::Line(_) => {
                 // We're sweeping from a curve on a flat surface, which is
@@ -59,6 +58,19 @@ impl Sweep for (SurfacePath, &Surface) {
 
                 GlobalPath::Circle(circle)
             }
+            SurfacePath::Ellipse(ellipse) => {
+                let center = surface
+                    .geometry()
+                    .point_from_surface_coords(ellipse.center());
+                let a =
+                    surface.geometry().vector_from_surface_coords(ellipse.a());
+                let b =
+                    surface.geometry().vector_from_surface_coords(ellipse.b());
+
+                let ellipse = Ellipse::new(center, a, b);
+
+                GlobalPath::Ellipse(ellipse)
+            }
             SurfacePath::Line(line) => {
                 let origin =
                     surface.geometry().point_from_surface_coords(line.origin());
@@ -72,6 +84,9 @@ impl Sweep for (SurfacePath, &Surface) {
             }
         };
 
-        Surface::new(SurfaceGeometry { u, v: path.into() }).insert(services)
+        Ok(
+            Surface::new(SurfaceGeometry { u, v: path.into() })
+                .insert(services),
+        )
     }
 }
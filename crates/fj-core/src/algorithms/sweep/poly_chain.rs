@@ -0,0 +1,553 @@
+use fj_math::{Point, PolyChain, Scalar, Transform, Vector};
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Face, Shell},
+    operations::{newell_normal, BuildShell, Insert},
+    services::Services,
+    storage::Handle,
+};
+
+use super::SweepError;
+
+/// Sweep an object along a path made up of straight line segments
+pub trait SweepAlongPolyChain: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the provided path
+    ///
+    /// Like [`Self::sweep_along_poly_chain_with_max_angle`], with a maximum
+    /// angle of π. This only rejects paths that double back on themselves.
+    fn sweep_along_poly_chain(
+        self,
+        path: &PolyChain<3>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError> {
+        self.sweep_along_poly_chain_with_max_angle(path, Scalar::PI, services)
+    }
+
+    /// Sweep the object along the provided path, limiting its corners
+    ///
+    /// `max_angle` is the maximum angle, in radians, between the directions of
+    /// two adjacent segments of the path. Returns an error, if any joint of the
+    /// path has a sharper corner than that.
+    fn sweep_along_poly_chain_with_max_angle(
+        self,
+        path: &PolyChain<3>,
+        max_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError>;
+}
+
+impl SweepAlongPolyChain for Handle<Face> {
+    type Swept = Handle<Shell>;
+
+    /// Sweep the face along the provided path
+    ///
+    /// Only the shape of the path is relevant, not its absolute position. The
+    /// face is swept as if the path started at the centroid of the face's
+    /// exterior vertices.
+    ///
+    /// The sections of the swept shape are mitered at the joints of the path,
+    /// meaning each joint lies in the plane that bisects the angle between the
+    /// adjacent segments. The cap at the end of the path is the cap at its
+    /// start, rotated along with the path.
+    ///
+    /// Returns an error, if the face is not defined on a plane, if any of its
+    /// edges are not line segments, if the path has less than two points, if
+    /// the path doubles back on itself at any of its joints, or if the angle at
+    /// any of its joints exceeds `max_angle`.
+    fn sweep_along_poly_chain_with_max_angle(
+        self,
+        path: &PolyChain<3>,
+        max_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError> {
+        let max_angle = max_angle.into();
+
+        let surface = self.surface().geometry();
+        match surface.u {
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                return Err(SweepError::CurvedSurface { surface });
+            }
+            GlobalPath::Line(_) => {}
+        }
+
+        let path_points = path.points().collect::<Vec<_>>();
+        if path_points.len() < 2 {
+            return Err(SweepError::PathTooFewPoints {
+                num_points: path_points.len(),
+            });
+        }
+        let directions = path_points
+            .windows(2)
+            .map(|points| points[1] - points[0])
+            .collect::<Vec<_>>();
+
+        // The cycles of the face, as global points. Exterior first, interiors
+        // after.
+        let mut profile = self
+            .region()
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .edges()
+                    .iter()
+                    .map(|edge| {
+                        match edge.path() {
+                            SurfacePath::Circle(_)
+                            | SurfacePath::Ellipse(_) => {
+                                return Err(SweepError::CurvedEdge {
+                                    edge: edge.clone(),
+                                });
+                            }
+                            SurfacePath::Line(_) => {}
+                        }
+
+                        Ok(surface
+                            .point_from_surface_coords(edge.start_position()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Make sure the exterior cycle winds counter-clockwise around the
+        // first segment of the path. That's the orientation that the code
+        // below expects.
+        let normal = newell_normal(&profile[0]);
+        if normal.dot(&directions[0]) < Scalar::ZERO {
+            for cycle in &mut profile {
+                cycle.reverse();
+            }
+        }
+
+        let anchor = {
+            let exterior = &profile[0];
+            let sum = Vector::sum_stable(exterior.iter().map(|p| p.coords));
+            Point {
+                coords: sum / exterior.len() as f64,
+            }
+        };
+
+        // Compute the planes that the sections of the swept shape lie in. The
+        // first section lies in the plane of the face.
+        let mut planes = Vec::new();
+        for (i, point) in path_points.iter().enumerate().skip(1) {
+            let origin = anchor + (*point - path_points[0]);
+
+            let normal = match directions.get(i) {
+                Some(next) => {
+                    let prev = directions[i - 1].normalize();
+                    let next = next.normalize();
+
+                    let bisector = prev + next;
+                    if bisector.magnitude() == Scalar::ZERO {
+                        return Err(SweepError::PathDoublesBack {
+                            joint: *point,
+                        });
+                    }
+
+                    // Rounding errors can push the dot product of two unit
+                    // vectors slightly outside of the domain of `acos`.
+                    let angle =
+                        prev.dot(&next).min(Scalar::ONE).max(-1.).acos();
+                    if angle > max_angle {
+                        return Err(SweepError::PathCornerTooSharp {
+                            joint: *point,
+                            angle,
+                            max_angle,
+                        });
+                    }
+
+                    bisector.normalize()
+                }
+                None => {
+                    let rotation = directions.windows(2).fold(
+                        Transform::identity(),
+                        |rotation, directions| {
+                            let [prev, next] = [directions[0], directions[1]]
+                                .map(|direction| direction.normalize());
+
+                            let axis = prev.cross(&next);
+                            if axis.magnitude() == Scalar::ZERO {
+                                return rotation;
+                            }
+
+                            let angle = prev
+                                .dot(&next)
+                                .min(Scalar::ONE)
+                                .max(-1.)
+                                .acos();
+                            rotation.then_rotate(axis.normalize() * angle)
+                        },
+                    );
+
+                    rotation.transform_normal(&normal)
+                }
+            };
+
+            planes.push((origin, normal));
+        }
+
+        let mut sections = vec![profile];
+        for ((origin, normal), direction) in planes.into_iter().zip(&directions)
+        {
+            let prev = sections.last().expect("Started with one section");
+
+            let section = prev
+                .iter()
+                .map(|cycle| {
+                    cycle
+                        .iter()
+                        .map(|point| {
+                            let t = (origin - *point).dot(&normal)
+                                / direction.dot(&normal);
+                            *point + *direction * t
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            sections.push(section);
+        }
+
+        // Assign an index to every point, and describe the faces of the shell
+        // in terms of those indices.
+        let mut points = Vec::new();
+        let indices = sections
+            .into_iter()
+            .map(|section| {
+                section
+                    .into_iter()
+                    .map(|cycle| {
+                        cycle
+                            .into_iter()
+                            .map(|point| {
+                                points.push(point);
+                                points.len() - 1
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut faces = Vec::new();
+
+        let start_cap = indices[0]
+            .iter()
+            .map(|cycle| cycle.iter().copied().rev().collect())
+            .collect();
+        faces.push(start_cap);
+
+        for sections in indices.windows(2) {
+            let [bottom, top] = [&sections[0], &sections[1]];
+
+            for (bottom, top) in bottom.iter().zip(top) {
+                for k in 0..bottom.len() {
+                    let l = (k + 1) % bottom.len();
+                    faces
+                        .push(vec![vec![bottom[k], bottom[l], top[l], top[k]]]);
+                }
+            }
+        }
+
+        let end_cap = indices[indices.len() - 1].clone();
+        faces.push(end_cap);
+
+        Ok(Shell::polyhedron_with_holes(
+            points,
+            faces,
+            self.region().color(),
+            services,
+        )
+        .insert(services))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_4, TAU};
+
+    use fj_math::{Aabb, Ellipse, Line, Point, PolyChain, Scalar, Vector};
+
+    use crate::{
+        algorithms::sweep::SweepError,
+        geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+        objects::{Cycle, Edge, Face, Region, Solid, Surface},
+        operations::{
+            newell_normal, BuildCycle, BuildEdge, BuildRegion, Insert,
+            UpdateCycle,
+        },
+        services::Services,
+    };
+
+    use super::SweepAlongPolyChain;
+
+    #[test]
+    fn sweep_square_along_l_shaped_path() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        let path =
+            PolyChain::from_points([[0., 0., 0.], [0., 0., 2.], [2., 0., 2.]]);
+        let shell = face.sweep_along_poly_chain(&path, &mut services)?;
+
+        // Two caps, plus four side faces per segment.
+        assert_eq!(shell.faces().len(), 10);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_almost_collinear_path() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        // The dot product of the normalized segment directions rounds to a
+        // value slightly larger than one.
+        let path = PolyChain::from_points([
+            [0., 0., 0.],
+            [0.762, 0.002, 1.445],
+            [1.524000001, 0.004, 2.89],
+        ]);
+        let shell = face.sweep_along_poly_chain(&path, &mut services)?;
+
+        let num_faces = shell.faces().len();
+        for cap in [0, num_faces - 1] {
+            let cap = shell.faces().nth(cap).unwrap();
+            let surface = cap.surface().geometry();
+            let points = cap
+                .region()
+                .exterior()
+                .edges()
+                .iter()
+                .map(|edge| {
+                    surface.point_from_surface_coords(edge.start_position())
+                })
+                .collect::<Vec<_>>();
+
+            let normal = newell_normal(&points).normalize();
+            for point in &points {
+                let distance = (*point - points[0]).dot(&normal);
+                assert!(distance.abs() < Scalar::from(1e-9));
+            }
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_invalid_path() {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        let path = PolyChain::from_points([[0., 0., 0.]]);
+        let err = face
+            .clone()
+            .sweep_along_poly_chain(&path, &mut services)
+            .unwrap_err();
+        assert_eq!(err, SweepError::PathTooFewPoints { num_points: 1 });
+
+        let path =
+            PolyChain::from_points([[0., 0., 0.], [0., 0., 2.], [0., 0., 1.]]);
+        let err = face
+            .sweep_along_poly_chain(&path, &mut services)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SweepError::PathDoublesBack {
+                joint: Point::from([0., 0., 2.])
+            }
+        );
+        assert!(err.to_string().contains("doubles back"));
+    }
+
+    #[test]
+    fn sweep_along_path_with_sharp_corner() {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        let path =
+            PolyChain::from_points([[0., 0., 0.], [0., 0., 2.], [2., 0., 2.]]);
+        let err = face
+            .sweep_along_poly_chain_with_max_angle(
+                &path,
+                FRAC_PI_4,
+                &mut services,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SweepError::PathCornerTooSharp { joint, .. }
+                if joint == Point::from([0., 0., 2.])
+        ));
+    }
+
+    #[test]
+    fn sweep_along_helix() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let [radius, pitch, turns, profile_radius] = [2., 1., 2., 0.2];
+        let path = PolyChain::helix(radius, pitch, turns, 12);
+        let num_segments = path.len() - 1;
+
+        // Place the profile in a plane that is perpendicular to the start of
+        // the path.
+        let surface = {
+            let points = path.points().take(2).collect::<Vec<_>>();
+            let direction = points[1] - points[0];
+
+            let u = direction.cross(&Vector::from([0., 0., 1.])).normalize();
+            let v = direction.cross(&u).normalize();
+
+            Surface::new(SurfaceGeometry {
+                u: GlobalPath::Line(Line::from_origin_and_direction(
+                    points[0], u,
+                )),
+                v,
+            })
+            .insert(&mut services)
+        };
+
+        let num_sides = 6;
+        let profile = (0..num_sides).map(|i| {
+            let angle = Scalar::TAU * i as f64 / num_sides as f64;
+            let (sin, cos) = angle.sin_cos();
+            [cos * profile_radius, sin * profile_radius]
+        });
+        let region =
+            Region::polygon(profile, &mut services).insert(&mut services);
+        let face = Face::new(surface, region).insert(&mut services);
+
+        let shell = face.sweep_along_poly_chain(&path, &mut services)?;
+
+        // Two caps, plus one side face per profile edge and segment. No caps
+        // between the segments.
+        assert_eq!(shell.faces().len(), 2 + num_sides * num_segments);
+
+        let points = shell.faces().into_iter().flat_map(|face| {
+            face.region()
+                .exterior()
+                .edges()
+                .iter()
+                .map(|edge| {
+                    face.surface()
+                        .geometry()
+                        .point_from_surface_coords(edge.start_position())
+                })
+                .collect::<Vec<_>>()
+        });
+        let aabb = Aabb::<3>::from_points(points);
+        let height = aabb.max.z - aabb.min.z;
+        assert!(height >= Scalar::from(pitch * turns));
+        assert!(height <= Scalar::from(pitch * turns + 2. * profile_radius));
+
+        let _ = Solid::new([shell]).insert(&mut services);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_circle_along_path() {
+        let mut services = Services::new();
+
+        let region =
+            Region::circle([0., 0.], 1., &mut services).insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+        let edge = face.region().exterior().edges().nth(0).unwrap().clone();
+
+        let path = PolyChain::from_points([[0., 0., 0.], [0., 0., 1.]]);
+        assert_eq!(
+            face.sweep_along_poly_chain(&path, &mut services),
+            Err(SweepError::CurvedEdge { edge })
+        );
+    }
+
+    #[test]
+    fn sweep_ellipse_along_path() {
+        let mut services = Services::new();
+
+        let edge = Edge::unjoined(
+            SurfacePath::Ellipse(Ellipse::new([0., 0.], [2., 0.], [0., 1.])),
+            [[0.], [TAU]].map(Point::from),
+            &mut services,
+        )
+        .insert(&mut services);
+        let region = Region::new(
+            Cycle::empty()
+                .add_edges([edge.clone()])
+                .insert(&mut services),
+            [],
+            None,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        let path = PolyChain::from_points([[0., 0., 0.], [0., 0., 1.]]);
+        assert_eq!(
+            face.sweep_along_poly_chain(&path, &mut services),
+            Err(SweepError::CurvedEdge { edge })
+        );
+    }
+
+    #[test]
+    fn sweep_face_on_elliptic_surface() {
+        let mut services = Services::new();
+
+        let geometry = SurfaceGeometry {
+            u: GlobalPath::Ellipse(Ellipse::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 1., 0.],
+            )),
+            v: Vector::from([0., 0., 1.]),
+        };
+        let region =
+            Region::polygon([[0., 0.], [1., 0.], [1., 1.]], &mut services)
+                .insert(&mut services);
+        let face =
+            Face::new(Surface::new(geometry).insert(&mut services), region)
+                .insert(&mut services);
+
+        let path = PolyChain::from_points([[0., 0., 0.], [1., 0., 0.]]);
+        assert_eq!(
+            face.sweep_along_poly_chain(&path, &mut services),
+            Err(SweepError::CurvedSurface { surface: geometry })
+        );
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepError};
 
 impl Sweep for (Handle<Sketch>, Handle<Surface>) {
     type Swept = Handle<Solid>;
@@ -17,7 +17,7 @@ impl Sweep for (Handle<Sketch>, Handle<Surface>) {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept {
+    ) -> Result<Self::Swept, SweepError> {
         let (sketch, surface) = self;
         let path = path.into();
 
@@ -25,10 +25,256 @@ impl Sweep for (Handle<Sketch>, Handle<Surface>) {
         for region in sketch.regions() {
             let face =
                 Face::new(surface.clone(), region.clone()).insert(services);
-            let shell = face.sweep_with_cache(path, cache, services);
+            let shell = face.sweep_with_cache(path, cache, services)?;
             shells.push(shell);
         }
 
-        Solid::new(shells).insert(services)
+        Ok(Solid::new(shells).insert(services))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{assert_abs_diff, Aabb, Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            bounding_volume::BoundingVolume,
+            fixtures::cylinder_side,
+            sweep::{Sweep, SweepError},
+            triangulate::Triangulate,
+        },
+        geometry::{GeometryContext, SurfacePath},
+        objects::{Cycle, Region, Sketch},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, Reverse,
+            UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+        storage::Handle,
+        validate::ValidationConfig,
+    };
+
+    #[test]
+    fn sweep_with_zero_length_path() {
+        let mut services = Services::new();
+
+        for path in [[0., 0., 0.], [0., 0., 1e-15]] {
+            let sketch = triangle_sketch(&mut services);
+            let surface = services.objects.surfaces.xy_plane();
+
+            let result = (sketch, surface).try_sweep(
+                path,
+                &GeometryContext::default(),
+                &mut services,
+            );
+            assert!(matches!(result, Err(SweepError::PathTooShort { .. })));
+        }
+    }
+
+    #[test]
+    fn sweep_region_with_hole() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // This is the same shape as the `spacer` model.
+        let hole = Cycle::circle([0., 0.], 1., &mut services)
+            .reverse(&mut services)
+            .insert(&mut services);
+        let region = Region::circle([0., 0.], 2., &mut services)
+            .add_interiors([hole])
+            .insert(&mut services);
+        let sketch = Sketch::empty().add_region(region).insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let mesh = (&*solid, Tolerance::from_scalar(0.01)?).triangulate();
+
+        // A closed surface with one hole through it has an Euler
+        // characteristic of zero.
+        let half_edges = mesh.build_half_edge_structure()?;
+        assert!(half_edges.boundary_loops().is_empty());
+
+        let num_vertices = mesh.vertices().count() as i64;
+        let num_edges = half_edges.half_edges().len() as i64 / 2;
+        let num_faces = mesh.triangles().count() as i64;
+        assert_eq!(num_vertices - num_edges + num_faces, 0);
+
+        // The volume of the hole is missing from the volume of the cylinder.
+        let volume = mesh.signed_volume();
+        let expected = Scalar::PI * 3.;
+        assert!((volume - expected).abs() < expected * 0.01);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_short_path() {
+        let mut services = Services::new();
+
+        let sketch = triangle_sketch(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        let result = (sketch, surface).try_sweep(
+            [0., 0., 1e-3],
+            &GeometryContext::default(),
+            &mut services,
+        );
+
+        assert!(result.is_ok());
+        assert!(services.drop_and_validate().is_ok());
+    }
+
+    #[test]
+    fn sweep_circle_with_origin_angle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut sweep_cylinder = |origin_angle: Scalar| {
+            let sketch = Sketch::empty()
+                .add_region(
+                    Region::circle_with_origin_angle(
+                        [0., 0.],
+                        2.,
+                        origin_angle,
+                        &mut services,
+                    )
+                    .insert(&mut services),
+                )
+                .insert(&mut services);
+            let surface = services.objects.surfaces.xy_plane();
+
+            (sketch, surface).sweep([0., 0., 1.], &mut services)
+        };
+
+        let a = sweep_cylinder(Scalar::ZERO);
+        let b = sweep_cylinder(Scalar::PI / 2.);
+
+        let [aabb_a, aabb_b] = [&a, &b].map(|solid| solid.aabb().unwrap());
+        assert_abs_diff!(aabb_a.min, aabb_b.min);
+        assert_abs_diff!(aabb_a.max, aabb_b.max);
+
+        for (solid, seam) in [(a, [2., 0.]), (b, [0., 2.])] {
+            let side = cylinder_side(&solid);
+
+            // The side face is bounded by four edges, which are all lines in
+            // surface coordinates. The circle's start and end, and therefore
+            // all of the face's vertices, are located on the seam.
+            let [x, y] = seam;
+            assert_eq!(side.region().exterior().edges().len(), 4);
+            for edge in side.region().exterior().edges() {
+                assert!(matches!(edge.path(), SurfacePath::Line(_)));
+
+                let [start, _] = edge.boundary().inner;
+                let position = edge.point_at(start, side.surface());
+                assert_abs_diff!(
+                    Point::from([position.x, position.y]),
+                    Point::from([x, y])
+                );
+            }
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_from_curved_surface() {
+        let mut services = Services::new();
+
+        let sketch = triangle_sketch(&mut services);
+        let surface = (
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.),
+            &*services.objects.surfaces.xy_plane(),
+        )
+            .sweep([0., 0., 1.], &mut services);
+
+        let result = (sketch, surface.clone()).try_sweep(
+            [1., 0., 0.],
+            &GeometryContext::default(),
+            &mut services,
+        );
+        assert_eq!(
+            result,
+            Err(SweepError::CurvedSurface {
+                surface: surface.geometry()
+            })
+        );
+    }
+
+    #[test]
+    fn reference_models_at_different_scales() -> anyhow::Result<()> {
+        for scale in [1e-3, 1., 1e5] {
+            // All models fit into this box.
+            let aabb = Aabb::<3>::from_points([
+                [-scale, -scale, 0.],
+                [scale, scale, scale],
+            ]);
+            let context = GeometryContext::for_model_size(&aabb);
+            let mut services = Services::with_validation_config(
+                ValidationConfig::from_context(&context),
+            );
+
+            let cube = Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]
+                    .map(|point| point.map(|coord| coord * scale)),
+                &mut services,
+            )
+            .insert(&mut services);
+            let cylinder = Region::circle([0., 0.], scale / 2., &mut services)
+                .insert(&mut services);
+            let hole = Cycle::circle([0., 0.], scale / 4., &mut services)
+                .reverse(&mut services)
+                .insert(&mut services);
+            let plate = Region::polygon(
+                [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]]
+                    .map(|point| point.map(|coord| coord * scale)),
+                &mut services,
+            )
+            .add_interiors([hole])
+            .insert(&mut services);
+
+            let models = [
+                (cube, scale, scale.powi(3)),
+                (cylinder, scale, PI * scale.powi(3) / 4.),
+                (plate, scale / 4., (4. - PI / 16.) * scale.powi(3) / 4.),
+            ];
+
+            for (region, height, expected_volume) in models {
+                let sketch =
+                    Sketch::empty().add_region(region).insert(&mut services);
+                let surface = services.objects.surfaces.xy_plane();
+                let solid =
+                    (sketch, surface).sweep([0., 0., height], &mut services);
+
+                let tolerance = Tolerance::from_scalar(scale * 1e-3)?;
+                let mesh = (&*solid, tolerance).triangulate();
+
+                let half_edges = mesh.build_half_edge_structure()?;
+                assert!(half_edges.boundary_loops().is_empty());
+
+                let volume = mesh.signed_volume();
+                let expected_volume = Scalar::from(expected_volume);
+                assert!(
+                    (volume - expected_volume).abs() < expected_volume * 0.01,
+                    "Unexpected volume at scale {scale}: {volume}"
+                );
+            }
+
+            services.drop_and_validate()?;
+        }
+
+        Ok(())
+    }
+
+    fn triangle_sketch(services: &mut Services) -> Handle<Sketch> {
+        Sketch::empty()
+            .add_region(
+                Region::polygon([[0., 0.], [1., 0.], [0., 1.]], services)
+                    .insert(services),
+            )
+            .insert(services)
     }
 }
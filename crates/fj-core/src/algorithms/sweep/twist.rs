@@ -0,0 +1,338 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    geometry::{GeometryContext, GlobalPath, SurfacePath},
+    objects::{Face, Shell},
+    operations::{newell_normal, BuildShell, Insert},
+    services::Services,
+    storage::Handle,
+};
+
+use super::SweepError;
+
+/// Sweep an object along a straight path, while twisting it
+pub trait SweepWithTwist: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the provided path, while twisting it
+    ///
+    /// The object is rotated around the path by `twist` radians in total, at a
+    /// constant rate. The sweep is divided into `segments` sections, and the
+    /// rotation is applied in steps, at the boundaries between them.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `segments` is zero.
+    fn sweep_with_twist(
+        self,
+        path: impl Into<Vector<3>>,
+        twist: impl Into<Scalar>,
+        segments: usize,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError>;
+}
+
+impl SweepWithTwist for Handle<Face> {
+    type Swept = Handle<Shell>;
+
+    /// Sweep the face along the provided path, while twisting it
+    ///
+    /// The axis of the rotation is parallel to the path, and goes through the
+    /// centroid of the face's exterior vertices.
+    ///
+    /// The actual side of a twisted sweep is curved. Within each section, it
+    /// is approximated by planar faces: Every side of every section is split
+    /// into four triangles, which meet at the average of its corners. More
+    /// sections result in a closer approximation.
+    ///
+    /// Returns an error, if the path is shorter than
+    /// [`GeometryContext::distinct_min_distance`], if the face is not defined
+    /// on a plane, or if any of its edges are not line segments.
+    fn sweep_with_twist(
+        self,
+        path: impl Into<Vector<3>>,
+        twist: impl Into<Scalar>,
+        segments: usize,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self::Swept, SweepError> {
+        let path = path.into();
+        let twist = twist.into();
+
+        assert!(segments > 0, "Twisted sweep needs at least one segment");
+
+        let length = path.magnitude();
+        let min_length = context.distinct_min_distance;
+        if length < min_length {
+            return Err(SweepError::PathTooShort {
+                path,
+                length,
+                min_length,
+            });
+        }
+
+        let surface = self.surface().geometry();
+        match surface.u {
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                return Err(SweepError::CurvedSurface { surface });
+            }
+            GlobalPath::Line(_) => {}
+        }
+
+        // The cycles of the face, as global points. Exterior first, interiors
+        // after.
+        let mut profile = self
+            .region()
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .edges()
+                    .iter()
+                    .map(|edge| {
+                        match edge.path() {
+                            SurfacePath::Circle(_)
+                            | SurfacePath::Ellipse(_) => {
+                                return Err(SweepError::CurvedEdge {
+                                    edge: edge.clone(),
+                                });
+                            }
+                            SurfacePath::Line(_) => {}
+                        }
+
+                        Ok(surface
+                            .point_from_surface_coords(edge.start_position()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Make sure the exterior cycle winds counter-clockwise around the
+        // path. That's the orientation that the code below expects.
+        if newell_normal(&profile[0]).dot(&path) < Scalar::ZERO {
+            for cycle in &mut profile {
+                cycle.reverse();
+            }
+        }
+
+        let anchor = {
+            let exterior = &profile[0];
+            let sum = Vector::sum_stable(exterior.iter().map(|p| p.coords));
+            Point {
+                coords: sum / exterior.len() as f64,
+            }
+        };
+        let axis = path.normalize();
+
+        // Assign an index to every point of every section, and remember those
+        // indices per section.
+        let mut points = Vec::new();
+        let sections = (0..=segments)
+            .map(|i| {
+                let fraction = Scalar::from_u64(i as u64)
+                    / Scalar::from_u64(segments as u64);
+                let rotation = Transform::rotation(axis * twist * fraction);
+                let offset = path * fraction;
+
+                profile
+                    .iter()
+                    .map(|cycle| {
+                        cycle
+                            .iter()
+                            .map(|&point| {
+                                let point = anchor
+                                    + rotation
+                                        .transform_vector(&(point - anchor))
+                                    + offset;
+
+                                points.push(point);
+                                points.len() - 1
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut faces = Vec::new();
+
+        let start_cap = sections[0]
+            .iter()
+            .map(|cycle| cycle.iter().copied().rev().collect())
+            .collect();
+        faces.push(start_cap);
+
+        for sections in sections.windows(2) {
+            let [bottom, top] = [&sections[0], &sections[1]];
+
+            for (bottom, top) in bottom.iter().zip(top) {
+                for k in 0..bottom.len() {
+                    let l = (k + 1) % bottom.len();
+                    let corners = [bottom[k], bottom[l], top[l], top[k]];
+
+                    let center = {
+                        let sum = Vector::sum_stable(
+                            corners.iter().map(|&i| points[i].coords),
+                        );
+                        Point { coords: sum / 4. }
+                    };
+                    points.push(center);
+                    let center = points.len() - 1;
+
+                    for m in 0..corners.len() {
+                        let n = (m + 1) % corners.len();
+                        faces.push(vec![vec![corners[m], corners[n], center]]);
+                    }
+                }
+            }
+        }
+
+        let end_cap = sections[sections.len() - 1].clone();
+        faces.push(end_cap);
+
+        Ok(Shell::polyhedron_with_holes(
+            points,
+            faces,
+            self.region().color(),
+            services,
+        )
+        .insert(services))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    use fj_math::{Ellipse, Point, Scalar, Transform, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::SweepError, triangulate::Triangulate,
+        },
+        geometry::{GeometryContext, SurfacePath},
+        objects::{Cycle, Edge, Face, Region, Solid},
+        operations::{BuildCycle, BuildEdge, BuildRegion, Insert, UpdateCycle},
+        services::Services,
+    };
+
+    use super::SweepWithTwist;
+
+    #[test]
+    fn twisted_square() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let square = [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]];
+        let region =
+            Region::polygon(square, &mut services).insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+
+        let shell = face.sweep_with_twist(
+            [0., 0., 1.],
+            FRAC_PI_2,
+            10,
+            &context,
+            &mut services,
+        )?;
+
+        let mesh = (&*shell, Tolerance::from_scalar(0.1)?).triangulate();
+        let volume = mesh.signed_volume();
+        assert!((volume - Scalar::ONE).abs() < Scalar::from(0.01));
+
+        // The top cap is the bottom cap, rotated by the full twist.
+        let top = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                face_points(face).iter().all(|point| {
+                    (point.z - Scalar::ONE).abs() < Scalar::from(1e-12)
+                })
+            })
+            .unwrap();
+        let rotation = Transform::rotation(Vector::unit_z() * FRAC_PI_2);
+        let mut top_points = face_points(top);
+        for point in square {
+            let expected = rotation
+                .transform_point(&Point::from(point).to_xyz())
+                + Vector::unit_z();
+
+            let index = top_points
+                .iter()
+                .position(|point| {
+                    (*point - expected).magnitude() < Scalar::from(1e-12)
+                })
+                .unwrap();
+            top_points.remove(index);
+        }
+        assert!(top_points.is_empty());
+
+        let _ = Solid::new([shell]).insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_twisted_sweeps() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let region =
+            Region::polygon([[0., 0.], [1., 0.], [1., 1.]], &mut services)
+                .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+        assert!(matches!(
+            face.sweep_with_twist(
+                [0., 0., 0.],
+                FRAC_PI_2,
+                10,
+                &context,
+                &mut services,
+            ),
+            Err(SweepError::PathTooShort { .. })
+        ));
+
+        let edge = Edge::unjoined(
+            SurfacePath::Ellipse(Ellipse::new([0., 0.], [2., 0.], [0., 1.])),
+            [[0.], [TAU]].map(Point::from),
+            &mut services,
+        )
+        .insert(&mut services);
+        let region = Region::new(
+            Cycle::empty()
+                .add_edges([edge.clone()])
+                .insert(&mut services),
+            [],
+            None,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region)
+            .insert(&mut services);
+        assert_eq!(
+            face.sweep_with_twist(
+                [0., 0., 1.],
+                FRAC_PI_2,
+                10,
+                &context,
+                &mut services,
+            ),
+            Err(SweepError::CurvedEdge { edge })
+        );
+    }
+
+    fn face_points(face: &Face) -> Vec<Point<3>> {
+        let surface = face.surface().geometry();
+        face.region()
+            .exterior()
+            .edges()
+            .iter()
+            .map(|edge| {
+                surface.point_from_surface_coords(edge.start_position())
+            })
+            .collect()
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepError};
 
 impl Sweep for Handle<Vertex> {
     type Swept = (Handle<Curve>, [Self; 2]);
@@ -17,7 +17,7 @@ impl Sweep for Handle<Vertex> {
         _: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Self::Swept {
+    ) -> Result<Self::Swept, SweepError> {
         let curve = cache
             .curves
             .entry(self.id())
@@ -32,6 +32,6 @@ impl Sweep for Handle<Vertex> {
             .clone();
         let vertices = [a, b];
 
-        (curve, vertices)
+        Ok((curve, vertices))
     }
 }
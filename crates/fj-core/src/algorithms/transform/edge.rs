@@ -27,3 +27,42 @@ impl TransformObject for Edge {
         Self::new(path, boundary, curve, start_vertex)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Point, Scalar};
+
+    use crate::{
+        algorithms::transform::TransformObject, geometry::SurfacePath,
+        objects::Edge, operations::BuildEdge, services::Services,
+    };
+
+    #[test]
+    fn circle_moves_with_surface() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let edge = Edge::circle([1., 2.], 3., &mut services);
+
+        let offset = [4., 5., 6.];
+        let transformed_surface =
+            surface.clone().translate(offset, &mut services);
+        let transformed_edge = edge.clone().translate(offset, &mut services);
+
+        // The path is defined in surface coordinates, so it doesn't change.
+        // It moves along with the surface.
+        let SurfacePath::Circle(circle) = transformed_edge.path() else {
+            panic!("Expected circle");
+        };
+        assert_eq!(circle.radius(), Scalar::from(3.));
+
+        let center = Point::from([5., 7., 6.]);
+        for t in [0., 1., 2., 4.] {
+            let point = transformed_edge.point_at([t], &transformed_surface);
+            assert_abs_diff!(point.distance_to(&center), Scalar::from(3.));
+
+            let original = edge.point_at([t], &surface);
+            assert_abs_diff!(point, original + offset);
+        }
+    }
+}
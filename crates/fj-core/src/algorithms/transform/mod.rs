@@ -22,6 +22,17 @@ use crate::{
 
 /// Transform an object
 ///
+/// # Edges and Surfaces
+///
+/// The path of an [`Edge`] is defined in the coordinates of its surface, so
+/// transforming an edge leaves its path as it is. Its position in global space
+/// follows from the surface. For the transformed edge to end up in the right
+/// place, the surface it is defined on must be transformed along with it, as
+/// happens when transforming a [`Face`].
+///
+/// [`Edge`]: crate::objects::Edge
+/// [`Face`]: crate::objects::Face
+///
 /// # Implementation Note
 ///
 /// So far, a general `transform` method is available, along some convenience
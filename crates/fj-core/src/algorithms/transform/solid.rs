@@ -18,3 +18,40 @@ impl TransformObject for Solid {
         Self::new(shells)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        algorithms::{fixtures, transform::TransformObject},
+        services::Services,
+    };
+
+    #[test]
+    fn transform_cube_with_shared_vertices() {
+        let mut services = Services::new();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let transformed = cube.clone().translate([1., 2., 3.], &mut services);
+
+        // Each corner of the cube is shared by three faces. If every vertex
+        // is transformed exactly once, the transformed cube has the same
+        // number of distinct vertices as the original one.
+        for solid in [&cube, &transformed] {
+            let vertices = solid
+                .shells()
+                .iter()
+                .flat_map(|shell| shell.faces().iter())
+                .flat_map(|face| face.region().all_cycles())
+                .flat_map(|cycle| cycle.edges().iter())
+                .map(|edge| edge.start_vertex().id())
+                .collect::<BTreeSet<_>>();
+
+            assert_eq!(vertices.len(), 8);
+        }
+
+        services.drop_and_validate().unwrap();
+    }
+}
@@ -0,0 +1,357 @@
+//! Triangulation that checks the boundary of faces first
+//!
+//! See [`TriangulateChecked`].
+
+use std::collections::BTreeSet;
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::algorithms::approx::{
+    cycle::CycleApprox, edge::EdgeApprox, face::FaceApprox, Approx,
+    ApproxPoint, Tolerance,
+};
+
+use super::Triangulate;
+
+/// Triangulate a shape, after checking its boundaries for self-intersections
+///
+/// [`Triangulate`] expects the cycles of each face not to intersect themselves.
+/// This checks that, and optionally repairs self-intersections that are small
+/// enough to be the result of numerical inaccuracies.
+pub trait TriangulateChecked: Sized {
+    /// Triangulate the shape, after checking its boundaries
+    ///
+    /// Returns the mesh, along with a report of any repairs that were made.
+    /// Returns an error, if a cycle intersects itself, and `mode` doesn't
+    /// allow repairing that.
+    fn triangulate_checked(
+        self,
+        mode: RepairMode,
+    ) -> Result<(Mesh<Point<3>>, RepairReport), BoundarySelfIntersection>;
+}
+
+impl<T> TriangulateChecked for (T, Tolerance)
+where
+    T: Approx,
+    T::Approximation: IntoIterator<Item = FaceApprox>,
+{
+    fn triangulate_checked(
+        self,
+        mode: RepairMode,
+    ) -> Result<(Mesh<Point<3>>, RepairReport), BoundarySelfIntersection> {
+        let (approx, tolerance) = self;
+
+        let mut mesh = Mesh::new();
+        let mut report = RepairReport::default();
+
+        for approx in approx.approx(tolerance) {
+            let approx = repair_face(approx, mode, &mut report)?;
+            approx.triangulate_into_mesh(&mut mesh);
+        }
+
+        Ok((mesh, report))
+    }
+}
+
+impl TriangulateChecked for FaceApprox {
+    fn triangulate_checked(
+        self,
+        mode: RepairMode,
+    ) -> Result<(Mesh<Point<3>>, RepairReport), BoundarySelfIntersection> {
+        let mut report = RepairReport::default();
+        let approx = repair_face(self, mode, &mut report)?;
+
+        Ok((approx.triangulate(), report))
+    }
+}
+
+/// How [`TriangulateChecked`] handles self-intersecting boundaries
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum RepairMode {
+    /// Fail on any self-intersection
+    #[default]
+    Strict,
+
+    /// Repair self-intersections up to the provided penetration
+    ///
+    /// Where two segments of a cycle cross, the smaller of the two loops that
+    /// the crossing splits the cycle into is cut off. Its points are replaced
+    /// with the crossing point. This is only done, if no point of that loop is
+    /// further than the provided tolerance from the crossing point.
+    Snap(Scalar),
+}
+
+/// A report of the repairs made by [`TriangulateChecked`]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RepairReport {
+    /// The repairs that were made, in the order they were made in
+    pub repairs: Vec<BoundaryRepair>,
+}
+
+/// A repaired self-intersection of a cycle
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundaryRepair {
+    /// The point where the cycle intersected itself, in surface coordinates
+    pub position: Point<2>,
+
+    /// The distance of the furthest point of the cut-off loop from `position`
+    pub penetration: Scalar,
+
+    /// The number of points that were replaced by `position`
+    pub num_points_removed: usize,
+}
+
+/// A cycle intersects itself
+///
+/// Returned by [`TriangulateChecked::triangulate_checked`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error(
+    "Boundary of face intersects itself\n\
+    - Position: {position:?}\n\
+    - Penetration: {penetration}"
+)]
+pub struct BoundarySelfIntersection {
+    /// The point where the cycle intersects itself, in surface coordinates
+    pub position: Point<2>,
+
+    /// The distance of the furthest point of the smaller loop from `position`
+    ///
+    /// See [`RepairMode::Snap`].
+    pub penetration: Scalar,
+}
+
+fn repair_face(
+    approx: FaceApprox,
+    mode: RepairMode,
+    report: &mut RepairReport,
+) -> Result<FaceApprox, BoundarySelfIntersection> {
+    let FaceApprox {
+        exterior,
+        interiors,
+        color,
+        coord_handedness,
+    } = approx;
+
+    let exterior = repair_cycle(exterior, mode, report)?;
+    let interiors = interiors
+        .into_iter()
+        .map(|interior| repair_cycle(interior, mode, report))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    Ok(FaceApprox {
+        exterior,
+        interiors,
+        color,
+        coord_handedness,
+    })
+}
+
+fn repair_cycle(
+    cycle: CycleApprox,
+    mode: RepairMode,
+    report: &mut RepairReport,
+) -> Result<CycleApprox, BoundarySelfIntersection> {
+    let mut points = cycle.points();
+
+    // The first point is repeated at the end, to close the cycle.
+    points.pop();
+
+    let num_repairs = report.repairs.len();
+
+    // Repairing a crossing can't introduce another one, but there might be
+    // more than one to begin with. Always repair the first crossing that is
+    // found, to keep the result deterministic.
+    while let Some(crossing) = find_first_crossing(&points) {
+        let Crossing { i, j, point } = crossing;
+
+        // The crossing splits the cycle into two loops. One consists of the
+        // points after segment `i`, up to and including the start of segment
+        // `j`, the other of the remaining points.
+        let inner = (i + 1..=j).collect::<Vec<_>>();
+        let outer = (j + 1..points.len()).chain(0..=i).collect::<Vec<_>>();
+
+        let penetration = |indices: &[usize]| {
+            indices
+                .iter()
+                .map(|&index| {
+                    points[index].local_form.distance_to(&point.local_form)
+                })
+                .max()
+                .unwrap_or(Scalar::ZERO)
+        };
+        let inner_penetration = penetration(&inner);
+        let outer_penetration = penetration(&outer);
+
+        let penetration = inner_penetration.min(outer_penetration);
+        let allowed = match mode {
+            RepairMode::Strict => false,
+            RepairMode::Snap(tolerance) => penetration <= tolerance,
+        };
+        if !allowed {
+            return Err(BoundarySelfIntersection {
+                position: point.local_form,
+                penetration,
+            });
+        }
+
+        let num_points_before = points.len();
+        points = if inner_penetration <= outer_penetration {
+            points[..=i]
+                .iter()
+                .copied()
+                .chain([point])
+                .chain(points[j + 1..].iter().copied())
+                .collect()
+        } else {
+            [point]
+                .into_iter()
+                .chain(points[i + 1..=j].iter().copied())
+                .collect()
+        };
+
+        report.repairs.push(BoundaryRepair {
+            position: point.local_form,
+            penetration,
+            num_points_removed: num_points_before + 1 - points.len(),
+        });
+    }
+
+    if report.repairs.len() == num_repairs {
+        // Nothing was repaired, so the original approximation can be used.
+        return Ok(cycle);
+    }
+
+    Ok(CycleApprox {
+        edges: points
+            .into_iter()
+            .map(EdgeApprox::from_first_point)
+            .collect(),
+    })
+}
+
+struct Crossing {
+    i: usize,
+    j: usize,
+    point: ApproxPoint<2>,
+}
+
+/// Find the first crossing of two non-adjacent segments of a cycle
+///
+/// The cycle is closed implicitly, meaning the last point is connected to the
+/// first. For the returned crossing, `i < j` is guaranteed.
+fn find_first_crossing(points: &[ApproxPoint<2>]) -> Option<Crossing> {
+    let n = points.len();
+    let segment = |i: usize| [points[i], points[(i + 1) % n]];
+
+    for i in 0..n {
+        for j in i + 2..n {
+            // The last segment is adjacent to the first.
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+
+            let [a, b] = segment(i);
+            let [c, d] = segment(j);
+
+            let r = b.local_form - a.local_form;
+            let s = d.local_form - c.local_form;
+
+            let denominator = r.cross2d(&s);
+            if denominator == Scalar::ZERO {
+                // The segments are parallel. Overlapping collinear segments
+                // are not detected.
+                continue;
+            }
+
+            let offset = c.local_form - a.local_form;
+            let t = offset.cross2d(&s) / denominator;
+            let u = offset.cross2d(&r) / denominator;
+
+            let range = Scalar::ZERO..=Scalar::ONE;
+            if !range.contains(&t) || !range.contains(&u) {
+                continue;
+            }
+
+            let point = ApproxPoint::new(
+                a.local_form + r * t,
+                a.global_form + (b.global_form - a.global_form) * t,
+            );
+
+            return Some(Crossing { i, j, point });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::approx::{
+            cycle::CycleApprox, edge::EdgeApprox, face::FaceApprox, ApproxPoint,
+        },
+        objects::Handedness,
+    };
+
+    use super::{RepairMode, TriangulateChecked};
+
+    #[test]
+    fn repair_small_self_intersection() -> anyhow::Result<()> {
+        // A unit square, whose last edge overshoots the first one by 1e-10,
+        // before returning to the first point.
+        let points = [[0., 0.], [1., 0.], [1., 1.], [0., 1.], [1e-10, -1e-10]];
+
+        let err = face_approx(points)
+            .triangulate_checked(RepairMode::Strict)
+            .unwrap_err();
+        assert!(err.penetration < Scalar::from(1e-9));
+
+        let (mesh, report) = face_approx(points)
+            .triangulate_checked(RepairMode::Snap(Scalar::from(1e-9)))?;
+        assert_eq!(report.repairs.len(), 1);
+        assert_eq!(report.repairs[0].num_points_removed, 2);
+
+        let area = Scalar::sum_stable(mesh.triangles().map(|triangle| {
+            let [a, b, c] = triangle.inner.points();
+            (b - a).cross(&(c - a)).magnitude() / 2.
+        }));
+        assert!((area - Scalar::ONE).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn bow_tie_fails() {
+        let approx = face_approx([[0., 0.], [1., 1.], [1., 0.], [0., 1.]]);
+
+        let err = approx
+            .triangulate_checked(RepairMode::Snap(Scalar::from(1e-9)))
+            .unwrap_err();
+        assert_eq!(err.position, Point::from([0.5, 0.5]));
+    }
+
+    fn face_approx<const N: usize>(points: [[f64; 2]; N]) -> FaceApprox {
+        let edges = points
+            .into_iter()
+            .map(|point| {
+                let point = Point::from(point);
+                EdgeApprox::from_first_point(ApproxPoint::new(
+                    point,
+                    point.to_xyz(),
+                ))
+            })
+            .collect();
+
+        FaceApprox {
+            exterior: CycleApprox { edges },
+            interiors: BTreeSet::new(),
+            color: None,
+            coord_handedness: Handedness::RightHanded,
+        }
+    }
+}
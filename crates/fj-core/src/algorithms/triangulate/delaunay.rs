@@ -53,7 +53,8 @@ pub fn triangulate(
             v2.point_surface,
         ])
         .expect("invalid triangle")
-        .winding();
+        .winding()
+        .expect("Triangulation produced degenerate triangle");
 
         let required_winding = match coord_handedness {
             Handedness::LeftHanded => Winding::Cw,
@@ -0,0 +1,281 @@
+//! Exact triangulation of polyhedral shapes
+//!
+//! See [`TriangulateExact`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+};
+
+use fj_interop::mesh::Mesh;
+use fj_math::Point;
+
+use crate::{
+    algorithms::approx::{
+        cycle::CycleApprox, edge::EdgeApprox, face::FaceApprox, ApproxPoint,
+    },
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{Cycle, Edge, Face, Shell, Solid, Surface, Vertex},
+    storage::{Handle, HandleWrapper},
+};
+
+use super::Triangulate;
+
+/// Triangulate a polyhedral shape, without approximating it
+///
+/// If all faces of a shape are planar and bounded by straight edges, their
+/// vertices already describe the shape exactly. This triangulates such shapes
+/// directly from the vertex positions, without involving a tolerance value.
+///
+/// Shapes that contain any curved geometry result in an error. Those need to be
+/// triangulated using [`Triangulate`] instead.
+pub trait TriangulateExact {
+    /// Triangulate the shape
+    fn triangulate_exact(&self) -> Result<Mesh<Point<3>>, NotPolyhedral>;
+}
+
+impl TriangulateExact for Solid {
+    fn triangulate_exact(&self) -> Result<Mesh<Point<3>>, NotPolyhedral> {
+        triangulate_faces(
+            self.shells()
+                .iter()
+                .flat_map(|shell| shell.faces().iter().map(Deref::deref)),
+        )
+    }
+}
+
+impl TriangulateExact for Shell {
+    fn triangulate_exact(&self) -> Result<Mesh<Point<3>>, NotPolyhedral> {
+        triangulate_faces(self.faces().iter().map(Deref::deref))
+    }
+}
+
+impl TriangulateExact for Face {
+    fn triangulate_exact(&self) -> Result<Mesh<Point<3>>, NotPolyhedral> {
+        triangulate_faces([self])
+    }
+}
+
+fn triangulate_faces<'r>(
+    faces: impl IntoIterator<Item = &'r Face>,
+) -> Result<Mesh<Point<3>>, NotPolyhedral> {
+    // Vertices are shared between faces, but the surfaces of those faces might
+    // compute slightly different positions for them. Make sure each vertex ends
+    // up at exactly one position, so the resulting mesh has no cracks.
+    let mut positions = BTreeMap::new();
+
+    let mut approxs = Vec::new();
+    for face in faces {
+        approxs.push(face_to_approx(face, &mut positions)?);
+    }
+
+    let mut mesh = Mesh::new();
+    for approx in approxs {
+        approx.triangulate_into_mesh(&mut mesh);
+    }
+
+    Ok(mesh)
+}
+
+fn face_to_approx(
+    face: &Face,
+    positions: &mut BTreeMap<HandleWrapper<Vertex>, Point<3>>,
+) -> Result<FaceApprox, NotPolyhedral> {
+    let surface = face.surface().geometry();
+    match surface.u {
+        GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+            return Err(NotPolyhedral::CurvedSurface {
+                surface: face.surface().clone(),
+            });
+        }
+        GlobalPath::Line(_) => {}
+    }
+
+    let exterior =
+        cycle_to_approx(face.region().exterior(), surface, positions)?;
+
+    let mut interiors = BTreeSet::new();
+    for cycle in face.region().interiors() {
+        interiors.insert(cycle_to_approx(cycle, surface, positions)?);
+    }
+
+    Ok(FaceApprox {
+        exterior,
+        interiors,
+        color: face.region().color(),
+        coord_handedness: face.coord_handedness(),
+    })
+}
+
+fn cycle_to_approx(
+    cycle: &Cycle,
+    surface: SurfaceGeometry,
+    positions: &mut BTreeMap<HandleWrapper<Vertex>, Point<3>>,
+) -> Result<CycleApprox, NotPolyhedral> {
+    let mut edges = Vec::new();
+
+    for edge in cycle.edges() {
+        match edge.path() {
+            SurfacePath::Circle(_) | SurfacePath::Ellipse(_) => {
+                return Err(NotPolyhedral::CurvedEdge { edge: edge.clone() });
+            }
+            SurfacePath::Line(_) => {}
+        }
+
+        let position_surface = edge.start_position();
+        let position_global = *positions
+            .entry(edge.start_vertex().clone().into())
+            .or_insert_with(|| {
+                surface.point_from_surface_coords(position_surface)
+            });
+
+        edges.push(EdgeApprox::from_first_point(ApproxPoint::new(
+            position_surface,
+            position_global,
+        )));
+    }
+
+    Ok(CycleApprox { edges })
+}
+
+/// Error triangulating a shape exactly
+///
+/// Returned by [`TriangulateExact::triangulate_exact`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum NotPolyhedral {
+    /// A face is defined on a curved surface
+    #[error("Shape is not polyhedral; found curved surface: {surface:#?}")]
+    CurvedSurface {
+        /// The curved surface
+        surface: Handle<Surface>,
+    },
+
+    /// A face is bounded by a curved edge
+    #[error("Shape is not polyhedral; found curved edge: {edge:#?}")]
+    CurvedEdge {
+        /// The curved edge
+        edge: Handle<Edge>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_math::{Ellipse, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{fixtures, sweep::Sweep},
+        geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+        objects::{Cycle, Edge, Face, Region, Sketch, Surface},
+        operations::{
+            BuildCycle, BuildEdge, BuildRegion, BuildSketch, Insert,
+            UpdateCycle, UpdateSketch,
+        },
+        services::Services,
+    };
+
+    use super::{NotPolyhedral, TriangulateExact};
+
+    #[test]
+    fn cube() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube([0., 0.], &mut services);
+
+        let mesh = solid.triangulate_exact()?;
+        assert_eq!(mesh.triangles().count(), 12);
+
+        let mut area = Scalar::ZERO;
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            let [ab, ac] = [b - a, c - a];
+
+            area += ab.cross(&ac).magnitude() / 2.;
+        }
+        let volume = mesh.signed_volume();
+
+        // These are exact comparisons. No tolerance is involved.
+        assert_eq!(area, Scalar::from(6.));
+        assert_eq!(volume, Scalar::from(1.));
+
+        // The mesh contains the exact vertex positions.
+        let vertices = mesh.vertices().collect::<Vec<_>>();
+        assert_eq!(vertices.len(), 8);
+        for vertex in vertices {
+            assert!(vertex
+                .coords
+                .components
+                .iter()
+                .all(|&c| c == Scalar::ZERO || c == Scalar::ONE));
+        }
+        assert!(
+            mesh.vertices().any(|v| v == Point::from([1., 1., 1.])),
+            "Expected vertex at the far corner of the cube"
+        );
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty().add_region(
+            Region::circle([0., 0.], 1., &mut services).insert(&mut services),
+        );
+        let solid = (
+            sketch.insert(&mut services),
+            services.objects.surfaces.xy_plane(),
+        )
+            .sweep(Vector::from([0., 0., 1.]), &mut services);
+
+        assert!(matches!(
+            solid.triangulate_exact(),
+            Err(NotPolyhedral::CurvedSurface { .. }
+                | NotPolyhedral::CurvedEdge { .. })
+        ));
+    }
+
+    #[test]
+    fn ellipse() {
+        let mut services = Services::new();
+
+        let edge = Edge::unjoined(
+            SurfacePath::Ellipse(Ellipse::new([0., 0.], [2., 0.], [0., 1.])),
+            [[0.], [TAU]].map(Point::from),
+            &mut services,
+        )
+        .insert(&mut services);
+        let region = Region::new(
+            Cycle::empty().add_edges([edge]).insert(&mut services),
+            [],
+            None,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region);
+        assert!(matches!(
+            face.triangulate_exact(),
+            Err(NotPolyhedral::CurvedEdge { .. })
+        ));
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::Ellipse(Ellipse::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 1., 0.],
+            )),
+            v: Vector::from([0., 0., 1.]),
+        })
+        .insert(&mut services);
+        let region =
+            Region::polygon([[0., 0.], [1., 0.], [1., 1.]], &mut services)
+                .insert(&mut services);
+        let face = Face::new(surface, region);
+        assert!(matches!(
+            face.triangulate_exact(),
+            Err(NotPolyhedral::CurvedSurface { .. })
+        ));
+    }
+}
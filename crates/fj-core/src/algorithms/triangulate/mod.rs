@@ -1,14 +1,31 @@
 //! Shape triangulation
 
+mod checked;
 mod delaunay;
+mod exact;
 mod polygon;
+mod stats;
 
 use fj_interop::mesh::Mesh;
-use fj_math::Point;
+use fj_math::{Aabb, Point, Scalar};
 
 use self::polygon::Polygon;
 
-use super::approx::{face::FaceApprox, Approx, Tolerance};
+pub use self::{
+    checked::{
+        BoundaryRepair, BoundarySelfIntersection, RepairMode, RepairReport,
+        TriangulateChecked,
+    },
+    exact::{NotPolyhedral, TriangulateExact},
+    stats::{triangulate_with_stats, FaceStats, MeshStats, TriangulationStats},
+};
+
+use crate::objects::Cycle;
+
+use super::{
+    approx::{face::FaceApprox, Approx, Tolerance},
+    cancellation::{CancellationToken, Cancelled},
+};
 
 /// Triangulate a shape
 pub trait Triangulate: Sized {
@@ -24,6 +41,40 @@ pub trait Triangulate: Sized {
     /// This is a low-level method, intended for implementation of
     /// `Triangulate`. Most callers should prefer [`Triangulate::triangulate`].
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>);
+
+    /// Triangulate the shape, unless cancelled
+    ///
+    /// Like [`Triangulate::triangulate`], but checks the provided token once
+    /// per face while approximating, and again once per face while
+    /// triangulating. Returns [`Cancelled`], if it has been cancelled. Without
+    /// a token, this is equivalent to [`Triangulate::triangulate`].
+    fn triangulate_cancellable(
+        self,
+        token: Option<&CancellationToken>,
+    ) -> Result<Mesh<Point<3>>, Cancelled> {
+        let mut mesh = Mesh::new();
+        self.triangulate_into_mesh_cancellable(&mut mesh, token)?;
+        Ok(mesh)
+    }
+
+    /// Triangulate a partial shape into the provided mesh, unless cancelled
+    ///
+    /// This is a low-level method, intended for implementation of
+    /// `Triangulate`. Most callers should prefer
+    /// [`Triangulate::triangulate_cancellable`].
+    ///
+    /// The default implementation checks the token once, before triangulating.
+    /// Implementations that triangulate multiple faces should override it, to
+    /// check the token once per face.
+    fn triangulate_into_mesh_cancellable(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        CancellationToken::check_optional(token)?;
+        self.triangulate_into_mesh(mesh);
+        Ok(())
+    }
 }
 
 impl<T> Triangulate for (T, Tolerance)
@@ -33,58 +84,201 @@ where
 {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
         let (approx, tolerance) = self;
+        (approx, tolerance, UvMapping::default()).triangulate_into_mesh(mesh);
+    }
+
+    fn triangulate_into_mesh_cancellable(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        let (approx, tolerance) = self;
+        (approx, tolerance, UvMapping::default())
+            .triangulate_into_mesh_cancellable(mesh, token)
+    }
+}
+
+impl<T> Triangulate for (T, Tolerance, UvMapping)
+where
+    T: Approx,
+    T::Approximation: IntoIterator<Item = FaceApprox>,
+{
+    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+        let (approx, tolerance, uv_mapping) = self;
 
         let approx = approx.approx(tolerance);
 
         for approx in approx {
-            approx.triangulate_into_mesh(mesh);
+            (approx, uv_mapping).triangulate_into_mesh(mesh);
         }
     }
+
+    fn triangulate_into_mesh_cancellable(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        let (approx, tolerance, uv_mapping) = self;
+
+        let approx = approx.approx_cancellable(tolerance, token)?;
+
+        for approx in approx {
+            (approx, uv_mapping)
+                .triangulate_into_mesh_cancellable(mesh, token)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Triangulate for FaceApprox {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+        (self, UvMapping::default()).triangulate_into_mesh(mesh);
+    }
+}
+
+impl Triangulate for (FaceApprox, UvMapping) {
+    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+        let (approx, uv_mapping) = self;
+
         let face_as_polygon = Polygon::new()
             .with_exterior(
-                self.exterior
+                approx
+                    .exterior
                     .points()
                     .into_iter()
                     .map(|point| point.local_form),
             )
-            .with_interiors(self.interiors.iter().map(|interior| {
+            .with_interiors(approx.interiors.iter().map(|interior| {
                 interior.points().into_iter().map(|point| point.local_form)
             }));
 
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
+        // The exterior contains all other points of the face, so its bounds
+        // are the bounds of the whole face.
+        let bounds = Aabb::<2>::from_points(
+            approx
+                .exterior
+                .points()
+                .into_iter()
+                .map(|point| point.local_form),
+        );
+
+        let cycles = [approx.exterior].into_iter().chain(approx.interiors);
         let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
+            delaunay::triangulate(cycles, approx.coord_handedness);
         triangles.retain(|triangle| {
             face_as_polygon
                 .contains_triangle(triangle.map(|point| point.point_surface))
         });
 
-        let color = self.color.unwrap_or_default();
+        let color = approx.color.unwrap_or_default();
 
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, color);
+            let uv = triangle.map(|point| {
+                uv_mapping.map_point(point.point_surface, &bounds)
+            });
+            mesh.push_triangle_with_uv(points, uv, color);
+        }
+    }
+}
+
+/// How texture coordinates are derived from the surface coordinates of a face
+///
+/// Every face has a 2-dimensional coordinate system, defined by its surface.
+/// Triangulation uses those coordinates as the texture coordinates of the
+/// resulting triangles.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum UvMapping {
+    /// Use the surface coordinates as they are
+    Raw,
+
+    /// Scale the surface coordinates of each face into the range `[0, 1]`
+    ///
+    /// The bounding box of each face, in surface coordinates, is mapped to the
+    /// unit square.
+    #[default]
+    Normalized,
+}
+
+impl UvMapping {
+    fn map_point(&self, point: Point<2>, bounds: &Aabb<2>) -> Point<2> {
+        match self {
+            Self::Raw => point,
+            Self::Normalized => {
+                let offset = point - bounds.min;
+                let size = bounds.max - bounds.min;
+
+                let coords = [0, 1].map(|i| {
+                    if size.components[i] > Scalar::ZERO {
+                        offset.components[i] / size.components[i]
+                    } else {
+                        Scalar::ZERO
+                    }
+                });
+
+                Point::from(coords)
+            }
         }
     }
 }
 
+/// Determine whether a point in surface coordinates is inside a cycle
+///
+/// Curved edges are approximated according to `tolerance`, and the result is
+/// determined by a ray-crossing test against the resulting polygon. The
+/// winding of the cycle doesn't matter.
+///
+/// Points on the boundary of that polygon are considered to be inside the
+/// cycle. Near curved edges, that boundary deviates from the actual one by up
+/// to `tolerance`.
+pub fn cycle_contains_point_surface(
+    cycle: &Cycle,
+    point: impl Into<Point<2>>,
+    tolerance: impl Into<Tolerance>,
+) -> bool {
+    let tolerance = tolerance.into();
+
+    let mut points = Vec::new();
+    for edge in cycle.edges() {
+        points.push(edge.start_position());
+        points.extend(
+            (&edge.path(), edge.boundary())
+                .approx(tolerance)
+                .into_iter()
+                .map(|(_, point_surface)| point_surface),
+        );
+    }
+    if let Some(first) = points.first().copied() {
+        points.push(first);
+    }
+
+    Polygon::new()
+        .with_exterior(points)
+        .contains_point(point.into())
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::mesh::Mesh;
     use fj_math::{Point, Scalar};
 
     use crate::{
-        algorithms::approx::{Approx, Tolerance},
-        objects::{Cycle, Face},
-        operations::{BuildCycle, BuildFace, Insert, UpdateFace, UpdateRegion},
+        algorithms::{
+            approx::{Approx, Tolerance},
+            cancellation::{CancellationToken, Cancelled},
+            fixtures::{cylinder, cylinder_side, tolerance},
+            sweep::Sweep,
+        },
+        objects::{Cycle, Face, Region, Sketch},
+        operations::{
+            BuildCycle, BuildFace, BuildRegion, BuildSketch, Insert,
+            UpdateFace, UpdateRegion, UpdateSketch,
+        },
         services::Services,
     };
 
-    use super::Triangulate;
+    use super::{cycle_contains_point_surface, Triangulate, UvMapping};
 
     #[test]
     fn simple() -> anyhow::Result<()> {
@@ -235,8 +429,173 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cancellation() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A grid of separate cubes, to have lots of faces.
+        let mut sketch = Sketch::empty();
+        for i in 0..5 {
+            for j in 0..5 {
+                let [x, y] = [i, j].map(|n| f64::from(n) * 2.);
+                let region = Region::polygon(
+                    [[x, y], [x + 1., y], [x + 1., y + 1.], [x, y + 1.]],
+                    &mut services,
+                )
+                .insert(&mut services);
+                sketch = sketch.add_region(region);
+            }
+        }
+        let sketch = sketch.insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.1)?;
+        let num_faces = solid
+            .shells()
+            .iter()
+            .map(|shell| shell.faces().len())
+            .sum::<usize>();
+
+        let expected = (&*solid, tolerance).triangulate();
+        let mesh = (&*solid, tolerance).triangulate_cancellable(None)?;
+        assert_eq!(
+            mesh.triangles().collect::<Vec<_>>(),
+            expected.triangles().collect::<Vec<_>>()
+        );
+
+        let token = CancellationToken::new();
+        (&*solid, tolerance).triangulate_cancellable(Some(&token))?;
+        assert_eq!(token.num_checks(), num_faces * 2);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = (&*solid, tolerance).triangulate_cancellable(Some(&token));
+        assert_eq!(result.err(), Some(Cancelled));
+        assert!(token.num_checks() < num_faces / 10);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn uv_of_square() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let mesh = face.approx(tolerance).triangulate();
+        let uvs = mesh
+            .triangles()
+            .flat_map(|triangle| triangle.uv.unwrap())
+            .collect::<Vec<_>>();
+        for uv in &uvs {
+            for coord in uv.coords.components {
+                assert!(coord == Scalar::ZERO || coord == Scalar::ONE);
+            }
+        }
+        for corner in [[0., 0.], [1., 0.], [1., 1.], [0., 1.]] {
+            assert!(uvs.contains(&Point::from(corner)));
+        }
+
+        let mesh = (face.approx(tolerance), UvMapping::Raw).triangulate();
+        for triangle in mesh.triangles() {
+            let uv = triangle.uv.unwrap();
+            let points = triangle.inner.points().map(|point| Point {
+                coords: point.coords.xy(),
+            });
+            assert_eq!(uv, points);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn uv_of_cylinder() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = cylinder(&mut services);
+        let side = cylinder_side(&solid);
+
+        let tolerance = tolerance();
+        let mesh = side.approx(tolerance).triangulate();
+
+        let eps = Scalar::from(1e-9);
+        let mut seam_start = Vec::new();
+        let mut seam_end = Vec::new();
+
+        for triangle in mesh.triangles() {
+            let uv = triangle.uv.unwrap();
+
+            // The U coordinate goes around the circumference. A triangle that
+            // spans a large range of it would interpolate across the seam.
+            let us = uv.map(|uv| uv.u);
+            let span = *us.iter().max().unwrap() - *us.iter().min().unwrap();
+            assert!(span < Scalar::from(0.5));
+
+            for (point, uv) in triangle.inner.points().into_iter().zip(uv) {
+                assert!(uv.u >= Scalar::ZERO && uv.u <= Scalar::ONE);
+                assert!(uv.v >= Scalar::ZERO && uv.v <= Scalar::ONE);
+
+                // U maps to the angle around the circle, V to the height.
+                let (_, cos) = (uv.u * Scalar::TAU).sin_cos();
+                assert!((point.x - cos).abs() < eps);
+                assert!((point.z - uv.v).abs() < eps);
+
+                if uv.u < eps {
+                    seam_start.push(point);
+                }
+                if uv.u > Scalar::ONE - eps {
+                    seam_end.push(point);
+                }
+            }
+        }
+
+        // Points on the seam appear twice, once at each end of the U range.
+        assert!(!seam_start.is_empty());
+        for point in seam_start {
+            assert!(seam_end
+                .iter()
+                .any(|other| other.distance_to(&point) < eps));
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
     fn triangulate(face: Face) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
         Ok(face.approx(tolerance).triangulate())
     }
+
+    #[test]
+    fn contains_point_surface() {
+        let mut services = Services::new();
+
+        let square = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+
+        let contains =
+            |point: [f64; 2]| cycle_contains_point_surface(&square, point, 0.1);
+        assert!(contains([0.5, 0.5]));
+        assert!(!contains([1.5, 0.5]));
+        assert!(!contains([0.5, -0.5]));
+
+        // Points on the boundary are inside.
+        assert!(contains([1., 0.5]));
+        assert!(contains([0., 0.]));
+
+        let circle = Cycle::circle([0., 0.], 1., &mut services);
+        assert!(cycle_contains_point_surface(&circle, [0.5, 0.5], 0.01));
+        assert!(!cycle_contains_point_surface(&circle, [0.8, 0.8], 0.01));
+    }
 }
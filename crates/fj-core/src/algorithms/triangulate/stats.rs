@@ -0,0 +1,299 @@
+//! Triangulation with statistics about the resulting mesh
+//!
+//! See [`triangulate_with_stats`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+use fj_interop::mesh::{Mesh, Triangle};
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::approx::{solid::approx_faces_with_overrides, Tolerance},
+    objects::Solid,
+    storage::ObjectId,
+};
+
+use super::Triangulate;
+
+/// Triangulate a solid, and collect statistics about each of its faces
+///
+/// Tolerances are chosen per face, as described in
+/// [`approx_with_overrides`]. The returned statistics are keyed by the ID of
+/// each face, which is the same key that `overrides` uses.
+///
+/// [`approx_with_overrides`]: crate::algorithms::approx::solid::approx_with_overrides
+pub fn triangulate_with_stats(
+    solid: &Solid,
+    default_tolerance: impl Into<Tolerance>,
+    overrides: &HashMap<ObjectId, Tolerance>,
+) -> (Mesh<Point<3>>, TriangulationStats) {
+    let mut mesh = Mesh::new();
+    let mut faces = BTreeMap::new();
+
+    for (id, tolerance, approx) in
+        approx_faces_with_overrides(solid, default_tolerance.into(), overrides)
+    {
+        let num_triangles_before = mesh.triangles().count();
+        approx.triangulate_into_mesh(&mut mesh);
+
+        let mesh_stats = MeshStats::from_triangles(
+            mesh.triangles().skip(num_triangles_before),
+        );
+        faces.insert(
+            id,
+            FaceStats {
+                tolerance,
+                mesh: mesh_stats,
+            },
+        );
+    }
+
+    let total = MeshStats {
+        num_triangles: faces.values().map(|face| face.mesh.num_triangles).sum(),
+        num_vertices: mesh.vertices().count(),
+        area: Scalar::sum_stable(faces.values().map(|face| face.mesh.area)),
+        worst_aspect_ratio: faces
+            .values()
+            .map(|face| face.mesh.worst_aspect_ratio)
+            .max()
+            .unwrap_or(Scalar::ZERO),
+    };
+
+    (mesh, TriangulationStats { faces, total })
+}
+
+/// Statistics about the triangulation of a solid
+///
+/// Returned by [`triangulate_with_stats`]. The [`fmt::Display`]
+/// implementation formats the statistics as a table, with the faces that
+/// produced the most triangles first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TriangulationStats {
+    /// The statistics of each face, keyed by the face's ID
+    pub faces: BTreeMap<ObjectId, FaceStats>,
+
+    /// The statistics of the whole mesh
+    ///
+    /// The number of triangles and the area are the sums of the respective
+    /// values of all faces. Vertices that are shared between faces are only
+    /// counted once, so the number of vertices can be less than the sum.
+    pub total: MeshStats,
+}
+
+impl TriangulationStats {
+    /// Access the statistics of all faces, most triangles first
+    ///
+    /// Faces with the same number of triangles are ordered by their ID.
+    pub fn faces_by_triangle_count(&self) -> Vec<(ObjectId, &FaceStats)> {
+        let mut faces = self
+            .faces
+            .iter()
+            .map(|(&id, stats)| (id, stats))
+            .collect::<Vec<_>>();
+        faces.sort_by(|(_, a), (_, b)| {
+            b.mesh.num_triangles.cmp(&a.mesh.num_triangles)
+        });
+        faces
+    }
+}
+
+impl fmt::Display for TriangulationStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<28}{:>11}{:>10}{:>14}{:>10}{:>12}",
+            "Face", "Triangles", "Vertices", "Area", "Aspect", "Tolerance",
+        )?;
+
+        for (id, stats) in self.faces_by_triangle_count() {
+            let MeshStats {
+                num_triangles,
+                num_vertices,
+                area,
+                worst_aspect_ratio,
+            } = stats.mesh;
+
+            writeln!(
+                f,
+                "{:<28}{num_triangles:>11}{num_vertices:>10}{:>14.6}{:>10.3}\
+                {:>12}",
+                format!("{id:?}"),
+                area.into_f64(),
+                worst_aspect_ratio.into_f64(),
+                stats.tolerance.inner(),
+            )?;
+        }
+
+        let MeshStats {
+            num_triangles,
+            num_vertices,
+            area,
+            worst_aspect_ratio,
+        } = self.total;
+
+        writeln!(
+            f,
+            "{:<28}{num_triangles:>11}{num_vertices:>10}{:>14.6}{:>10.3}",
+            "Total",
+            area.into_f64(),
+            worst_aspect_ratio.into_f64(),
+        )
+    }
+}
+
+/// Statistics about the triangulation of a single face
+///
+/// See [`TriangulationStats`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaceStats {
+    /// The tolerance that the face was approximated with
+    pub tolerance: Tolerance,
+
+    /// Statistics about the triangles of the face
+    pub mesh: MeshStats,
+}
+
+/// Statistics about a set of triangles
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MeshStats {
+    /// The number of triangles
+    pub num_triangles: usize,
+
+    /// The number of distinct vertices of the triangles
+    pub num_vertices: usize,
+
+    /// The total area of the triangles
+    pub area: Scalar,
+
+    /// The worst aspect ratio of any of the triangles
+    ///
+    /// The aspect ratio of a triangle is the ratio of its longest edge to the
+    /// height of the triangle over that edge. An equilateral triangle has the
+    /// best possible aspect ratio, of about 1.15.
+    pub worst_aspect_ratio: Scalar,
+}
+
+impl MeshStats {
+    fn from_triangles(triangles: impl IntoIterator<Item = Triangle>) -> Self {
+        let mut num_triangles = 0;
+        let mut vertices = BTreeSet::new();
+        let mut areas = Vec::new();
+        let mut worst_aspect_ratio = Scalar::ZERO;
+
+        for triangle in triangles {
+            let points = triangle.inner.points();
+            let [a, b, c] = points;
+
+            num_triangles += 1;
+            vertices.extend(points);
+
+            let area = (b - a).cross(&(c - a)).magnitude() / 2.;
+            areas.push(area);
+
+            let longest_edge = [(a, b), (b, c), (c, a)]
+                .map(|(a, b)| a.distance_to(&b))
+                .into_iter()
+                .max()
+                .expect("Array is not empty");
+
+            // Triangles without area don't have a meaningful aspect ratio.
+            if area > Scalar::ZERO {
+                let aspect_ratio = longest_edge * longest_edge / (area * 2.);
+                worst_aspect_ratio = worst_aspect_ratio.max(aspect_ratio);
+            }
+        }
+
+        Self {
+            num_triangles,
+            num_vertices: vertices.len(),
+            area: Scalar::sum_stable(areas),
+            worst_aspect_ratio,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            fixtures::{cylinder_side, tolerance},
+            sweep::Sweep,
+        },
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::triangulate_with_stats;
+
+    #[test]
+    fn circular_face_dominates() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let circle =
+            Region::circle([0., 0.], 1., &mut services).insert(&mut services);
+        let rectangles = [3., 6.].map(|x| {
+            Region::polygon(
+                [[x, -1.], [x + 2., -1.], [x + 2., 1.], [x, 1.]],
+                &mut services,
+            )
+            .insert(&mut services)
+        });
+        let sketch = Sketch::empty()
+            .add_region(circle)
+            .add_region(rectangles[0].clone())
+            .add_region(rectangles[1].clone())
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let side = cylinder_side(&solid);
+
+        let tolerance = tolerance();
+        let fine = Tolerance::from_scalar(0.001)?;
+        let (mesh, stats) = triangulate_with_stats(
+            &solid,
+            tolerance,
+            &HashMap::from([(side.id(), fine)]),
+        );
+
+        let faces = stats.faces_by_triangle_count();
+        assert_eq!(faces[0].0, side.id());
+        assert_eq!(faces[0].1.tolerance, fine);
+
+        let num_triangles = mesh.triangles().count();
+        assert_eq!(stats.total.num_triangles, num_triangles);
+        assert_eq!(
+            faces
+                .iter()
+                .map(|(_, face)| face.mesh.num_triangles)
+                .sum::<usize>(),
+            num_triangles,
+        );
+        assert_eq!(stats.total.num_vertices, mesh.vertices().count());
+
+        let area = Scalar::sum_stable(mesh.triangles().map(|triangle| {
+            let [a, b, c] = triangle.inner.points();
+            (b - a).cross(&(c - a)).magnitude() / 2.
+        }));
+        assert!((stats.total.area - area).abs() < Scalar::from(1e-12));
+
+        let table = stats.to_string();
+        assert!(table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with(&format!("{:?}", side.id())));
+        assert!(table.lines().last().unwrap().starts_with("Total"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use fj_math::{Aabb, Scalar, Vector};
+
+/// The tolerances that geometric computations are based on
+///
+/// Floating-point computations are inexact, so any comparison of geometry needs
+/// to allow for some deviation. This type collects the values that define those
+/// allowed deviations, so there is one place that defines them.
+///
+/// The distances in [`GeometryContext::default`] are meant for models with a
+/// size in the order of one unit. Use [`GeometryContext::for_model_size`] to
+/// adapt them to larger or smaller models.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct GeometryContext {
+    /// The maximum distance between identical objects
+    ///
+    /// Objects that are considered identical might still have a distance
+    /// between them, due to inaccuracies of the numerical representation. If
+    /// that distance is larger than this value, they can not be considered
+    /// identical.
+    pub identical_max_distance: Scalar,
+
+    /// The minimum distance between distinct objects
+    ///
+    /// Objects whose distance is less than this value are considered
+    /// identical.
+    pub distinct_min_distance: Scalar,
+
+    /// The maximum sine of the angle between directions considered parallel
+    pub parallel_max_sine: Scalar,
+
+    /// The epsilon value for comparisons in parameter space
+    ///
+    /// Used for values that don't have a unit of length, like the dot product
+    /// of unit vectors, or coordinates on a circle.
+    pub parameter_epsilon: Scalar,
+}
+
+impl GeometryContext {
+    /// Construct a context that is adapted to the size of a model
+    ///
+    /// The distances of the default context are scaled by the largest extent of
+    /// the model's bounding box. Angular and parameter space tolerances are
+    /// independent of the model size, and keep their default values.
+    ///
+    /// Returns the default context, if the bounding box has no extent.
+    pub fn for_model_size(aabb: &Aabb<3>) -> Self {
+        let default = Self::default();
+
+        let (_, size) = aabb.size().max_component();
+        if size <= Scalar::ZERO {
+            return default;
+        }
+
+        Self {
+            identical_max_distance: default.identical_max_distance * size,
+            distinct_min_distance: default.distinct_min_distance * size,
+            ..default
+        }
+    }
+
+    /// Determine whether two directions are parallel
+    ///
+    /// This is the case, if the sine of the angle between them is at most
+    /// [`GeometryContext::parallel_max_sine`]. Directions that point the
+    /// opposite way are parallel too. A zero vector is parallel to anything.
+    pub fn are_parallel(&self, a: &Vector<3>, b: &Vector<3>) -> bool {
+        a.cross(b).magnitude()
+            <= self.parallel_max_sine * a.magnitude() * b.magnitude()
+    }
+}
+
+impl Default for GeometryContext {
+    fn default() -> Self {
+        Self {
+            // This value was chosen pretty arbitrarily. Seems small enough to
+            // catch errors. If it turns out it's too small (because it produces
+            // false positives due to floating-point accuracy issues), we can
+            // adjust it.
+            identical_max_distance: Scalar::from_f64(5e-14),
+
+            distinct_min_distance: Scalar::from_f64(5e-7), // 0.5 µm
+
+            parallel_max_sine: Scalar::from_f64(1e-12),
+            parameter_epsilon: Scalar::from_f64(f64::EPSILON),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Scalar, Vector};
+
+    use super::GeometryContext;
+
+    #[test]
+    fn for_model_size() {
+        let default = GeometryContext::default();
+
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1000., 10., 10.]]);
+        let context = GeometryContext::for_model_size(&aabb);
+        assert_eq!(
+            context.distinct_min_distance,
+            default.distinct_min_distance * 1000.
+        );
+        assert_eq!(
+            context.identical_max_distance,
+            default.identical_max_distance * 1000.
+        );
+        assert_eq!(context.parallel_max_sine, default.parallel_max_sine);
+
+        let point = Aabb::<3>::from_points([[1., 1., 1.]]);
+        assert_eq!(GeometryContext::for_model_size(&point), default);
+    }
+
+    #[test]
+    fn are_parallel() {
+        let context = GeometryContext {
+            parallel_max_sine: Scalar::from(1e-3),
+            ..GeometryContext::default()
+        };
+
+        let x = Vector::unit_x();
+        assert!(context.are_parallel(&x, &(x * -2.)));
+        assert!(context.are_parallel(&x, &Vector::from([1., 1e-4, 0.])));
+        assert!(!context.are_parallel(&x, &Vector::from([1., 1e-2, 0.])));
+    }
+}
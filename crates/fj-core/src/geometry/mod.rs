@@ -1,11 +1,13 @@
 //! Types that are tied to objects, but aren't objects themselves
 
 mod boundary;
+mod context;
 mod path;
 mod surface;
 
 pub use self::{
     boundary::{CurveBoundary, CurveBoundaryElement},
+    context::GeometryContext,
     path::{GlobalPath, SurfacePath},
-    surface::SurfaceGeometry,
+    surface::{SurfaceCurvature, SurfaceGeometry},
 };
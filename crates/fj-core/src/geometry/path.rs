@@ -2,7 +2,7 @@
 //!
 //! See [`SurfacePath`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Transform, Vector};
 
 /// A path through surface (2D) space
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -10,6 +10,9 @@ pub enum SurfacePath {
     /// A circle
     Circle(Circle<2>),
 
+    /// An ellipse
+    Ellipse(Ellipse<2>),
+
     /// A line
     Line(Line<2>),
 }
@@ -23,6 +26,26 @@ impl SurfacePath {
         Self::Circle(Circle::from_center_and_radius(center, radius))
     }
 
+    /// Build a circle from the given radius, with a rotated parameter origin
+    ///
+    /// Like [`SurfacePath::circle_from_center_and_radius`], but the circle's
+    /// coordinate `0` is at the provided angle (in radians, counter-clockwise
+    /// from the u-axis), instead of on the u-axis itself.
+    pub fn circle_from_center_and_radius_with_origin_angle(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        origin_angle: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+        let origin_angle = origin_angle.into();
+
+        let (sin, cos) = origin_angle.sin_cos();
+        let a = Vector::from([cos, sin]) * radius;
+        let b = Vector::from([-sin, cos]) * radius;
+
+        Self::Circle(Circle::new(center, a, b))
+    }
+
     /// Build a line that represents the u-axis of the surface its on
     pub fn u_axis() -> Self {
         let a = Point::origin();
@@ -65,6 +88,7 @@ impl SurfacePath {
     ) -> Point<2> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
@@ -74,6 +98,7 @@ impl SurfacePath {
     pub fn reverse(self) -> Self {
         match self {
             Self::Circle(circle) => Self::Circle(circle.reverse()),
+            Self::Ellipse(ellipse) => Self::Ellipse(ellipse.reverse()),
             Self::Line(line) => Self::Line(line.reverse()),
         }
     }
@@ -85,6 +110,9 @@ pub enum GlobalPath {
     /// A circle
     Circle(Circle<3>),
 
+    /// An ellipse
+    Ellipse(Ellipse<3>),
+
     /// A line
     Line(Line<3>),
 }
@@ -135,6 +163,7 @@ impl GlobalPath {
     pub fn origin(&self) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
+            Self::Ellipse(ellipse) => ellipse.center() + ellipse.a(),
             Self::Line(line) => line.origin(),
         }
     }
@@ -146,6 +175,7 @@ impl GlobalPath {
     ) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
@@ -157,10 +187,25 @@ impl GlobalPath {
     ) -> Vector<3> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
             Self::Line(line) => line.vector_from_line_coords(vector),
         }
     }
 
+    /// Compute the curvature of the path at the given point
+    ///
+    /// The curvature of a path has no sign. It is `0` for lines, and the
+    /// inverse of the radius for circles.
+    pub fn curvature_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        match self {
+            Self::Circle(circle) => Scalar::ONE / circle.radius(),
+            Self::Ellipse(ellipse) => ellipse.curvature_at(point),
+            Self::Line(_) => Scalar::ZERO,
+        }
+    }
+
     /// Transform the path
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
@@ -168,6 +213,9 @@ impl GlobalPath {
             Self::Circle(curve) => {
                 Self::Circle(transform.transform_circle(&curve))
             }
+            Self::Ellipse(curve) => {
+                Self::Ellipse(transform.transform_ellipse(&curve))
+            }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
         }
     }
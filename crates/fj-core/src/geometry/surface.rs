@@ -1,6 +1,6 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{Ellipse, Line, Plane, Point, Scalar, Transform, Vector};
 
 use super::GlobalPath;
 
@@ -40,14 +40,99 @@ impl SurfaceGeometry {
     }
 
     /// Project the global point into the surface
+    ///
+    /// Returns the surface coordinates of the point on the surface that is
+    /// closest to the provided one. The point doesn't need to be on the
+    /// surface.
+    ///
+    /// For a plane, this is the orthogonal projection. For a surface with a
+    /// circular u-axis, like a cylinder, the point is first projected into the
+    /// plane of the circle, to find the u-coordinate. This results in the
+    /// closest point, if the v-axis is perpendicular to that plane. For an
+    /// elliptic u-axis, the resulting point lies in the same direction from the
+    /// center of the ellipse as the original one, which is not necessarily the
+    /// closest point.
     pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
-        let GlobalPath::Line(line) = self.u else {
-            todo!("Projecting point into non-plane surface is not supported")
+        let point = point.into();
+
+        let (center, a, b) = match self.u {
+            GlobalPath::Line(line) => {
+                let plane = Plane::from_parametric(
+                    line.origin(),
+                    line.direction(),
+                    self.v,
+                );
+                return plane.project_point(point);
+            }
+            GlobalPath::Circle(circle) => {
+                (circle.center(), circle.a(), circle.b())
+            }
+            GlobalPath::Ellipse(ellipse) => {
+                (ellipse.center(), ellipse.a(), ellipse.b())
+            }
+        };
+
+        let in_plane =
+            Plane::from_parametric(center, a, b).project_point(point);
+        let atan = Scalar::atan2(in_plane.v, in_plane.u);
+        let u = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+
+        // Now that we know where on the u-axis the point is, the v-coordinate
+        // is the orthogonal projection onto the line that runs through that
+        // point of the u-axis, along the v-axis.
+        let point_on_u = self.u.point_from_path_coords([u]);
+        let v = (point - point_on_u).dot(&self.v) / self.v.dot(&self.v);
+
+        Point::from([u, v])
+    }
+
+    /// Compute the curvature of the surface at the given point
+    ///
+    /// Returns the principal curvatures of the surface, and the directions in
+    /// which they occur. See [`SurfaceCurvature`] for the sign convention.
+    pub fn curvature_at(&self, point: impl Into<Point<2>>) -> SurfaceCurvature {
+        let point = point.into();
+
+        let ellipse = match self.u {
+            GlobalPath::Circle(circle) => Ellipse::from(circle),
+            GlobalPath::Ellipse(ellipse) => ellipse,
+            GlobalPath::Line(_) => {
+                return SurfaceCurvature {
+                    principal_curvatures: [Scalar::ZERO; 2],
+                    principal_directions: [Vector::unit_u(), Vector::unit_v()],
+                };
+            }
         };
 
-        let plane =
-            Plane::from_parametric(line.origin(), line.direction(), self.v);
-        plane.project_point(point)
+        // The surface is defined as `S(u, v) = C(u) + v * V`, with `C` being
+        // the circle or ellipse, and `V` being the v-axis of the surface. Its
+        // derivatives are computed below, and the curvature is derived from the
+        // first and second fundamental forms.
+        let s_u = ellipse.derivative_at([point.u]);
+        let s_v = self.v;
+        let s_uu = -ellipse.vector_from_ellipse_coords([point.u]);
+
+        let normal = s_u.cross(&s_v).normalize();
+
+        let e = s_u.dot(&s_u);
+        let f = s_u.dot(&s_v);
+        let g = s_v.dot(&s_v);
+        let l = s_uu.dot(&normal);
+
+        // The second derivatives involving `v` are zero, so the surface doesn't
+        // curve along the v-axis. The other principal direction is the one
+        // perpendicular to it.
+        let curvature = l * g / (e * g - f * f);
+        let direction = Vector::from([g, -f]).normalize();
+
+        SurfaceCurvature {
+            principal_curvatures: [curvature, Scalar::ZERO],
+            principal_directions: [direction, Vector::unit_v()],
+        }
     }
 
     /// Transform the surface geometry
@@ -59,13 +144,35 @@ impl SurfaceGeometry {
     }
 }
 
+/// The curvature of a surface at a specific point
+///
+/// Returned by [`SurfaceGeometry::curvature_at`].
+///
+/// The surface normal is defined as the cross product of the surface's u-axis
+/// and v-axis. A curvature is positive, if the surface curves toward that
+/// normal, and negative, if it curves away from it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SurfaceCurvature {
+    /// The principal curvatures, the first being the one with the larger
+    /// magnitude
+    pub principal_curvatures: [Scalar; 2],
+
+    /// The normalized directions of the principal curvatures, in surface
+    /// coordinates
+    pub principal_directions: [Vector<2>; 2],
+}
+
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use fj_math::{assert_abs_diff, Circle, Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::geometry::{GlobalPath, SurfaceGeometry};
 
+    use super::SurfaceCurvature;
+
     #[test]
     fn point_from_surface_coords() {
         let surface = SurfaceGeometry {
@@ -97,4 +204,74 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn curvature_of_plane() {
+        let surface = SurfaceGeometry {
+            u: GlobalPath::x_axis(),
+            v: Vector::from([0., 1., 1.]),
+        };
+
+        assert_eq!(
+            surface.curvature_at([3., -2.]),
+            SurfaceCurvature {
+                principal_curvatures: [Scalar::ZERO; 2],
+                principal_directions: [Vector::unit_u(), Vector::unit_v()],
+            }
+        );
+    }
+
+    #[test]
+    fn curvature_of_cylinder() {
+        // The normal of this surface points toward the axis of the cylinder,
+        // which is the direction the surface curves in.
+        let surface = SurfaceGeometry {
+            u: GlobalPath::Circle(Circle::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+            )),
+            v: Vector::from([0., 0., -1.]),
+        };
+
+        for point in [[0., 0.], [1., 3.], [4., -1.], [6., 0.5]] {
+            let curvature = surface.curvature_at(point);
+
+            let [k1, k2] = curvature.principal_curvatures;
+            assert_abs_diff!(k1, Scalar::from(0.5));
+            assert_abs_diff!(k2, Scalar::ZERO);
+
+            let [d1, d2] = curvature.principal_directions;
+            assert_abs_diff!(d1, Vector::unit_u());
+            assert_abs_diff!(d2, Vector::unit_v());
+        }
+
+        // Reversing the v-axis flips the normal, and with it the sign.
+        let surface = SurfaceGeometry {
+            v: -surface.v,
+            ..surface
+        };
+        let [k1, _] = surface.curvature_at([1., 1.]).principal_curvatures;
+        assert_abs_diff!(k1, Scalar::from(-0.5));
+    }
+
+    #[test]
+    fn project_global_point_onto_cylinder() {
+        let surface = SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(1.),
+            v: Vector::from([0., 0., 2.]),
+        };
+
+        // Points outside and inside of the cylinder are moved onto it.
+        for (point, expected) in [
+            ([2., 0., 2.], [0., 1.]),
+            ([0., 0.5, -1.], [FRAC_PI_2, -0.5]),
+            ([-3., 0., 0.], [PI, 0.]),
+        ] {
+            assert_abs_diff!(
+                surface.project_global_point(point),
+                Point::from(expected)
+            );
+        }
+    }
 }
@@ -30,9 +30,9 @@ impl Cycle {
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
     pub fn winding(&self) -> Winding {
-        // The cycle could be made up of one or two circles. If that is the
-        // case, the winding of the cycle is determined by the winding of the
-        // first circle.
+        // The cycle could be made up of one or two circles or ellipses. If that
+        // is the case, the winding of the cycle is determined by the winding of
+        // the first of them.
         if self.edges.len() < 3 {
             let first = self
                 .edges()
@@ -43,13 +43,14 @@ impl Cycle {
             let [a, b] = first.boundary().inner;
             let edge_direction_positive = a < b;
 
-            let circle = match first.path() {
-                SurfacePath::Circle(circle) => circle,
+            let [a, b] = match first.path() {
+                SurfacePath::Circle(circle) => [circle.a(), circle.b()],
+                SurfacePath::Ellipse(ellipse) => [ellipse.a(), ellipse.b()],
                 SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
+                    "Invalid cycle: less than 3 edges, but not all are curved"
                 ),
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+            let cross_positive = a.cross2d(&b) > Scalar::ZERO;
 
             if edge_direction_positive == cross_positive {
                 return Winding::Ccw;
@@ -62,13 +63,10 @@ impl Cycle {
         // cycle as a polygon:
         // https://stackoverflow.com/a/1165943
 
-        let mut sum = Scalar::ZERO;
-
-        for (a, b) in self.edges().pairs() {
+        let sum = Scalar::sum_stable(self.edges().pairs().map(|(a, b)| {
             let [a, b] = [a, b].map(|edge| edge.start_position());
-
-            sum += (b.u - a.u) * (b.v + a.v);
-        }
+            (b.u - a.u) * (b.v + a.v)
+        }));
 
         if sum > Scalar::ZERO {
             return Winding::Cw;
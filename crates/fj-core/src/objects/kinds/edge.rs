@@ -2,7 +2,7 @@ use fj_math::Point;
 
 use crate::{
     geometry::{CurveBoundary, SurfacePath},
-    objects::{Curve, Vertex},
+    objects::{Curve, Surface, Vertex},
     storage::{Handle, HandleWrapper},
 };
 
@@ -56,6 +56,27 @@ impl Edge {
         self.path.point_from_path_coords(start)
     }
 
+    /// Compute the surface position at the provided curve coordinate
+    ///
+    /// The coordinate does not need to be within the edge's boundary.
+    pub fn point_at_surface(&self, t: impl Into<Point<1>>) -> Point<2> {
+        self.path.point_from_path_coords(t)
+    }
+
+    /// Compute the global position at the provided curve coordinate
+    ///
+    /// `surface` must be the surface that the edge is defined on. The
+    /// coordinate does not need to be within the edge's boundary.
+    pub fn point_at(
+        &self,
+        t: impl Into<Point<1>>,
+        surface: &Surface,
+    ) -> Point<3> {
+        surface
+            .geometry()
+            .point_from_surface_coords(self.point_at_surface(t))
+    }
+
     /// Access the curve of the edge
     pub fn curve(&self) -> &Handle<Curve> {
         &self.curve
@@ -66,3 +87,43 @@ impl Edge {
         &self.start_vertex
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{assert_abs_diff, Point};
+
+    use crate::{objects::Edge, operations::BuildEdge, services::Services};
+
+    #[test]
+    fn point_at_line() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xz_plane();
+        let edge =
+            Edge::line_segment([[1., 1.], [3., 1.]], None, &mut services);
+
+        assert_eq!(edge.point_at_surface([0.5]), Point::from([2., 1.]));
+        assert_eq!(edge.point_at_surface([2.]), Point::from([5., 1.]));
+
+        assert_eq!(edge.point_at([0.], &surface), Point::from([1., 0., 1.]));
+        assert_eq!(edge.point_at([1.], &surface), Point::from([3., 0., 1.]));
+    }
+
+    #[test]
+    fn point_at_circle() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let edge = Edge::circle([1., 2.], 3., &mut services);
+
+        assert_eq!(edge.point_at_surface([0.]), Point::from([4., 2.]));
+        assert_eq!(edge.point_at([0.], &surface), Point::from([4., 2., 0.]));
+
+        assert_abs_diff!(
+            edge.point_at([FRAC_PI_2], &surface),
+            Point::from([1., 5., 0.])
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use fj_math::Point;
+
 use crate::geometry::SurfaceGeometry;
 
 /// A two-dimensional shape
@@ -16,4 +18,29 @@ impl Surface {
     pub fn geometry(&self) -> SurfaceGeometry {
         self.geometry
     }
+
+    /// Project a point into the surface
+    ///
+    /// Returns the surface coordinates of the point on the surface that is
+    /// closest to the provided point. See
+    /// [`SurfaceGeometry::project_global_point`] for details.
+    pub fn project_point(&self, point: &Point<3>) -> Point<2> {
+        self.geometry.project_global_point(*point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::services::Services;
+
+    #[test]
+    fn project_point() {
+        let services = Services::new();
+        let xy_plane = services.objects.surfaces.xy_plane();
+
+        let point = Point::from([1., 2., 3.]);
+        assert_eq!(xy_plane.project_point(&point), Point::from([1., 2.]));
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
         Surface, Vertex,
     },
     storage::{Handle, HandleWrapper, ObjectId},
-    validate::{Validate, ValidationError},
+    validate::{Validate, ValidationConfig, ValidationError},
 };
 
 macro_rules! object {
@@ -22,6 +22,17 @@ macro_rules! object {
             )*
         }
 
+        impl<F: Form> Object<F> {
+            /// Access the name of the object's type
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$ty(_) => $name,
+                    )*
+                }
+            }
+        }
+
         impl Object<BehindHandle> {
             /// Access the ID of the object
             pub fn id(&self) -> ObjectId {
@@ -40,6 +51,21 @@ macro_rules! object {
                     )*
                 }
             }
+
+            /// Validate the object, using the provided configuration
+            pub fn validate_with_config(
+                &self,
+                config: &ValidationConfig,
+                errors: &mut Vec<ValidationError>,
+            ) {
+                match self {
+                    $(
+                        Self::$ty(object) => {
+                            object.validate_with_config(config, errors)
+                        }
+                    )*
+                }
+            }
         }
 
         impl Object<WithHandle> {
@@ -1,6 +1,11 @@
 use std::collections::{btree_set, BTreeSet};
 
-use super::{BehindHandle, Curve, Cycle, Edge, Face, Object, Surface, Vertex};
+use crate::storage::Handle;
+
+use super::{
+    BehindHandle, Curve, Cycle, Edge, Face, Object, Region, Shell, Sketch,
+    Solid, Surface, Vertex,
+};
 
 /// A graph of objects and their relationships
 pub struct ObjectSet {
@@ -42,6 +47,46 @@ where
     }
 }
 
+impl ObjectSet {
+    /// Iterate over the objects in the set
+    pub fn iter(&self) -> btree_set::Iter<Object<BehindHandle>> {
+        self.inner.iter()
+    }
+
+    fn from_handle<T>(handle: &Handle<T>) -> Self
+    where
+        T: InsertIntoSet,
+        Handle<T>: Into<Object<BehindHandle>>,
+    {
+        let mut self_ = Self {
+            inner: BTreeSet::new(),
+        };
+
+        self_.inner.insert(handle.clone().into());
+        handle.insert_into_set(&mut self_);
+
+        self_
+    }
+}
+
+impl From<&Handle<Shell>> for ObjectSet {
+    fn from(shell: &Handle<Shell>) -> Self {
+        Self::from_handle(shell)
+    }
+}
+
+impl From<&Handle<Sketch>> for ObjectSet {
+    fn from(sketch: &Handle<Sketch>) -> Self {
+        Self::from_handle(sketch)
+    }
+}
+
+impl From<&Handle<Solid>> for ObjectSet {
+    fn from(solid: &Handle<Solid>) -> Self {
+        Self::from_handle(solid)
+    }
+}
+
 impl IntoIterator for ObjectSet {
     type Item = Object<BehindHandle>;
     type IntoIter = btree_set::IntoIter<Self::Item>;
@@ -73,17 +118,8 @@ impl InsertIntoSet for Face {
         objects.inner.insert(self.surface().clone().into());
         self.surface().insert_into_set(objects);
 
-        objects
-            .inner
-            .insert(self.region().exterior().clone().into());
-        self.region().exterior().insert_into_set(objects);
-
-        for interior in self.region().interiors() {
-            objects.inner.insert(interior.clone().into());
-        }
-        for interior in self.region().interiors() {
-            interior.insert_into_set(objects);
-        }
+        objects.inner.insert(self.region().clone().into());
+        self.region().insert_into_set(objects);
     }
 }
 
@@ -97,6 +133,42 @@ impl InsertIntoSet for Edge {
     }
 }
 
+impl InsertIntoSet for Region {
+    fn insert_into_set(&self, objects: &mut ObjectSet) {
+        for cycle in self.all_cycles() {
+            objects.inner.insert(cycle.clone().into());
+            cycle.insert_into_set(objects);
+        }
+    }
+}
+
+impl InsertIntoSet for Shell {
+    fn insert_into_set(&self, objects: &mut ObjectSet) {
+        for face in self.faces() {
+            objects.inner.insert(face.clone().into());
+            face.insert_into_set(objects);
+        }
+    }
+}
+
+impl InsertIntoSet for Sketch {
+    fn insert_into_set(&self, objects: &mut ObjectSet) {
+        for region in self.regions() {
+            objects.inner.insert(region.clone().into());
+            region.insert_into_set(objects);
+        }
+    }
+}
+
+impl InsertIntoSet for Solid {
+    fn insert_into_set(&self, objects: &mut ObjectSet) {
+        for shell in self.shells() {
+            objects.inner.insert(shell.clone().into());
+            shell.insert_into_set(objects);
+        }
+    }
+}
+
 impl InsertIntoSet for Surface {
     fn insert_into_set(&self, _: &mut ObjectSet) {}
 }
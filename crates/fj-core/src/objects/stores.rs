@@ -1,8 +1,11 @@
+use std::fmt::{Debug, Write};
+
 use fj_math::Vector;
 
 use crate::{
-    geometry::{GlobalPath, SurfaceGeometry},
-    storage::{Handle, Store},
+    geometry::{GeometryContext, GlobalPath, SurfaceGeometry},
+    storage::{Handle, Iter, Store},
+    validate::{Validate, ValidationConfig, ValidationError},
 };
 
 use super::{
@@ -48,6 +51,143 @@ impl Objects {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Dump all objects into a human-readable string, for debugging
+    ///
+    /// Lists the objects in each store, identified the same way that the
+    /// [`Debug`] implementation of [`Handle`] identifies them. This makes it
+    /// possible to look up handles that are referenced in validation errors.
+    ///
+    /// Objects are listed with their key fields and the handles of the objects
+    /// they reference. Curves and vertices don't have any data of their own.
+    /// Their geometry is defined by the edges that refer to them, and each
+    /// edge lists its start position.
+    pub fn dump(&self) -> String {
+        let mut output = String::new();
+
+        dump_store(&mut output, "Curves", self.curves.iter(), |_| {
+            String::new()
+        });
+        dump_store(&mut output, "Cycles", self.cycles.iter(), |cycle| {
+            let edges = cycle.edges().iter().collect::<Vec<_>>();
+            format!("edges: {edges:?}")
+        });
+        dump_store(&mut output, "Edges", self.edges.iter(), |edge| {
+            format!(
+                "path: {:?}, boundary: {:?}, start position: {}, curve: {:?}, \
+                start vertex: {:?}",
+                edge.path(),
+                edge.boundary().inner,
+                edge.start_position(),
+                edge.curve(),
+                edge.start_vertex(),
+            )
+        });
+        dump_store(&mut output, "Faces", self.faces.iter(), |face| {
+            format!(
+                "surface: {:?}, region: {:?}",
+                face.surface(),
+                face.region()
+            )
+        });
+        dump_store(&mut output, "Regions", self.regions.iter(), |region| {
+            let interiors = region.interiors().iter().collect::<Vec<_>>();
+            format!(
+                "exterior: {:?}, interiors: {interiors:?}, color: {:?}",
+                region.exterior(),
+                region.color(),
+            )
+        });
+        dump_store(&mut output, "Shells", self.shells.iter(), |shell| {
+            let faces = shell.faces().iter().collect::<Vec<_>>();
+            format!("faces: {faces:?}")
+        });
+        dump_store(&mut output, "Sketches", self.sketches.iter(), |sketch| {
+            let regions = sketch.regions().iter().collect::<Vec<_>>();
+            format!("regions: {regions:?}")
+        });
+        dump_store(&mut output, "Solids", self.solids.iter(), |solid| {
+            let shells = solid.shells().iter().collect::<Vec<_>>();
+            format!("shells: {shells:?}")
+        });
+        dump_store(&mut output, "Surfaces", self.surfaces.iter(), |surface| {
+            let SurfaceGeometry { u, v } = surface.geometry();
+            format!("u: {u:?}, v: {v:?}")
+        });
+        dump_store(&mut output, "Vertices", self.vertices.iter(), |_| {
+            String::new()
+        });
+
+        output
+    }
+
+    /// Validate all objects in all stores
+    ///
+    /// Objects are validated when they are inserted through the validation
+    /// service, but objects that are inserted into a store directly are not.
+    /// This method makes sure that every object is covered, regardless of how
+    /// it ended up in the store.
+    ///
+    /// The validation configuration is derived from the provided
+    /// [`GeometryContext`].
+    ///
+    /// Returns all validation errors that were found, if any.
+    pub fn validate_all(
+        &self,
+        context: &GeometryContext,
+    ) -> Result<(), Vec<ValidationError>> {
+        let config = ValidationConfig::from_context(context);
+        let mut errors = Vec::new();
+
+        validate_store(self.curves.iter(), &config, &mut errors);
+        validate_store(self.cycles.iter(), &config, &mut errors);
+        validate_store(self.edges.iter(), &config, &mut errors);
+        validate_store(self.faces.iter(), &config, &mut errors);
+        validate_store(self.regions.iter(), &config, &mut errors);
+        validate_store(self.shells.iter(), &config, &mut errors);
+        validate_store(self.sketches.iter(), &config, &mut errors);
+        validate_store(self.solids.iter(), &config, &mut errors);
+        validate_store(self.surfaces.iter(), &config, &mut errors);
+        validate_store(self.vertices.iter(), &config, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn validate_store<T: Validate>(
+    objects: Iter<T>,
+    config: &ValidationConfig,
+    errors: &mut Vec<ValidationError>,
+) {
+    for object in objects {
+        object.validate_with_config(config, errors);
+    }
+}
+
+fn dump_store<T: Debug>(
+    output: &mut String,
+    name: &str,
+    objects: Iter<T>,
+    details: impl Fn(&T) -> String,
+) {
+    let objects = objects.collect::<Vec<_>>();
+
+    // Writing to a `String` can't fail.
+    let _ = writeln!(output, "{name} ({}):", objects.len());
+
+    for object in objects {
+        let details = details(&object);
+
+        let _ = if details.is_empty() {
+            writeln!(output, "    {object:?}")
+        } else {
+            writeln!(output, "    {object:?}: {details}")
+        };
+    }
 }
 
 /// Store for [`Surface`]s
@@ -71,6 +211,11 @@ impl Surfaces {
         self.store.insert(handle, surface);
     }
 
+    /// Iterate over all surfaces in the store
+    pub fn iter(&self) -> Iter<Surface> {
+        self.store.iter()
+    }
+
     /// Access the xy-plane
     pub fn xy_plane(&self) -> Handle<Surface> {
         self.xy_plane.clone()
@@ -125,3 +270,81 @@ impl Default for Surfaces {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        geometry::{GeometryContext, SurfacePath},
+        objects::{Curve, Cycle, Edge, Vertex},
+        operations::{BuildCycle, Insert},
+        services::Services,
+        validate::{EdgeValidationError, ValidationError},
+    };
+
+    use super::Objects;
+
+    #[test]
+    fn dump() {
+        let mut services = Services::new();
+
+        let cycle =
+            Cycle::polygon([[1., 2.], [3., 4.], [1., 4.]], &mut services)
+                .insert(&mut services);
+        let edge = cycle.edges().nth(0).unwrap();
+
+        let dump = services.objects.dump();
+
+        assert!(dump.contains(&format!("{edge:?}: ")));
+        assert!(dump.contains("start position: (1, 2)"));
+        assert!(dump.contains(&format!("{:?}", edge.curve())));
+        assert!(dump.contains(&format!("{:?}", edge.start_vertex())));
+        assert!(dump.contains(&format!("{cycle:?}: edges: [{edge:?}, ")));
+        assert!(dump.contains("Surfaces (3):"));
+    }
+
+    #[test]
+    fn validate_all() {
+        let mut objects = Objects::new();
+        let context = GeometryContext::default();
+
+        let curve = objects.curves.reserve();
+        objects.curves.insert(curve.clone(), Curve::new());
+        let vertex = objects.vertices.reserve();
+        objects.vertices.insert(vertex.clone(), Vertex::new());
+
+        let (path, boundary) =
+            SurfacePath::line_from_points([[0., 0.], [1., 0.]]);
+        let edge = objects.edges.reserve();
+        objects.edges.insert(
+            edge,
+            Edge::new(path, boundary, curve.clone(), vertex.clone()),
+        );
+        assert!(objects.validate_all(&context).is_ok());
+
+        // Inserting into the store directly bypasses validation.
+        let invalid = objects.edges.reserve();
+        objects.edges.insert(
+            invalid,
+            Edge::new(path, [Point::from([0.]); 2], curve, vertex),
+        );
+
+        let errors = objects.validate_all(&context).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ValidationError::Edge(
+                EdgeValidationError::VerticesAreCoincident { .. }
+            )
+        ));
+
+        // With a larger minimum distance, the other edge is too short too.
+        let context = GeometryContext {
+            distinct_min_distance: Scalar::from(2.),
+            ..context
+        };
+        let errors = objects.validate_all(&context).unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+}
@@ -24,6 +24,25 @@ pub trait BuildCycle {
         Cycle::empty().add_edges([circle])
     }
 
+    /// Build a circle, with its start vertex at the provided angle
+    ///
+    /// See [`BuildEdge::circle_with_origin_angle`].
+    fn circle_with_origin_angle(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        origin_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Cycle {
+        let circle = Edge::circle_with_origin_angle(
+            center,
+            radius,
+            origin_angle,
+            services,
+        )
+        .insert(services);
+        Cycle::empty().add_edges([circle])
+    }
+
     /// Build a polygon
     fn polygon<P, Ps>(points: Ps, services: &mut Services) -> Cycle
     where
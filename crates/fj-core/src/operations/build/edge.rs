@@ -61,6 +61,30 @@ pub trait BuildEdge {
         Edge::unjoined(path, boundary, services)
     }
 
+    /// Create a circle, with its start vertex at the provided angle
+    ///
+    /// The angle is given in radians, counter-clockwise from the u-axis. See
+    /// [`SurfacePath::circle_from_center_and_radius_with_origin_angle`].
+    ///
+    /// This controls where the seam of any surface is, that is created by
+    /// sweeping the circle.
+    fn circle_with_origin_angle(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        origin_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Edge {
+        let path = SurfacePath::circle_from_center_and_radius_with_origin_angle(
+            center,
+            radius,
+            origin_angle,
+        );
+        let boundary =
+            [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
+
+        Edge::unjoined(path, boundary, services)
+    }
+
     /// Create a line segment
     fn line_segment(
         points_surface: [impl Into<Point<2>>; 2],
@@ -4,5 +4,6 @@ pub mod face;
 pub mod region;
 pub mod shell;
 pub mod sketch;
+pub mod sketch_dsl;
 pub mod solid;
 pub mod surface;
@@ -27,6 +27,27 @@ pub trait BuildRegion {
         Region::new(exterior, [], None)
     }
 
+    /// Build a circle, with its start vertex at the provided angle
+    ///
+    /// See [`BuildEdge::circle_with_origin_angle`].
+    ///
+    /// [`BuildEdge::circle_with_origin_angle`]: crate::operations::BuildEdge::circle_with_origin_angle
+    fn circle_with_origin_angle(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        origin_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Region {
+        let exterior = Cycle::circle_with_origin_angle(
+            center,
+            radius,
+            origin_angle,
+            services,
+        )
+        .insert(services);
+        Region::new(exterior, [], None)
+    }
+
     /// Build a polygon
     fn polygon<P, Ps>(points: Ps, services: &mut Services) -> Region
     where
@@ -1,7 +1,11 @@
-use fj_math::Point;
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::Color;
+use fj_math::{Point, Vector};
 
 use crate::{
-    objects::{Face, Shell},
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{Curve, Cycle, Edge, Face, Region, Shell, Surface, Vertex},
     operations::{
         reverse::ReverseCurveCoordinateSystems, update::region::UpdateRegion,
         BuildFace, Insert, IsInserted, IsInsertedNo, IsInsertedYes, JoinCycle,
@@ -142,6 +146,153 @@ pub trait BuildShell {
             cbd,
         }
     }
+
+    /// Build a polyhedron from the provided points and polygons
+    ///
+    /// Each polygon is defined by the indices of its points. All faces of the
+    /// polyhedron are planes, and the edges between them are line segments.
+    ///
+    /// # Assumptions
+    ///
+    /// This method makes some assumptions that need to be met, if the result
+    /// is to be a valid shell:
+    ///
+    /// - **The points of each polygon must lie on a plane.**
+    /// - **The points of each polygon must be in counter-clockwise order,
+    ///   when viewed from the outside.**
+    /// - **Every edge of a polygon must be shared with exactly one other
+    ///   polygon, which must refer to the edge's points in reverse order.**
+    ///
+    /// # Panics
+    ///
+    /// Panics, if a polygon refers to a point that doesn't exist, or if a
+    /// polygon has fewer than 3 points.
+    fn polyhedron(
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+        polygons: impl IntoIterator<Item = impl IntoIterator<Item = usize>>,
+        services: &mut Services,
+    ) -> Shell {
+        Self::polyhedron_with_holes(
+            points,
+            polygons.into_iter().map(|polygon| [polygon]),
+            None,
+            services,
+        )
+    }
+
+    /// Build a polyhedron whose faces can have holes
+    ///
+    /// Like [`BuildShell::polyhedron`], except that each face is defined by
+    /// multiple polygons: Its exterior first, followed by its interiors. The
+    /// same assumptions apply, except that the interiors must be in clockwise
+    /// order, when viewed from the outside.
+    ///
+    /// All faces get the provided color.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if a polygon refers to a point that doesn't exist, if a face has
+    /// no polygons, or if a polygon has fewer than 3 points.
+    fn polyhedron_with_holes(
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+        faces: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl IntoIterator<Item = usize>>,
+        >,
+        color: Option<Color>,
+        services: &mut Services,
+    ) -> Shell {
+        let points: Vec<Point<3>> =
+            points.into_iter().map(Into::into).collect();
+        let vertices: Vec<_> = points
+            .iter()
+            .map(|_| Vertex::new().insert(services))
+            .collect();
+
+        // The two edges that are shared by two polygons need to refer to the
+        // same curve. They also need to agree on the curve's coordinate
+        // system, so each line is defined to go from the point with the lower
+        // index to the point with the higher one.
+        let mut curves = BTreeMap::new();
+
+        let faces = faces
+            .into_iter()
+            .map(|polygons| {
+                let polygons: Vec<Vec<usize>> = polygons
+                    .into_iter()
+                    .map(|polygon| polygon.into_iter().collect())
+                    .collect();
+                let exterior = polygons.first().expect("Face needs exterior");
+                for polygon in &polygons {
+                    assert!(
+                        polygon.len() >= 3,
+                        "Polygon needs at least 3 points"
+                    );
+                }
+
+                // Define the surface such that the exterior winds counter-
+                // clockwise, when viewed from the front.
+                let [a, b] = [exterior[0], exterior[1]].map(|i| points[i]);
+                let normal = newell_normal(
+                    &exterior.iter().map(|&i| points[i]).collect::<Vec<_>>(),
+                );
+                let (u, _) = GlobalPath::line_from_points([a, b]);
+                let v = normal.normalize().cross(&(b - a));
+                let surface = Surface::new(SurfaceGeometry { u, v });
+
+                let mut cycles = polygons.iter().map(|polygon| {
+                    let edges = (0..polygon.len()).map(|i| {
+                        let j = (i + 1) % polygon.len();
+                        let [start, end] = [polygon[i], polygon[j]];
+                        let [low, high] = if start < end {
+                            [start, end]
+                        } else {
+                            [end, start]
+                        };
+
+                        let curve = curves
+                            .entry((low, high))
+                            .or_insert_with(|| Curve::new().insert(services))
+                            .clone();
+
+                        let path = SurfacePath::line_from_points_with_coords(
+                            [([0.], low), ([1.], high)].map(|(t, index)| {
+                                (
+                                    t,
+                                    surface
+                                        .geometry()
+                                        .project_global_point(points[index]),
+                                )
+                            }),
+                        );
+                        let boundary = if start < end {
+                            [[0.], [1.]]
+                        } else {
+                            [[1.], [0.]]
+                        };
+
+                        Edge::new(
+                            path,
+                            boundary.map(Point::from),
+                            curve,
+                            vertices[start].clone(),
+                        )
+                        .insert(services)
+                    });
+
+                    Cycle::new(edges.collect::<Vec<_>>()).insert(services)
+                });
+
+                let exterior = cycles.next().expect("Face needs exterior");
+                let region =
+                    Region::new(exterior, cycles.collect::<Vec<_>>(), color)
+                        .insert(services);
+
+                Face::new(surface.insert(services), region).insert(services)
+            })
+            .collect::<Vec<_>>();
+
+        Shell::new(faces)
+    }
 }
 
 impl BuildShell for Shell {}
@@ -169,3 +320,21 @@ pub struct TetrahedronShell<I: IsInserted = IsInsertedNo> {
     /// The face formed by the points `c`, `b`, and `d`.
     pub cbd: Polygon<3, IsInsertedYes>,
 }
+
+/// Compute the normal of a polygon, using Newell's method
+///
+/// The polygon winds counter-clockwise around the returned normal. The length
+/// of the normal is twice the area of the polygon.
+pub(crate) fn newell_normal(polygon: &[Point<3>]) -> Vector<3> {
+    let mut normal = Vector::from([0., 0., 0.]);
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+
+        normal.x += (a.y - b.y) * (a.z + b.z);
+        normal.y += (a.z - b.z) * (a.x + b.x);
+        normal.z += (a.x - b.x) * (a.y + b.y);
+    }
+
+    normal
+}
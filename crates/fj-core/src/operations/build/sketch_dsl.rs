@@ -0,0 +1,328 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::GeometryContext,
+    objects::{Cycle, Edge, Face, Region, Surface},
+    operations::{BuildEdge, Insert},
+    services::Services,
+    storage::Handle,
+};
+
+/// A lightweight API for describing closed outlines made of lines and arcs
+///
+/// Records a path in surface coordinates, segment by segment, starting at the
+/// point passed to [`SketchDsl::start_at`]. Each segment starts where the
+/// previous one ended. Once the path is complete, it can be converted into a
+/// [`Cycle`] or a [`Face`].
+///
+/// ``` rust
+/// use fj_core::{
+///     geometry::GeometryContext,
+///     operations::{Side, SketchDsl},
+///     services::Services,
+/// };
+///
+/// let mut services = Services::new();
+/// let surface = services.objects.surfaces.xy_plane();
+/// let context = GeometryContext::default();
+///
+/// let face = SketchDsl::start_at([0., 0.])
+///     .line_to([2., 0.])
+///     .arc_to([2., 2.], 1., Side::Left)
+///     .line_to([0., 2.])
+///     .line_to([0., 0.])
+///     .close(surface, &context, &mut services)
+///     .unwrap();
+/// # let _ = face;
+/// ```
+#[derive(Clone, Debug)]
+pub struct SketchDsl {
+    start: Point<2>,
+    segments: Vec<SketchSegment>,
+}
+
+impl SketchDsl {
+    /// Start a new path at the provided point
+    pub fn start_at(point: impl Into<Point<2>>) -> Self {
+        Self {
+            start: point.into(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Add a line segment from the current point to the provided one
+    #[must_use]
+    pub fn line_to(mut self, end: impl Into<Point<2>>) -> Self {
+        self.segments.push(SketchSegment::Line { end: end.into() });
+        self
+    }
+
+    /// Add an arc from the current point to the provided one
+    ///
+    /// The arc is the shorter one of the two possible arcs with the given
+    /// radius. `side` defines on which side of the path the center of the arc
+    /// is, meaning the direction in which the path turns.
+    #[must_use]
+    pub fn arc_to(
+        mut self,
+        end: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        side: Side,
+    ) -> Self {
+        self.segments.push(SketchSegment::Arc {
+            end: end.into(),
+            radius: radius.into(),
+            side,
+        });
+        self
+    }
+
+    /// Convert the path into a [`Cycle`]
+    ///
+    /// Returns an error, if the path is empty, if it doesn't end where it
+    /// started, if any of its segments has zero length, or if the radius of
+    /// any arc is too small to connect its endpoints. Distances below
+    /// [`GeometryContext::distinct_min_distance`] count as zero.
+    pub fn into_cycle(
+        self,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Cycle, SketchDslError> {
+        let min_distance = context.distinct_min_distance;
+
+        let Some(last) = self.segments.last() else {
+            return Err(SketchDslError::Empty);
+        };
+        if (last.end() - self.start).magnitude() >= min_distance {
+            return Err(SketchDslError::Unclosed {
+                start: self.start,
+                end: last.end(),
+            });
+        }
+
+        let mut edges = Vec::new();
+        let mut start = self.start;
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            // Make sure the cycle is closed exactly, even if the user-provided
+            // end point is off by a tiny bit.
+            let end = if index == self.segments.len() - 1 {
+                self.start
+            } else {
+                segment.end()
+            };
+
+            let chord = (end - start).magnitude();
+            if chord < min_distance {
+                return Err(SketchDslError::ZeroLengthSegment {
+                    index,
+                    point: start,
+                });
+            }
+
+            let edge = match *segment {
+                SketchSegment::Line { .. } => {
+                    Edge::line_segment([start, end], None, services)
+                }
+                SketchSegment::Arc { radius, side, .. } => {
+                    if radius * 2. < chord {
+                        return Err(SketchDslError::ArcRadiusTooSmall {
+                            index,
+                            radius,
+                            chord,
+                        });
+                    }
+
+                    let half_angle =
+                        (chord / (radius * 2.)).into_f64().min(1.).asin();
+                    let angle = match side {
+                        Side::Left => half_angle * 2.,
+                        Side::Right => -half_angle * 2.,
+                    };
+
+                    Edge::arc(start, end, angle, services)
+                }
+            };
+
+            edges.push(edge.insert(services));
+            start = end;
+        }
+
+        Ok(Cycle::new(edges))
+    }
+
+    /// Convert the path into a [`Face`] on the provided surface
+    ///
+    /// The path becomes the exterior of the face. See [`SketchDsl::into_cycle`]
+    /// for the errors that can occur.
+    pub fn close(
+        self,
+        surface: Handle<Surface>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Face, SketchDslError> {
+        let exterior = self.into_cycle(context, services)?.insert(services);
+        let region = Region::new(exterior, [], None).insert(services);
+
+        Ok(Face::new(surface, region))
+    }
+}
+
+/// The side of a path, relative to its direction
+///
+/// See [`SketchDsl::arc_to`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Side {
+    /// The left side; an arc that curves to the left
+    Left,
+
+    /// The right side; an arc that curves to the right
+    Right,
+}
+
+/// Error converting a [`SketchDsl`] path into objects
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SketchDslError {
+    /// The path has no segments
+    #[error("Sketch path has no segments")]
+    Empty,
+
+    /// The path doesn't end where it started
+    #[error(
+        "Sketch path is not closed\n\
+        - Start: {start:?}\n\
+        - End: {end:?}"
+    )]
+    Unclosed {
+        /// The point where the path starts
+        start: Point<2>,
+
+        /// The point where the path ends
+        end: Point<2>,
+    },
+
+    /// A segment of the path has zero length
+    #[error(
+        "Segment of sketch path has zero length\n\
+        - Index of segment: {index}\n\
+        - Point: {point:?}"
+    )]
+    ZeroLengthSegment {
+        /// The index of the segment within the path
+        index: usize,
+
+        /// The point where the segment starts and ends
+        point: Point<2>,
+    },
+
+    /// The radius of an arc is too small to connect its endpoints
+    #[error(
+        "Arc radius is less than half the distance between its endpoints\n\
+        - Index of segment: {index}\n\
+        - Radius: {radius}\n\
+        - Distance between endpoints: {chord}"
+    )]
+    ArcRadiusTooSmall {
+        /// The index of the arc within the path
+        index: usize,
+
+        /// The radius of the arc
+        radius: Scalar,
+
+        /// The distance between the endpoints of the arc
+        chord: Scalar,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SketchSegment {
+    Line {
+        end: Point<2>,
+    },
+    Arc {
+        end: Point<2>,
+        radius: Scalar,
+        side: Side,
+    },
+}
+
+impl SketchSegment {
+    fn end(&self) -> Point<2> {
+        match self {
+            Self::Line { end } => *end,
+            Self::Arc { end, .. } => *end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::sweep::Sweep, geometry::GeometryContext, objects::Solid,
+        operations::Insert, services::Services,
+    };
+
+    use super::{Side, SketchDsl, SketchDslError};
+
+    #[test]
+    fn rounded_rectangle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let surface = services.objects.surfaces.xy_plane();
+
+        let face = SketchDsl::start_at([1., 0.])
+            .line_to([3., 0.])
+            .arc_to([4., 1.], 1., Side::Left)
+            .line_to([4., 2.])
+            .arc_to([3., 3.], 1., Side::Left)
+            .line_to([1., 3.])
+            .arc_to([0., 2.], 1., Side::Left)
+            .line_to([0., 1.])
+            .arc_to([1., 0.], 1., Side::Left)
+            .close(surface, &GeometryContext::default(), &mut services)?
+            .insert(&mut services);
+        assert_eq!(face.region().exterior().edges().len(), 8);
+
+        let shell = face.sweep([0., 0., 1.], &mut services);
+        let solid = Solid::new([shell]).insert(&mut services);
+        assert_eq!(solid.shells().len(), 1);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        assert_eq!(
+            SketchDsl::start_at([0., 0.]).into_cycle(&context, &mut services),
+            Err(SketchDslError::Empty)
+        );
+
+        let result = SketchDsl::start_at([0., 0.])
+            .line_to([1., 0.])
+            .line_to([1., 1.])
+            .into_cycle(&context, &mut services);
+        assert!(matches!(result, Err(SketchDslError::Unclosed { .. })));
+
+        let result = SketchDsl::start_at([0., 0.])
+            .line_to([1., 0.])
+            .line_to([1., 0.])
+            .line_to([0., 0.])
+            .into_cycle(&context, &mut services);
+        assert!(matches!(
+            result,
+            Err(SketchDslError::ZeroLengthSegment { index: 1, .. })
+        ));
+
+        let result = SketchDsl::start_at([0., 0.])
+            .arc_to([4., 0.], 1., Side::Left)
+            .line_to([0., 0.])
+            .into_cycle(&context, &mut services);
+        assert!(matches!(
+            result,
+            Err(SketchDslError::ArcRadiusTooSmall { index: 0, .. })
+        ));
+    }
+}
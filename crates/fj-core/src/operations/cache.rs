@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use fj_math::Scalar;
+
+/// Cache for the results of parametric builders
+///
+/// When building a family of parts, each variant is built by calling the same
+/// builder functions with different parameters. Often, only a few parameters
+/// change between variants, and the objects built from the other parameters
+/// end up being identical. `ParamCache` allows for reusing those objects,
+/// instead of building them again.
+///
+/// The cached values are typically [`Handle`]s to objects. Objects are never
+/// removed from the stores, so a cached handle stays valid for as long as the
+/// stores that it was inserted into. A cache must only be used with the same
+/// [`Services`] instance that its values were built with.
+///
+/// [`Handle`]: crate::storage::Handle
+/// [`Services`]: crate::services::Services
+#[derive(Clone, Debug)]
+pub struct ParamCache<T> {
+    inner: BTreeMap<ParamKey, T>,
+}
+
+impl<T> ParamCache<T> {
+    /// Construct an empty instance of `ParamCache`
+    pub fn new() -> Self {
+        Self {
+            inner: BTreeMap::new(),
+        }
+    }
+
+    /// Access the cached value for the provided key, if any
+    pub fn get(&self, key: &ParamKey) -> Option<&T> {
+        self.inner.get(key)
+    }
+
+    /// Access the cached value for the provided key, or build it
+    ///
+    /// `build` is only called, if no value for `key` is cached yet. Its result
+    /// is cached, and returned from any later call with an equal key.
+    pub fn get_or_build(
+        &mut self,
+        key: ParamKey,
+        build: impl FnOnce() -> T,
+    ) -> T
+    where
+        T: Clone,
+    {
+        self.inner.entry(key).or_insert_with(build).clone()
+    }
+
+    /// Remove the cached value for the provided key
+    ///
+    /// Returns the removed value, if there was one.
+    pub fn invalidate(&mut self, key: &ParamKey) -> Option<T> {
+        self.inner.remove(key)
+    }
+
+    /// Remove all cached values that were built by the provided builder
+    pub fn invalidate_builder(&mut self, builder: &str) {
+        self.inner.retain(|key, _| key.builder != builder);
+    }
+
+    /// Remove all cached values
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Return the number of cached values
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T> Default for ParamCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The key of a value in a [`ParamCache`]
+///
+/// Identifies a builder, and the parameters that it was called with. The
+/// parameters are stored as [`Scalar`]s, which are canonical: `-0.0` and `0.0`
+/// result in the same key.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct ParamKey {
+    builder: &'static str,
+    params: Vec<Scalar>,
+}
+
+impl ParamKey {
+    /// Construct an instance of `ParamKey`
+    ///
+    /// `builder` identifies the builder function. Using the function's name is
+    /// recommended. Keys built for different builders never compare equal,
+    /// even if their parameters are the same.
+    pub fn new(
+        builder: &'static str,
+        params: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Self {
+        Self {
+            builder,
+            params: params.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Access the builder that this key identifies
+    pub fn builder(&self) -> &'static str {
+        self.builder
+    }
+
+    /// Access the parameters of this key
+    pub fn params(&self) -> &[Scalar] {
+        &self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::{ChangeTracker, Services},
+        storage::Handle,
+    };
+
+    use super::{ParamCache, ParamKey};
+
+    #[test]
+    fn keys_are_canonical() {
+        assert_eq!(
+            ParamKey::new("builder", [0., 1.]),
+            ParamKey::new("builder", [-0., 1.]),
+        );
+        assert_ne!(
+            ParamKey::new("builder", [0., 1.]),
+            ParamKey::new("other", [0., 1.]),
+        );
+        assert_ne!(
+            ParamKey::new("builder", [0., 1.]),
+            ParamKey::new("builder", [0., 1., 2.]),
+        );
+    }
+
+    #[test]
+    fn invalidate() {
+        let mut cache = ParamCache::new();
+        let mut builds = 0;
+
+        let a = ParamKey::new("a", [1.]);
+        let b = ParamKey::new("b", [1.]);
+
+        for _ in 0..2 {
+            for key in [&a, &b] {
+                cache.get_or_build(key.clone(), || {
+                    builds += 1;
+                    builds
+                });
+            }
+        }
+        assert_eq!(builds, 2);
+        assert_eq!(cache.len(), 2);
+
+        assert_eq!(cache.invalidate(&a), Some(1));
+        assert_eq!(cache.get_or_build(a.clone(), || 3), 3);
+
+        cache.invalidate_builder("b");
+        assert_eq!(cache.get(&a), Some(&3));
+        assert_eq!(cache.get(&b), None);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn reuse_unchanged_sub_solids() {
+        let mut services = Services::new();
+        let mut cache = ParamCache::new();
+
+        let (width, height, thickness) = (4., 2., 0.5);
+
+        let mut variants = Vec::new();
+        let mut changes = Vec::new();
+        for i in 0..10 {
+            let tracker = ChangeTracker::new(&services.objects);
+
+            let hole_position = f64::from(i) * 0.3 + 0.5;
+            let bracket = Bracket {
+                plate: cache.get_or_build(
+                    ParamKey::new("plate", [width, height, thickness]),
+                    || plate(width, height, thickness, &mut services),
+                ),
+                hole: hole(hole_position, &mut services),
+            };
+
+            changes.push(tracker.changed_since(&services.objects));
+            variants.push(bracket);
+        }
+
+        // The plate has only been built for the first variant, and was reused
+        // for all others.
+        assert_eq!(cache.len(), 1);
+        assert!(!changes[0].solids.is_empty());
+        for changed in &changes[1..] {
+            assert!(changed.solids.is_empty());
+            assert!(changed.faces.is_empty());
+            assert!(!changed.regions.is_empty());
+        }
+        for bracket in &variants {
+            assert_eq!(bracket.plate.id(), variants[0].plate.id());
+        }
+
+        // The holes are all different.
+        for (a, b) in variants.iter().zip(&variants[1..]) {
+            assert_ne!(a.hole.id(), b.hole.id());
+        }
+    }
+
+    struct Bracket {
+        plate: Handle<Solid>,
+        hole: Handle<Region>,
+    }
+
+    fn plate(
+        width: f64,
+        height: f64,
+        thickness: f64,
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [width, 0.], [width, height], [0., height]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface).sweep([0., 0., thickness], services)
+    }
+
+    fn hole(position: f64, services: &mut Services) -> Handle<Region> {
+        Region::circle([position, 1.], 0.2, services).insert(services)
+    }
+}
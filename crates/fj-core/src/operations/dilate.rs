@@ -0,0 +1,550 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::Tolerance,
+    geometry::{GeometryContext, GlobalPath, SurfacePath},
+    objects::{Edge, Shell, Solid, Surface},
+    operations::{newell_normal, BuildShell, Insert},
+    services::Services,
+    storage::{Handle, HandleWrapper},
+};
+
+/// Dilate a [`Solid`]
+pub trait Dilate: Sized {
+    /// Inflate the solid by the provided radius
+    ///
+    /// Approximates the Minkowski sum of the solid with a sphere, which is the
+    /// set of all points within `radius` of the solid. This is useful for
+    /// clearance checks.
+    ///
+    /// Each face is offset outward along its normal. The gaps between those
+    /// offset faces are closed by polyhedral approximations of cylinders along
+    /// the edges, and of spheres at the vertices. The points of those
+    /// approximations lie on the exact curved surfaces, and `tolerance` limits
+    /// their distance to those surfaces.
+    ///
+    /// Only strictly convex solids that are bounded by planar faces are
+    /// supported, for now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if `radius` is not positive, if the solid is not
+    /// strictly convex, or if it has curved faces or edges. Neighboring faces
+    /// that are parallel according to [`GeometryContext::are_parallel`] count
+    /// as not strictly convex.
+    fn dilate(
+        &self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self, DilateError>;
+}
+
+impl Dilate for Solid {
+    fn dilate(
+        &self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self, DilateError> {
+        let radius = radius.into();
+        let tolerance = tolerance.into();
+
+        if radius <= Scalar::ZERO {
+            return Err(DilateError::InvalidRadius { radius });
+        }
+
+        let mut shells = self.shells().iter();
+        let Some(shell) = shells.next() else {
+            return Ok(self.clone());
+        };
+        if shells.next().is_some() {
+            // A convex solid can't have any cavities.
+            return Err(DilateError::NotConvex);
+        }
+
+        let polyhedron =
+            ConvexPolyhedron::from_shell(shell, tolerance, context)?;
+
+        // An arc of the approximation deviates from the exact circle by at
+        // most `tolerance`, if its segments don't cover more than this angle.
+        let max_segment_angle = {
+            let ratio = (tolerance.inner() / radius).min(Scalar::ONE);
+            (Scalar::ONE - ratio).acos() * 2.
+        };
+        let num_segments = |angle: Scalar| {
+            (angle / max_segment_angle).ceil().into_u64().max(1) as usize
+        };
+
+        let mut points = Points {
+            polyhedron: &polyhedron,
+            radius,
+            points: Vec::new(),
+            indices_by_key: BTreeMap::new(),
+        };
+        let mut polygons = Vec::new();
+
+        // Offset faces
+        for (f, (polygon, normal)) in polyhedron.faces.iter().enumerate() {
+            polygons.push(
+                polygon
+                    .iter()
+                    .map(|&vertex| {
+                        points.shared(vertex, DirectionKey::Face(f), *normal)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        // Approximated cylinders along the edges
+        for (&(a, b), &f) in &polyhedron.faces_by_edge {
+            if a > b {
+                // Every edge is shared by two faces. Let's only build the
+                // cylinder from one of them.
+                continue;
+            }
+            let g = polyhedron.faces_by_edge[&(b, a)];
+
+            let arc = polyhedron.arc(f, g, num_segments);
+            for window in arc.windows(2) {
+                let [(key_k, dir_k), (key_l, dir_l)] = [window[0], window[1]];
+
+                // Face `f` goes from `a` to `b`, so the adjacent cylinder
+                // segment must go from `b` to `a`.
+                polygons.push(vec![
+                    points.shared(b, key_k, dir_k),
+                    points.shared(a, key_k, dir_k),
+                    points.shared(a, key_l, dir_l),
+                    points.shared(b, key_l, dir_l),
+                ]);
+            }
+        }
+
+        // Approximated spheres at the vertices
+        for vertex in 0..polyhedron.points.len() {
+            let mut boundary =
+                polyhedron.arcs_around_vertex(vertex, num_segments);
+
+            let center = Vector::sum_stable(
+                boundary
+                    .iter()
+                    .filter(|(key, _)| matches!(key, DirectionKey::Face(_)))
+                    .map(|&(_, direction)| direction),
+            )
+            .normalize();
+
+            // Make sure the boundary is counter-clockwise, when viewed from
+            // the outside.
+            let winding = Scalar::sum_stable(
+                boundary.iter().zip(boundary.iter().cycle().skip(1)).map(
+                    |(&(_, a), &(_, b))| {
+                        (a - center).cross(&(b - center)).dot(&center)
+                    },
+                ),
+            );
+            if winding < Scalar::ZERO {
+                boundary.reverse();
+            }
+
+            let max_angle = boundary
+                .iter()
+                .map(|&(_, direction)| angle_between(center, direction))
+                .max()
+                .unwrap_or(Scalar::ZERO);
+            let num_rings = num_segments(max_angle);
+
+            // Each spoke goes from the center of the patch to a point on its
+            // boundary.
+            let center_index = points.unique(vertex, center);
+            let mut spokes = Vec::new();
+            for &(key, direction) in &boundary {
+                let mut spoke = vec![center_index];
+                for j in 1..num_rings {
+                    let t = Scalar::from_u64(j as u64)
+                        / Scalar::from_u64(num_rings as u64);
+                    spoke.push(
+                        points.unique(vertex, slerp(center, direction, t)),
+                    );
+                }
+                spoke.push(points.shared(vertex, key, direction));
+
+                spokes.push(spoke);
+            }
+
+            for (a, b) in spokes.iter().zip(spokes.iter().cycle().skip(1)) {
+                polygons.push(vec![a[0], a[1], b[1]]);
+
+                for j in 1..num_rings {
+                    polygons.push(vec![a[j], a[j + 1], b[j + 1]]);
+                    polygons.push(vec![a[j], b[j + 1], b[j]]);
+                }
+            }
+        }
+
+        let shell = Shell::polyhedron(points.points, polygons, services);
+        Ok(Solid::new([shell.insert(services)]))
+    }
+}
+
+/// Error dilating a [`Solid`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DilateError {
+    /// The radius is not positive
+    #[error("Dilation radius must be positive (radius: {radius})")]
+    InvalidRadius {
+        /// The radius
+        radius: Scalar,
+    },
+
+    /// The solid is not strictly convex
+    #[error("Dilating non-convex solids is not supported")]
+    NotConvex,
+
+    /// The solid has a face whose surface is curved
+    #[error("Dilating solids with curved faces is not supported")]
+    CurvedSurface {
+        /// The curved surface
+        surface: Handle<Surface>,
+    },
+
+    /// The solid has an edge that is curved
+    #[error("Dilating solids with curved edges is not supported")]
+    CurvedEdge {
+        /// The curved edge
+        edge: Handle<Edge>,
+    },
+}
+
+/// A convex polyhedron, extracted from a shell
+struct ConvexPolyhedron {
+    points: Vec<Point<3>>,
+
+    /// The faces, each with its points in counter-clockwise order around its
+    /// outward normal
+    faces: Vec<(Vec<usize>, Vector<3>)>,
+
+    faces_by_edge: BTreeMap<(usize, usize), usize>,
+}
+
+impl ConvexPolyhedron {
+    fn from_shell(
+        shell: &Shell,
+        tolerance: Tolerance,
+        context: &GeometryContext,
+    ) -> Result<Self, DilateError> {
+        let mut points = Vec::new();
+        let mut indices_by_vertex = BTreeMap::new();
+        let mut faces = Vec::new();
+
+        for face in shell.faces() {
+            let surface = face.surface();
+            if let GlobalPath::Circle(_) | GlobalPath::Ellipse(_) =
+                surface.geometry().u
+            {
+                return Err(DilateError::CurvedSurface {
+                    surface: surface.clone(),
+                });
+            }
+            if !face.region().interiors().is_empty() {
+                return Err(DilateError::NotConvex);
+            }
+
+            let mut polygon = Vec::new();
+            for edge in face.region().exterior().edges() {
+                if let SurfacePath::Circle(_) | SurfacePath::Ellipse(_) =
+                    edge.path()
+                {
+                    return Err(DilateError::CurvedEdge { edge: edge.clone() });
+                }
+
+                let index = *indices_by_vertex
+                    .entry(HandleWrapper::from(edge.start_vertex().clone()))
+                    .or_insert_with(|| {
+                        points.push(
+                            surface.geometry().point_from_surface_coords(
+                                edge.start_position(),
+                            ),
+                        );
+                        points.len() - 1
+                    });
+                polygon.push(index);
+            }
+
+            let normal = newell_normal(
+                &polygon.iter().map(|&i| points[i]).collect::<Vec<_>>(),
+            )
+            .normalize();
+
+            faces.push((polygon, normal));
+        }
+
+        // For a convex polyhedron, the centroid of its points is inside of
+        // it. That can be used to make sure that all normals point outward,
+        // regardless of how the faces are oriented.
+        let centroid = Point {
+            coords: Vector::sum_stable(points.iter().map(|point| point.coords))
+                / Scalar::from_u64(points.len() as u64),
+        };
+        for (polygon, normal) in &mut faces {
+            if (points[polygon[0]] - centroid).dot(normal) < Scalar::ZERO {
+                polygon.reverse();
+                *normal = -*normal;
+            }
+
+            let is_convex = points.iter().all(|point| {
+                (*point - points[polygon[0]]).dot(normal) <= tolerance.inner()
+            });
+            if !is_convex {
+                return Err(DilateError::NotConvex);
+            }
+        }
+
+        let mut faces_by_edge = BTreeMap::new();
+        for (f, (polygon, _)) in faces.iter().enumerate() {
+            for (&a, &b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+                faces_by_edge.insert((a, b), f);
+            }
+        }
+        for (&(a, b), &f) in &faces_by_edge {
+            let g = faces_by_edge
+                .get(&(b, a))
+                .expect("Valid shell must be watertight");
+
+            // The normals point outward, so normals of neighboring faces can't
+            // point in opposite directions.
+            if context.are_parallel(&faces[f].1, &faces[*g].1) {
+                // Neighboring faces that are coplanar would result in
+                // degenerate cylinder faces.
+                return Err(DilateError::NotConvex);
+            }
+        }
+
+        Ok(Self {
+            points,
+            faces,
+            faces_by_edge,
+        })
+    }
+
+    /// Compute the arc from the normal of face `f` to the normal of face `g`
+    fn arc(
+        &self,
+        f: usize,
+        g: usize,
+        num_segments: impl Fn(Scalar) -> usize,
+    ) -> Vec<(DirectionKey, Vector<3>)> {
+        let [n_f, n_g] = [f, g].map(|face| self.faces[face].1);
+        let m = num_segments(angle_between(n_f, n_g));
+
+        (0..=m)
+            .map(|k| {
+                let key = if k == 0 {
+                    DirectionKey::Face(f)
+                } else if k == m {
+                    DirectionKey::Face(g)
+                } else if f < g {
+                    DirectionKey::Arc(f, g, k)
+                } else {
+                    DirectionKey::Arc(g, f, m - k)
+                };
+
+                let t = Scalar::from_u64(k as u64) / Scalar::from_u64(m as u64);
+                (key, slerp(n_f, n_g, t))
+            })
+            .collect()
+    }
+
+    /// Compute the closed loop of arcs between the faces around a vertex
+    fn arcs_around_vertex(
+        &self,
+        vertex: usize,
+        num_segments: impl Fn(Scalar) -> usize + Copy,
+    ) -> Vec<(DirectionKey, Vector<3>)> {
+        let first = self
+            .faces
+            .iter()
+            .position(|(polygon, _)| polygon.contains(&vertex))
+            .expect("Every point is the start of an edge of a face");
+
+        let mut boundary = Vec::new();
+        let mut f = first;
+        loop {
+            let polygon = &self.faces[f].0;
+            let i = polygon
+                .iter()
+                .position(|&v| v == vertex)
+                .expect("Face must contain vertex");
+            let next = polygon[(i + 1) % polygon.len()];
+            let g = self.faces_by_edge[&(next, vertex)];
+
+            let mut arc = self.arc(f, g, num_segments);
+            arc.pop();
+            boundary.extend(arc);
+
+            f = g;
+            if f == first {
+                break;
+            }
+        }
+
+        boundary
+    }
+}
+
+/// The points of the dilated solid
+struct Points<'r> {
+    polyhedron: &'r ConvexPolyhedron,
+    radius: Scalar,
+    points: Vec<Point<3>>,
+    indices_by_key: BTreeMap<(usize, DirectionKey), usize>,
+}
+
+impl Points<'_> {
+    /// Add a point that is shared by multiple faces, or get its index
+    fn shared(
+        &mut self,
+        vertex: usize,
+        key: DirectionKey,
+        direction: Vector<3>,
+    ) -> usize {
+        if let Some(&index) = self.indices_by_key.get(&(vertex, key)) {
+            return index;
+        }
+
+        let index = self.unique(vertex, direction);
+        self.indices_by_key.insert((vertex, key), index);
+        index
+    }
+
+    /// Add a point that is only used within the patch of a single vertex
+    fn unique(&mut self, vertex: usize, direction: Vector<3>) -> usize {
+        self.points
+            .push(self.polyhedron.points[vertex] + direction * self.radius);
+        self.points.len() - 1
+    }
+}
+
+/// Identifies a direction of the offset, relative to the original faces
+///
+/// Points offset from the same vertex in the same direction are shared between
+/// the faces of the dilated solid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum DirectionKey {
+    /// The normal of a face
+    Face(usize),
+
+    /// A direction between the normals of two faces, with the lower face index
+    /// first
+    Arc(usize, usize, usize),
+}
+
+fn angle_between(a: Vector<3>, b: Vector<3>) -> Scalar {
+    a.dot(&b).max(-Scalar::ONE).min(Scalar::ONE).acos()
+}
+
+/// Interpolate between two normalized vectors along a great circle
+fn slerp(a: Vector<3>, b: Vector<3>, t: Scalar) -> Vector<3> {
+    let angle = angle_between(a, b);
+    if angle == Scalar::ZERO {
+        return a;
+    }
+
+    let (sin, _) = angle.sin_cos();
+    let (sin_a, _) = ((Scalar::ONE - t) * angle).sin_cos();
+    let (sin_b, _) = (t * angle).sin_cos();
+
+    (a * sin_a + b * sin_b) / sin
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Aabb, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, fixtures, sweep::Sweep, triangulate::Triangulate,
+        },
+        geometry::GeometryContext,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::{Dilate, DilateError};
+
+    #[test]
+    fn dilate_cube() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+        let dilated = cube.dilate(0.1, 0.01, &context, &mut services)?;
+
+        let mesh =
+            (&dilated, Tolerance::from_scalar(0.01).unwrap()).triangulate();
+
+        let aabb = Aabb::<3>::from_points(mesh.vertices());
+        for size in aabb.size().components {
+            assert!((size - 1.2).abs() < Scalar::from(1e-12));
+        }
+
+        let volume = mesh.signed_volume();
+        let r: f64 = 0.1;
+        let expected = 1. + 6. * r + 3. * PI * r * r + 4. / 3. * PI * r.powi(3);
+
+        // The points of the approximation are on the exact surface, so it is
+        // always slightly smaller. The difference is bounded by the tolerance,
+        // times the area of the rounded parts.
+        let difference = Scalar::from(expected) - volume;
+        assert!(difference > Scalar::ZERO);
+        assert!(difference < Scalar::from(0.01 * 2.1));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_non_convex() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [2., 0.], [2., 2.], [1., 1.], [0., 2.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        assert!(matches!(
+            solid.dilate(0.1, 0.01, &context, &mut services),
+            Err(DilateError::NotConvex)
+        ));
+    }
+
+    #[test]
+    fn reject_invalid_radius() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        for radius in [0., -0.1] {
+            assert_eq!(
+                cube.dilate(radius, 0.01, &context, &mut services),
+                Err(DilateError::InvalidRadius {
+                    radius: Scalar::from(radius)
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod sketch;
+pub mod solid;
@@ -0,0 +1,376 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::SurfacePath,
+    objects::{Cycle, Edge, Region, Sketch},
+    operations::{BuildEdge, Insert},
+    services::Services,
+    storage::Handle,
+};
+
+/// Merge adjacent regions of a [`Sketch`]
+pub trait MergeRegions: Sized {
+    /// Merge all regions that share edges
+    ///
+    /// Two exterior edges of different regions are considered to be shared, if
+    /// they are coincident within `tolerance`, but point in opposite
+    /// directions. Regions that share edges are merged into a single region.
+    /// The shared edges are removed, and the remaining exterior edges form the
+    /// boundary of the merged region. Consecutive line segments that end up
+    /// being collinear are replaced by a single line segment.
+    ///
+    /// The interiors of merged regions are preserved. If merging regions
+    /// encloses an area that isn't covered by any of them, this area becomes an
+    /// additional interior of the merged region. The merged region has the
+    /// color of the first of the regions it was merged from.
+    ///
+    /// Regions that don't share any edges with other regions are left as they
+    /// are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if an edge is shared by more than two regions, or if
+    /// the edges of the merged regions don't form closed cycles.
+    fn merge_regions(
+        &self,
+        tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, MergeRegionsError>;
+}
+
+impl MergeRegions for Sketch {
+    fn merge_regions(
+        &self,
+        tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, MergeRegionsError> {
+        let tolerance = tolerance.into();
+        let regions = self.regions().iter().collect::<Vec<_>>();
+
+        // All exterior edges, along with the index of the region they belong
+        // to.
+        let edges = regions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, region)| {
+                region
+                    .exterior()
+                    .edges()
+                    .iter()
+                    .map(move |edge| (i, edge.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        // Find the edges that are shared between regions. Every region starts
+        // out in its own group, and groups are combined whenever we find a
+        // shared edge between them.
+        let mut shared = vec![false; edges.len()];
+        let mut groups = (0..regions.len()).collect::<Vec<_>>();
+
+        for (a, (region_a, edge_a)) in edges.iter().enumerate() {
+            for (b, (region_b, edge_b)) in edges.iter().enumerate().skip(a + 1)
+            {
+                if region_a == region_b
+                    || !are_coincident_and_opposite(edge_a, edge_b, tolerance)
+                {
+                    continue;
+                }
+
+                if shared[a] || shared[b] {
+                    return Err(MergeRegionsError::NonManifoldEdge {
+                        edge: edge_a.clone(),
+                    });
+                }
+
+                shared[a] = true;
+                shared[b] = true;
+
+                let (old, new) = (groups[*region_b], groups[*region_a]);
+                for group in &mut groups {
+                    if *group == old {
+                        *group = new;
+                    }
+                }
+            }
+        }
+
+        let mut merged = Vec::new();
+
+        for (i, region) in regions.iter().enumerate() {
+            let group = groups[i];
+
+            // We handle each group when we encounter its first region. This
+            // preserves the order of the regions in the sketch.
+            if groups.iter().position(|&g| g == group) != Some(i) {
+                continue;
+            }
+
+            let members = (0..regions.len())
+                .filter(|&j| groups[j] == group)
+                .collect::<Vec<_>>();
+
+            if members.len() == 1 {
+                merged.push((*region).clone());
+                continue;
+            }
+
+            let boundary = edges
+                .iter()
+                .zip(&shared)
+                .filter(|((region, _), shared)| {
+                    members.contains(region) && !**shared
+                })
+                .map(|((_, edge), _)| edge.clone());
+
+            let mut cycles = Vec::new();
+            for cycle in connect_edges(boundary, tolerance)? {
+                let edges = merge_collinear_lines(cycle, tolerance, services);
+                cycles.push(Cycle::new(edges).insert(services));
+            }
+
+            // The exterior of the merged region has the same winding as the
+            // exteriors it was merged from. Any other cycles enclose areas that
+            // none of the merged regions cover.
+            let winding = region.exterior().winding();
+            let exterior = cycles
+                .iter()
+                .position(|cycle| cycle.winding() == winding)
+                .ok_or(MergeRegionsError::DisconnectedBoundary)?;
+            let exterior = cycles.remove(exterior);
+
+            let interiors = cycles.into_iter().chain(
+                members
+                    .iter()
+                    .flat_map(|&j| regions[j].interiors().iter().cloned()),
+            );
+
+            merged.push(
+                Region::new(exterior, interiors, region.color())
+                    .insert(services),
+            );
+        }
+
+        Ok(Sketch::new(merged))
+    }
+}
+
+/// Error merging the regions of a [`Sketch`]
+///
+/// Returned by [`MergeRegions::merge_regions`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum MergeRegionsError {
+    /// An edge is shared by more than two regions
+    #[error("Edge is shared by more than two regions: {edge:#?}")]
+    NonManifoldEdge {
+        /// One of the edges that is shared by more than two regions
+        edge: Handle<Edge>,
+    },
+
+    /// The edges of the merged regions don't form closed cycles
+    #[error("Boundary of merged regions does not form closed cycles")]
+    DisconnectedBoundary,
+}
+
+fn end_position(edge: &Edge) -> Point<2> {
+    let [_, end] = edge.boundary().inner;
+    edge.path().point_from_path_coords(end)
+}
+
+fn mid_position(edge: &Edge) -> Point<2> {
+    let [start, end] = edge.boundary().inner;
+    edge.path()
+        .point_from_path_coords(start + (end - start) * 0.5)
+}
+
+fn are_coincident_and_opposite(a: &Edge, b: &Edge, tolerance: Scalar) -> bool {
+    a.start_position().distance_to(&end_position(b)) <= tolerance
+        && end_position(a).distance_to(&b.start_position()) <= tolerance
+        && mid_position(a).distance_to(&mid_position(b)) <= tolerance
+}
+
+/// Sort the provided edges into closed cycles
+fn connect_edges(
+    edges: impl IntoIterator<Item = Handle<Edge>>,
+    tolerance: Scalar,
+) -> Result<Vec<Vec<Handle<Edge>>>, MergeRegionsError> {
+    let mut remaining = edges.into_iter().collect::<Vec<_>>();
+    let mut cycles = Vec::new();
+
+    while !remaining.is_empty() {
+        let first = remaining.remove(0);
+        let start = first.start_position();
+
+        let mut cycle = vec![first];
+
+        loop {
+            let end = end_position(&cycle[cycle.len() - 1]);
+            if end.distance_to(&start) <= tolerance {
+                break;
+            }
+
+            let next = remaining
+                .iter()
+                .position(|edge| {
+                    edge.start_position().distance_to(&end) <= tolerance
+                })
+                .ok_or(MergeRegionsError::DisconnectedBoundary)?;
+
+            cycle.push(remaining.remove(next));
+        }
+
+        cycles.push(cycle);
+    }
+
+    Ok(cycles)
+}
+
+/// Replace consecutive, collinear line segments with a single line segment
+fn merge_collinear_lines(
+    edges: Vec<Handle<Edge>>,
+    tolerance: Scalar,
+    services: &mut Services,
+) -> Vec<Handle<Edge>> {
+    let mut merged: Vec<Handle<Edge>> = Vec::new();
+
+    for edge in edges {
+        match merged.last() {
+            Some(prev) if are_collinear_lines(prev, &edge, tolerance) => {
+                let line = Edge::line_segment(
+                    [prev.start_position(), end_position(&edge)],
+                    None,
+                    services,
+                )
+                .insert(services);
+
+                merged.pop();
+                merged.push(line);
+            }
+            _ => merged.push(edge),
+        }
+    }
+
+    // The cycle is closed, so the last edge might be collinear with the first
+    // one too.
+    if merged.len() > 2 {
+        let last = &merged[merged.len() - 1];
+        let first = &merged[0];
+
+        if are_collinear_lines(last, first, tolerance) {
+            let line = Edge::line_segment(
+                [last.start_position(), end_position(first)],
+                None,
+                services,
+            )
+            .insert(services);
+
+            merged.pop();
+            merged[0] = line;
+        }
+    }
+
+    merged
+}
+
+fn are_collinear_lines(a: &Edge, b: &Edge, tolerance: Scalar) -> bool {
+    if !matches!(
+        (a.path(), b.path()),
+        (SurfacePath::Line(_), SurfacePath::Line(_))
+    ) {
+        return false;
+    }
+
+    let start = a.start_position();
+    let middle = b.start_position();
+    let end = end_position(b);
+
+    let direction = end - start;
+    if direction.magnitude() == Scalar::ZERO {
+        return false;
+    }
+
+    let distance_from_line =
+        direction.cross2d(&(middle - start)).abs() / direction.magnitude();
+    let same_direction = (middle - start).dot(&(end - middle)) > Scalar::ZERO;
+
+    distance_from_line <= tolerance && same_direction
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::{MergeRegions, MergeRegionsError};
+
+    #[test]
+    fn merge_two_squares() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .add_region(
+                Region::polygon(
+                    [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            );
+
+        let merged = sketch.merge_regions(1e-9, &mut services)?;
+
+        assert_eq!(merged.regions().len(), 1);
+        let region = merged.regions().nth(0).unwrap();
+        assert_eq!(region.exterior().edges().len(), 4);
+        assert_eq!(region.interiors().len(), 0);
+
+        let solid = (
+            merged.insert(&mut services),
+            services.objects.surfaces.xy_plane(),
+        )
+            .sweep([0., 0., 1.], &mut services);
+
+        assert_eq!(solid.shells().len(), 1);
+        assert_eq!(solid.shells().nth(0).unwrap().faces().len(), 6);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn merge_non_manifold() {
+        let mut services = Services::new();
+
+        let mut sketch = Sketch::empty().add_region(
+            Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                &mut services,
+            )
+            .insert(&mut services),
+        );
+        for _ in 0..2 {
+            sketch = sketch.add_region(
+                Region::polygon(
+                    [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            );
+        }
+
+        let result = sketch.merge_regions(1e-9, &mut services);
+        assert!(matches!(
+            result,
+            Err(MergeRegionsError::NonManifoldEdge { .. })
+        ));
+    }
+}
@@ -1,6 +1,4 @@
-use crate::objects::Solid;
-
-use super::UpdateSolid;
+use crate::{objects::Solid, operations::UpdateSolid};
 
 /// Merge two [`Solid`]s
 pub trait Merge {
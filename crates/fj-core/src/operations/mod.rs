@@ -1,10 +1,15 @@
 //! Operations to update shapes
 
 mod build;
+mod cache;
+mod dilate;
 mod insert;
 mod join;
 mod merge;
+mod orient;
+mod rehost;
 mod reverse;
+mod split;
 mod update;
 
 pub use self::{
@@ -15,16 +20,30 @@ pub use self::{
         region::BuildRegion,
         shell::{BuildShell, TetrahedronShell},
         sketch::BuildSketch,
+        sketch_dsl::{Side, SketchDsl, SketchDslError},
         solid::{BuildSolid, Tetrahedron},
         surface::BuildSurface,
     },
+    cache::{ParamCache, ParamKey},
+    dilate::{Dilate, DilateError},
     insert::{Insert, IsInserted, IsInsertedNo, IsInsertedYes},
     join::cycle::JoinCycle,
-    merge::Merge,
+    merge::{
+        sketch::{MergeRegions, MergeRegionsError},
+        solid::Merge,
+    },
+    orient::shell::{OrientShell, OrientShellError},
+    rehost::{RehostEdge, RehostError, RehostFace},
     reverse::Reverse,
+    split::{
+        cycle::SplitEdgeInCycle,
+        edge::{SplitEdge, SplitEdgeError},
+    },
     update::{
         cycle::UpdateCycle, edge::UpdateEdge, face::UpdateFace,
         region::UpdateRegion, shell::UpdateShell, sketch::UpdateSketch,
         solid::UpdateSolid,
     },
 };
+
+pub(crate) use self::build::shell::newell_normal;
@@ -0,0 +1,308 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use fj_math::Scalar;
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        triangulate::Triangulate,
+    },
+    objects::{Edge, Face, Shell},
+    operations::{Insert, Reverse},
+    queries::BoundingVerticesOfEdge,
+    services::Services,
+    storage::{Handle, HandleWrapper},
+};
+
+/// Orient the faces of a [`Shell`] consistently
+pub trait OrientShell: Sized {
+    /// Orient all faces of the shell, so their normals point outward
+    ///
+    /// Starting from the first face of each connected group of faces, the
+    /// orientation is propagated to neighboring faces. Two faces that share an
+    /// edge are consistently oriented, if they traverse that edge in opposite
+    /// directions. Faces that are not consistent with their neighbors are
+    /// reversed.
+    ///
+    /// Afterwards, the signed volume of the shell is computed from its
+    /// triangulation, using the provided tolerance. If it is negative, all
+    /// faces are pointing inward, and are reversed too.
+    ///
+    /// Faces that don't need to be reversed are left as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if an edge isn't shared by exactly two faces, or if
+    /// the shell can't be oriented consistently.
+    fn orient(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Self, OrientShellError>;
+}
+
+impl OrientShell for Shell {
+    fn orient(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Self, OrientShellError> {
+        let faces = self.faces().iter().collect::<Vec<_>>();
+
+        // Group the edges by the edge they represent, remembering which face
+        // they come from, and which direction they go in.
+        let mut edges_by_coincidence = BTreeMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for cycle in face.region().all_cycles() {
+                for edge in cycle.edges() {
+                    let curve = HandleWrapper::from(edge.curve().clone());
+                    let boundary = cycle
+                        .bounding_vertices_of_edge(edge)
+                        .expect(
+                            "Just got edge from this cycle; must be part of it",
+                        )
+                        .normalize();
+
+                    // Coincident edges share a curve, and the same coordinate
+                    // system on it. So we can compare their directions based
+                    // on their boundaries.
+                    let direction = edge.boundary().is_normalized();
+
+                    edges_by_coincidence
+                        .entry((curve, boundary))
+                        .or_insert(Vec::new())
+                        .push((i, direction, edge.clone()));
+                }
+            }
+        }
+
+        // Each edge relates two faces. If both faces go along the edge in the
+        // same direction, one of them must be reversed, but not the other.
+        let mut neighbors = vec![Vec::new(); faces.len()];
+        for edges in edges_by_coincidence.into_values() {
+            let [(a, direction_a, edge), (b, direction_b, _)] =
+                <[_; 2]>::try_from(edges).map_err(|edges| {
+                    OrientShellError::NonManifold {
+                        num_faces: edges.len(),
+                        edge: edges[0].2.clone(),
+                    }
+                })?;
+
+            let must_differ = direction_a == direction_b;
+            neighbors[a].push((b, must_differ, edge.clone()));
+            neighbors[b].push((a, must_differ, edge));
+        }
+
+        let mut reverse: Vec<Option<bool>> = vec![None; faces.len()];
+        for seed in 0..faces.len() {
+            if reverse[seed].is_some() {
+                continue;
+            }
+
+            reverse[seed] = Some(false);
+            let mut queue = VecDeque::from([seed]);
+
+            while let Some(i) = queue.pop_front() {
+                let reverse_i = reverse[i].expect("Queued faces are decided");
+
+                for (j, must_differ, edge) in &neighbors[i] {
+                    let required = reverse_i ^ must_differ;
+
+                    match reverse[*j] {
+                        None => {
+                            reverse[*j] = Some(required);
+                            queue.push_back(*j);
+                        }
+                        Some(reverse_j) if reverse_j != required => {
+                            return Err(OrientShellError::NonOrientable {
+                                edge: edge.clone(),
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        let reverse = reverse
+            .into_iter()
+            .map(|reverse| reverse.expect("Every face has been visited"))
+            .collect::<Vec<_>>();
+
+        let tolerance = tolerance.into();
+        let volume = Scalar::sum_stable(faces.iter().zip(&reverse).map(
+            |(face, reverse)| {
+                let volume = signed_volume(face, tolerance);
+                if *reverse {
+                    -volume
+                } else {
+                    volume
+                }
+            },
+        ));
+        let reverse_all = volume < Scalar::ZERO;
+
+        let faces = faces.into_iter().zip(reverse).map(|(face, reverse)| {
+            if reverse ^ reverse_all {
+                face.reverse(services).insert(services)
+            } else {
+                face.clone()
+            }
+        });
+
+        Ok(Shell::new(faces))
+    }
+}
+
+/// Error orienting a [`Shell`]
+///
+/// Returned by [`OrientShell::orient`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OrientShellError {
+    /// An edge is not shared by exactly two faces
+    #[error(
+        "Edge is shared by {num_faces} faces, instead of two\n\
+        - Edge: {edge:#?}"
+    )]
+    NonManifold {
+        /// One of the edges that represent the shared edge
+        edge: Handle<Edge>,
+
+        /// The number of faces that share the edge
+        num_faces: usize,
+    },
+
+    /// The faces of the shell can't be oriented consistently
+    #[error(
+        "Shell is not orientable; faces can't agree on direction of edge\n\
+        - Edge: {edge:#?}"
+    )]
+    NonOrientable {
+        /// The edge at which the orientations of the faces contradict
+        edge: Handle<Edge>,
+    },
+}
+
+/// Compute the contribution of a face to the signed volume of its shell
+fn signed_volume(face: &Face, tolerance: Tolerance) -> Scalar {
+    face.approx(tolerance).triangulate().signed_volume()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{approx::Tolerance, fixtures, triangulate::Triangulate},
+        objects::Shell,
+        operations::{
+            reverse::ReverseCurveCoordinateSystems, BuildShell, Insert,
+            Reverse, UpdateCycle, UpdateFace, UpdateRegion, UpdateShell,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{OrientShell, OrientShellError};
+
+    #[test]
+    fn cube_with_reversed_faces() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+        let shell = cube.shells().nth(0).unwrap().clone();
+
+        let mut broken = shell.clone_object();
+        for i in [1, 4] {
+            let face = shell.faces().nth(i).unwrap();
+            broken = broken.replace_face(
+                face,
+                face.reverse(&mut services).insert(&mut services),
+            );
+        }
+        assert!(broken.validate_and_return_first_error().is_err());
+
+        let fixed = broken.orient(0.1, &mut services)?;
+        fixed.validate_and_return_first_error()?;
+        assert!(volume(&fixed) > Scalar::ZERO);
+
+        // Unchanged faces are kept as they are.
+        for i in [0, 2, 3, 5] {
+            assert_eq!(
+                fixed.faces().nth(i).unwrap().id(),
+                shell.faces().nth(i).unwrap().id()
+            );
+        }
+
+        // A shell that is consistently oriented, but inside out, is flipped.
+        let mut inside_out = shell.clone_object();
+        for face in shell.faces() {
+            inside_out = inside_out.replace_face(
+                face,
+                face.reverse(&mut services).insert(&mut services),
+            );
+        }
+        assert!(volume(&inside_out) < Scalar::ZERO);
+
+        let fixed = inside_out.orient(0.1, &mut services)?;
+        fixed.validate_and_return_first_error()?;
+        assert!(volume(&fixed) > Scalar::ZERO);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn non_orientable() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+
+        // Reversing the direction of a single edge on its curve, without
+        // reversing the face it belongs to, results in an edge that both of
+        // its faces traverse in the same direction, while all other pairs of
+        // faces agree. Like a Möbius strip, there is no way to fix this by
+        // reversing faces.
+        let face = &tetrahedron.abc.face;
+        let twisted = face
+            .update_region(|region| {
+                region
+                    .update_exterior(|cycle| {
+                        cycle
+                            .update_nth_edge(0, |edge| {
+                                edge.reverse_curve_coordinate_systems(
+                                    &mut services,
+                                )
+                                .insert(&mut services)
+                            })
+                            .insert(&mut services)
+                    })
+                    .insert(&mut services)
+            })
+            .insert(&mut services);
+        let shell = tetrahedron.shell.replace_face(face, twisted);
+
+        assert!(matches!(
+            shell.orient(0.1, &mut services),
+            Err(OrientShellError::NonOrientable { .. })
+        ));
+
+        let open = tetrahedron.shell.remove_face(face);
+        assert!(matches!(
+            open.orient(0.1, &mut services),
+            Err(OrientShellError::NonManifold { num_faces: 1, .. })
+        ));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn volume(shell: &Shell) -> Scalar {
+        (shell, Tolerance::from_scalar(0.1).unwrap())
+            .triangulate()
+            .signed_volume()
+    }
+}
@@ -0,0 +1,345 @@
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Cycle, Edge, Face, Region, Surface},
+    operations::{Insert, Reverse},
+    services::Services,
+    storage::Handle,
+};
+
+/// Move an [`Edge`] to another surface
+pub trait RehostEdge: Sized {
+    /// Move the edge from one surface to another
+    ///
+    /// The edge's path is mapped into global space using `from`, then
+    /// projected into `to`. The boundary, curve, and start vertex of the edge
+    /// are kept, so its geometry in global space doesn't change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the edge doesn't lie on `to` within `tolerance`, or
+    /// if either surface is not a plane.
+    fn rehost(
+        &self,
+        from: &Handle<Surface>,
+        to: &Handle<Surface>,
+        tolerance: impl Into<Scalar>,
+    ) -> Result<Self, RehostError>;
+}
+
+impl RehostEdge for Edge {
+    fn rehost(
+        &self,
+        from: &Handle<Surface>,
+        to: &Handle<Surface>,
+        tolerance: impl Into<Scalar>,
+    ) -> Result<Self, RehostError> {
+        let tolerance = tolerance.into();
+
+        for surface in [from, to] {
+            if let GlobalPath::Circle(_) | GlobalPath::Ellipse(_) =
+                surface.geometry().u
+            {
+                return Err(RehostError::CurvedSurface {
+                    surface: surface.clone(),
+                });
+            }
+        }
+
+        // Both surfaces are planes, which means converting between them is an
+        // affine map. It is enough to map a few points of the path, and
+        // rebuild the path from those.
+        let project = |point: Point<2>| {
+            let global = from.geometry().point_from_surface_coords(point);
+            let projected = to.geometry().project_global_point(global);
+
+            let distance = to
+                .geometry()
+                .point_from_surface_coords(projected)
+                .distance_to(&global);
+            if distance > tolerance {
+                return Err(RehostError::PointNotOnSurface {
+                    point: global,
+                    distance,
+                });
+            }
+
+            Ok(projected)
+        };
+
+        let path = match self.path() {
+            SurfacePath::Line(line) => {
+                let boundary = self.boundary().inner;
+
+                let mut points = Vec::new();
+                for t in boundary {
+                    points.push((t, project(line.point_from_line_coords(t))?));
+                }
+
+                SurfacePath::Line(Line::from_points_with_line_coords([
+                    points[0], points[1],
+                ]))
+            }
+            SurfacePath::Circle(circle) => {
+                let (center, [a, b]) = rehost_conic(
+                    circle.center(),
+                    [circle.a(), circle.b()],
+                    project,
+                )?;
+
+                // Depending on the coordinate systems of the surfaces, the
+                // circle might no longer be a circle in surface coordinates.
+                // Both checks are distances, so they can use the tolerance:
+                // The difference between the radii, and how far `b` extends
+                // in the direction of `a`.
+                let is_circle = (a.magnitude() - b.magnitude()).abs()
+                    <= tolerance
+                    && a.dot(&b).abs() <= tolerance * a.magnitude();
+                if is_circle {
+                    SurfacePath::Circle(Circle::new(center, a, b))
+                } else {
+                    SurfacePath::Ellipse(Ellipse::new(center, a, b))
+                }
+            }
+            SurfacePath::Ellipse(ellipse) => {
+                let (center, [a, b]) = rehost_conic(
+                    ellipse.center(),
+                    [ellipse.a(), ellipse.b()],
+                    project,
+                )?;
+
+                SurfacePath::Ellipse(Ellipse::new(center, a, b))
+            }
+        };
+
+        Ok(Edge::new(
+            path,
+            self.boundary(),
+            self.curve().clone(),
+            self.start_vertex().clone(),
+        ))
+    }
+}
+
+/// Move a [`Face`] to another surface
+pub trait RehostFace: Sized {
+    /// Move the face to another surface
+    ///
+    /// All edges of the face are moved to `surface`, as described in
+    /// [`RehostEdge::rehost`].
+    ///
+    /// If the normal of `surface` points the other way than the normal of the
+    /// face's current surface, the cycles of the face would wind the wrong way
+    /// in the new surface coordinates. They are reversed in that case, which
+    /// means the resulting face points in the direction of `surface`'s normal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if any edge can't be moved.
+    fn rehost(
+        &self,
+        surface: Handle<Surface>,
+        tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, RehostError>;
+}
+
+impl RehostFace for Face {
+    fn rehost(
+        &self,
+        surface: Handle<Surface>,
+        tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, RehostError> {
+        let tolerance = tolerance.into();
+
+        let is_flipped =
+            normal(self.surface()).dot(&normal(&surface)) < Scalar::ZERO;
+
+        let mut rehost_cycle =
+            |cycle: &Handle<Cycle>| -> Result<_, RehostError> {
+                let mut edges = Vec::new();
+                for edge in cycle.edges() {
+                    let edge =
+                        edge.rehost(self.surface(), &surface, tolerance)?;
+                    edges.push(edge.insert(services));
+                }
+
+                let mut cycle = Cycle::new(edges);
+                if is_flipped {
+                    cycle = cycle.reverse(services);
+                }
+
+                Ok(cycle.insert(services))
+            };
+
+        let exterior = rehost_cycle(self.region().exterior())?;
+        let mut interiors = Vec::new();
+        for interior in self.region().interiors() {
+            interiors.push(rehost_cycle(interior)?);
+        }
+
+        let region = Region::new(exterior, interiors, self.region().color())
+            .insert(services);
+
+        Ok(Face::new(surface, region))
+    }
+}
+
+/// Error moving an object to another surface
+///
+/// Returned by [`RehostEdge::rehost`] and [`RehostFace::rehost`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RehostError {
+    /// A point of the object doesn't lie on the new surface
+    #[error(
+        "Point is not on the surface that the object is moved to\n\
+        - Point: {point:?}\n\
+        - Distance to surface: {distance}"
+    )]
+    PointNotOnSurface {
+        /// The point, in global coordinates
+        point: Point<3>,
+
+        /// The distance of the point from the surface
+        distance: Scalar,
+    },
+
+    /// Moving objects from or to curved surfaces is not supported
+    #[error("Moving objects from or to curved surface is not supported")]
+    CurvedSurface {
+        /// The curved surface
+        surface: Handle<Surface>,
+    },
+}
+
+fn normal(surface: &Surface) -> Vector<3> {
+    let geometry = surface.geometry();
+    let [u, v] = [[1., 0.], [0., 1.]]
+        .map(|vector| geometry.vector_from_surface_coords(vector));
+
+    u.cross(&v)
+}
+
+fn rehost_conic(
+    center: Point<2>,
+    [a, b]: [Vector<2>; 2],
+    project: impl Fn(Point<2>) -> Result<Point<2>, RehostError>,
+) -> Result<(Point<2>, [Vector<2>; 2]), RehostError> {
+    let center_projected = project(center)?;
+    let a = project(center + a)? - center_projected;
+    let b = project(center + b)? - center_projected;
+
+    Ok((center_projected, [a, b]))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Point, Transform, Vector};
+
+    use crate::{
+        geometry::{GlobalPath, SurfaceGeometry},
+        objects::{Edge, Face, Handedness, Region, Surface},
+        operations::{BuildEdge, BuildRegion, Insert},
+        services::Services,
+    };
+
+    use super::{RehostEdge, RehostError, RehostFace};
+
+    #[test]
+    fn rehost_onto_translated_plane() {
+        let mut services = Services::new();
+
+        let xy_plane = services.objects.surfaces.xy_plane();
+        let translated = Surface::new(
+            xy_plane
+                .geometry()
+                .transform(&Transform::translation([0., 0., 1.])),
+        )
+        .insert(&mut services);
+
+        let edge =
+            Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services);
+
+        assert!(matches!(
+            edge.rehost(&xy_plane, &translated, 1e-9),
+            Err(RehostError::PointNotOnSurface { .. })
+        ));
+    }
+
+    #[test]
+    fn rehost_onto_identical_plane() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let xy_plane = services.objects.surfaces.xy_plane();
+        let copy = Surface::new(xy_plane.geometry()).insert(&mut services);
+        assert_ne!(xy_plane.id(), copy.id());
+
+        let line =
+            Edge::line_segment([[0., 0.], [1., 2.]], None, &mut services);
+        let circle = Edge::circle([1., 1.], 2., &mut services);
+
+        for edge in [line, circle] {
+            let rehosted = edge.rehost(&xy_plane, &copy, 1e-9)?;
+
+            assert_eq!(rehosted.boundary(), edge.boundary());
+            assert_eq!(rehosted.curve().id(), edge.curve().id());
+            for t in [0., 0.25, 0.5, 1.] {
+                let t = Point::from([t]);
+                assert_abs_diff!(
+                    rehosted.point_at(t, &copy),
+                    edge.point_at(t, &xy_plane)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn rehost_onto_flipped_plane() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let xy_plane = services.objects.surfaces.xy_plane();
+        let flipped = Surface::new(SurfaceGeometry {
+            u: GlobalPath::x_axis(),
+            v: -Vector::unit_y(),
+        })
+        .insert(&mut services);
+
+        let region = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(xy_plane.clone(), region);
+        assert_eq!(face.coord_handedness(), Handedness::RightHanded);
+
+        let rehosted = face.rehost(flipped.clone(), 1e-9, &mut services)?;
+        assert_eq!(rehosted.coord_handedness(), Handedness::RightHanded);
+
+        let corners = |face: &Face| {
+            let mut corners = face
+                .region()
+                .exterior()
+                .edges()
+                .iter()
+                .map(|edge| {
+                    face.surface()
+                        .geometry()
+                        .point_from_surface_coords(edge.start_position())
+                })
+                .collect::<Vec<_>>();
+            corners.sort();
+            corners
+        };
+        assert_eq!(corners(&rehosted), corners(&face));
+
+        let _ = face.insert(&mut services);
+        let _ = rehosted.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+}
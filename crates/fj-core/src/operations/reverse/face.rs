@@ -74,3 +74,77 @@ impl<const D: usize> ReverseCurveCoordinateSystems
         self.replace_face(face)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::approx::Approx,
+        objects::{Cycle, Edge, Face},
+        operations::{
+            BuildCycle, BuildEdge, BuildFace, Insert, Reverse, UpdateCycle,
+            UpdateFace, UpdateRegion,
+        },
+        services::Services,
+    };
+
+    #[test]
+    fn reverse_face_with_arc() {
+        let mut services = Services::new();
+
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            let edges = [
+                                Edge::arc(
+                                    [1., 0.],
+                                    [0., 1.],
+                                    FRAC_PI_2,
+                                    &mut services,
+                                ),
+                                Edge::line_segment(
+                                    [[0., 1.], [0., 0.]],
+                                    None,
+                                    &mut services,
+                                ),
+                                Edge::line_segment(
+                                    [[0., 0.], [1., 0.]],
+                                    None,
+                                    &mut services,
+                                ),
+                            ]
+                            .map(|edge| edge.insert(&mut services));
+
+                            Cycle::empty()
+                                .add_edges(edges)
+                                .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                });
+        let reversed = face.reverse(&mut services);
+
+        let tolerance = 0.01;
+        let points = (&face).approx(tolerance).exterior.points();
+        let points_reversed = (&reversed).approx(tolerance).exterior.points();
+
+        // Both approximations start and end at the same point, but traverse
+        // the boundary in opposite directions.
+        let mut expected = points.clone();
+        expected.reverse();
+
+        assert!(points.len() > 4);
+        assert_eq!(points_reversed.len(), expected.len());
+        for (a, b) in points_reversed.into_iter().zip(expected) {
+            assert!(
+                a.global_form.distance_to(&b.global_form) < Scalar::from(1e-12)
+            );
+        }
+
+        services.only_validate([face, reversed]);
+    }
+}
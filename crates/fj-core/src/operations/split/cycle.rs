@@ -0,0 +1,104 @@
+use fj_math::Point;
+
+use crate::{
+    geometry::GeometryContext,
+    objects::{Cycle, Edge},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::edge::{SplitEdge, SplitEdgeError};
+
+/// Split an edge of a [`Cycle`]
+pub trait SplitEdgeInCycle: Sized {
+    /// Split the provided edge of the cycle at the provided curve coordinate
+    ///
+    /// The edge is replaced by the two edges that result from splitting it. See
+    /// [`SplitEdge::split_at`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the edge can't be split at the provided point.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the edge is not part of the cycle.
+    fn split_edge(
+        &self,
+        edge: &Handle<Edge>,
+        point: impl Into<Point<1>>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self, SplitEdgeError>;
+}
+
+impl SplitEdgeInCycle for Cycle {
+    fn split_edge(
+        &self,
+        edge: &Handle<Edge>,
+        point: impl Into<Point<1>>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<Self, SplitEdgeError> {
+        let [a, b] = edge
+            .split_at(point, context, services)?
+            .map(|edge| edge.insert(services));
+
+        let index = self
+            .edges()
+            .index_of(edge)
+            .expect("Expected edge to be part of the cycle");
+
+        let edges = self
+            .edges()
+            .iter()
+            .take(index)
+            .cloned()
+            .chain([a, b])
+            .chain(self.edges().iter().skip(index + 1).cloned());
+
+        Ok(Cycle::new(edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::GeometryContext, objects::Cycle, operations::BuildCycle,
+        services::Services,
+    };
+
+    use super::SplitEdgeInCycle;
+
+    #[test]
+    fn split_edge_in_cycle() {
+        let mut services = Services::new();
+
+        let cycle =
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services);
+        let edge = cycle.edges().nth(1).unwrap().clone();
+
+        let split = cycle
+            .split_edge(
+                &edge,
+                [0.5],
+                &GeometryContext::default(),
+                &mut services,
+            )
+            .unwrap();
+
+        assert_eq!(split.edges().len(), 4);
+        let [a, b] = [1, 2].map(|i| split.edges().nth(i).unwrap());
+        assert_eq!(a.start_vertex().id(), edge.start_vertex().id());
+        assert_eq!(b.start_position(), edge.point_at_surface([0.5]));
+
+        // The other edges are unchanged.
+        for (i, j) in [(0, 0), (3, 2)] {
+            assert_eq!(
+                split.edges().nth(i).unwrap().id(),
+                cycle.edges().nth(j).unwrap().id()
+            );
+        }
+    }
+}
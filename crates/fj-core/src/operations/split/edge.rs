@@ -0,0 +1,206 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::GeometryContext,
+    objects::{Edge, Vertex},
+    operations::Insert,
+    services::Services,
+};
+
+/// Split an [`Edge`] into two
+pub trait SplitEdge: Sized {
+    /// Split the edge at the provided curve coordinate
+    ///
+    /// Returns two edges that together cover the same part of the curve as the
+    /// original one. Both edges keep the path and curve of the original edge.
+    /// The first edge starts at the original start vertex, the second one at a
+    /// new vertex, which is where the first one ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if `point` is not between the boundary points of the
+    /// edge, or if it is closer to one of them than
+    /// [`GeometryContext::distinct_min_distance`], which means it can't become
+    /// a distinct vertex.
+    fn split_at(
+        &self,
+        point: impl Into<Point<1>>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<[Self; 2], SplitEdgeError>;
+}
+
+impl SplitEdge for Edge {
+    fn split_at(
+        &self,
+        point: impl Into<Point<1>>,
+        context: &GeometryContext,
+        services: &mut Services,
+    ) -> Result<[Self; 2], SplitEdgeError> {
+        let point = point.into();
+        let [start, end] = self.boundary().inner;
+
+        let is_within_boundary =
+            (start < point && point < end) || (end < point && point < start);
+        if !is_within_boundary {
+            return Err(SplitEdgeError::OutsideOfBoundary {
+                point,
+                boundary: [start, end],
+            });
+        }
+
+        let min_distance = context.distinct_min_distance;
+        let point_surface = self.point_at_surface(point);
+        for boundary_point in [start, end] {
+            let distance = self
+                .point_at_surface(boundary_point)
+                .distance_to(&point_surface);
+
+            if distance < min_distance {
+                return Err(SplitEdgeError::CoincidesWithBoundary {
+                    point,
+                    boundary_point,
+                    distance,
+                });
+            }
+        }
+
+        let vertex = Vertex::new().insert(services);
+
+        let first = Edge::new(
+            self.path(),
+            [start, point],
+            self.curve().clone(),
+            self.start_vertex().clone(),
+        );
+        let second =
+            Edge::new(self.path(), [point, end], self.curve().clone(), vertex);
+
+        Ok([first, second])
+    }
+}
+
+/// Error splitting an [`Edge`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SplitEdgeError {
+    /// The split point is not between the boundary points of the edge
+    #[error(
+        "Can't split edge at point outside of its boundary\n\
+        - Point: {point:?}\n\
+        - Boundary: {boundary:?}"
+    )]
+    OutsideOfBoundary {
+        /// The point where the edge should have been split
+        point: Point<1>,
+
+        /// The boundary of the edge
+        boundary: [Point<1>; 2],
+    },
+
+    /// The split point coincides with one of the boundary points of the edge
+    #[error(
+        "Can't split edge at point that coincides with its boundary\n\
+        - Point: {point:?}\n\
+        - Boundary point: {boundary_point:?}\n\
+        - Distance on surface: {distance}"
+    )]
+    CoincidesWithBoundary {
+        /// The point where the edge should have been split
+        point: Point<1>,
+
+        /// The boundary point that the split point coincides with
+        boundary_point: Point<1>,
+
+        /// The distance between both points, in surface coordinates
+        distance: Scalar,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Point, Scalar};
+
+    use crate::{
+        algorithms::measure::edge_length, geometry::GeometryContext,
+        objects::Edge, operations::BuildEdge, services::Services,
+    };
+
+    use super::{SplitEdge, SplitEdgeError};
+
+    #[test]
+    fn split_line_segment() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let edge =
+            Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services);
+
+        let [a, b] = edge.split_at([0.25], &context, &mut services).unwrap();
+
+        assert_abs_diff!(
+            edge_length(&a, &surface, 0.001).unwrap(),
+            Scalar::from(0.25)
+        );
+        assert_abs_diff!(
+            edge_length(&b, &surface, 0.001).unwrap(),
+            Scalar::from(0.75)
+        );
+
+        assert_eq!(a.start_vertex().id(), edge.start_vertex().id());
+        assert_ne!(b.start_vertex().id(), edge.start_vertex().id());
+        assert_eq!(a.curve().id(), edge.curve().id());
+        assert_eq!(b.curve().id(), edge.curve().id());
+        assert_eq!(b.start_position(), Point::from([0.25, 0.]));
+    }
+
+    #[test]
+    fn split_circle() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let edge = Edge::circle([1., 1.], 2., &mut services);
+
+        let [a, b] = edge.split_at([1.], &context, &mut services).unwrap();
+
+        for edge in [&a, &b] {
+            let [start, end] = edge.boundary().inner;
+            for point in [start, end] {
+                let position = edge.point_at(point, &surface);
+                assert_abs_diff!(
+                    position.distance_to(&Point::from([1., 1., 0.])),
+                    Scalar::from(2.)
+                );
+            }
+        }
+
+        assert_eq!(a.boundary().inner, [[0.], [1.]].map(Point::from));
+        assert_eq!(
+            b.boundary().inner,
+            [Point::from([1.]), Point::from([Scalar::TAU])]
+        );
+    }
+
+    #[test]
+    fn split_at_boundary() {
+        let mut services = Services::new();
+        let context = GeometryContext::default();
+
+        let edge =
+            Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services);
+
+        assert!(matches!(
+            edge.split_at([0.], &context, &mut services),
+            Err(SplitEdgeError::OutsideOfBoundary { .. })
+        ));
+        assert!(matches!(
+            edge.split_at([1e-9], &context, &mut services),
+            Err(SplitEdgeError::CoincidesWithBoundary { .. })
+        ));
+        assert!(matches!(
+            edge.split_at([2.], &context, &mut services),
+            Err(SplitEdgeError::OutsideOfBoundary { .. })
+        ));
+    }
+}
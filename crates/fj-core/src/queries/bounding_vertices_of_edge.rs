@@ -66,3 +66,47 @@ impl BoundingVerticesOfEdge for Shell {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::Cycle,
+        operations::{BuildCycle, Reverse},
+        services::Services,
+    };
+
+    use super::BoundingVerticesOfEdge;
+
+    #[test]
+    fn definition_order() {
+        let mut services = Services::new();
+
+        let cycle =
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services);
+        let reversed = cycle.reverse(&mut services);
+
+        for edge in cycle.edges() {
+            let twin = reversed
+                .edges()
+                .iter()
+                .find(|twin| twin.curve().id() == edge.curve().id())
+                .unwrap();
+
+            let vertices = cycle.bounding_vertices_of_edge(edge).unwrap();
+            let twin_vertices =
+                reversed.bounding_vertices_of_edge(twin).unwrap();
+
+            assert_eq!(
+                vertices.clone().normalize(),
+                twin_vertices.clone().normalize()
+            );
+            assert_eq!(vertices.reverse(), twin_vertices);
+
+            assert_eq!(
+                edge.boundary().normalize(),
+                twin.boundary().normalize()
+            );
+            assert_eq!(edge.boundary().reverse(), twin.boundary());
+        }
+    }
+}
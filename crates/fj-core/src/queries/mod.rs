@@ -11,8 +11,10 @@
 
 mod all_edges_with_surface;
 mod bounding_vertices_of_edge;
+mod object_graph;
 
 pub use self::{
     all_edges_with_surface::AllEdgesWithSurface,
     bounding_vertices_of_edge::BoundingVerticesOfEdge,
+    object_graph::{find_unshared_duplicates, DuplicatePair, ObjectGraph},
 };
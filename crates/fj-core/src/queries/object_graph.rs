@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::GeometryContext,
+    objects::{Bare, BehindHandle, Object},
+    storage::ObjectId,
+};
+
+/// The graph of objects that are referenced by an object
+///
+/// Each node of the graph is an object, identified by its handle. Each edge of
+/// the graph is a reference from one object to another. This is useful for
+/// analyzing the structure of a shape, for example to find objects that should
+/// be shared, but aren't. See [`ObjectGraph::find_unshared_duplicates`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ObjectGraph {
+    nodes: Vec<Object<BehindHandle>>,
+    references: Vec<[usize; 2]>,
+    roots: Vec<usize>,
+    indices_by_id: BTreeMap<ObjectId, usize>,
+}
+
+impl ObjectGraph {
+    /// Build the graph of all objects referenced by the provided object
+    ///
+    /// The provided object itself is not part of the graph, as a bare object
+    /// has no identity. The objects it references directly are available via
+    /// [`ObjectGraph::roots`].
+    pub fn from_root(root: impl Into<Object<Bare>>) -> Self {
+        let mut graph = Self::default();
+
+        for object in references_of_bare(&root.into()) {
+            let index = graph.add(object);
+            graph.roots.push(index);
+        }
+
+        graph
+    }
+
+    /// Access the nodes of the graph
+    ///
+    /// [`Object::type_name`] and [`Object::id`] can be used to tell them apart.
+    pub fn nodes(&self) -> &[Object<BehindHandle>] {
+        &self.nodes
+    }
+
+    /// Access the references between nodes
+    ///
+    /// Each reference points from the referencing node to the referenced one.
+    /// Nodes are identified by their index into [`ObjectGraph::nodes`].
+    pub fn references(&self) -> &[[usize; 2]] {
+        &self.references
+    }
+
+    /// Access the nodes that are referenced by the root object directly
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    /// Access the nodes referenced by the node with the provided index
+    pub fn referenced_by(
+        &self,
+        index: usize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        self.references
+            .iter()
+            .filter(move |[from, _]| *from == index)
+            .map(|[_, to]| *to)
+    }
+
+    /// Find objects that are coincident, but not identical
+    ///
+    /// Vertices and curves that are coincident in global space should be
+    /// represented by the same object. This returns all pairs of vertices and
+    /// curves, for which this is not the case.
+    ///
+    /// Positions are computed from the faces in the graph. Objects closer than
+    /// [`GeometryContext::distinct_min_distance`] count as coincident. Objects
+    /// that are not part of any face, like those of a sketch, are not
+    /// considered.
+    ///
+    /// This compares all pairs of objects, and is only meant for debugging.
+    pub fn find_unshared_duplicates(
+        &self,
+        context: &GeometryContext,
+    ) -> Vec<DuplicatePair> {
+        let min_distance = context.distinct_min_distance;
+
+        let mut vertices = BTreeMap::new();
+        let mut curves = Vec::new();
+
+        for node in &self.nodes {
+            let Object::Face(face) = node else {
+                continue;
+            };
+            let geometry = face.surface().geometry();
+
+            for cycle in face.region().all_cycles() {
+                for edge in cycle.edges() {
+                    let samples = {
+                        let [start, end] = edge.boundary().inner;
+                        let middle = start + (end - start) / 2.;
+
+                        [start, middle, end].map(|point| {
+                            geometry.point_from_surface_coords(
+                                edge.point_at_surface(point),
+                            )
+                        })
+                    };
+
+                    vertices
+                        .entry(edge.start_vertex().id())
+                        .or_insert((edge.start_vertex().clone(), samples[0]));
+                    curves.push((edge.curve().clone(), samples));
+                }
+            }
+        }
+
+        let mut duplicates = Vec::new();
+
+        let vertices = vertices.into_values().collect::<Vec<_>>();
+        for (i, (a, position_a)) in vertices.iter().enumerate() {
+            for (b, position_b) in &vertices[i + 1..] {
+                let distance = position_a.distance_to(position_b);
+
+                if distance < min_distance {
+                    duplicates.push(DuplicatePair {
+                        a: a.clone().into(),
+                        b: b.clone().into(),
+                        distance,
+                    });
+                }
+            }
+        }
+
+        let mut curve_pairs = BTreeMap::new();
+        for (i, (a, samples_a)) in curves.iter().enumerate() {
+            for (b, samples_b) in &curves[i + 1..] {
+                if a.id() == b.id() {
+                    continue;
+                }
+
+                let mut reversed = *samples_b;
+                reversed.reverse();
+
+                let distance = [*samples_b, reversed]
+                    .into_iter()
+                    .map(|samples_b| max_distance(samples_a, &samples_b))
+                    .min()
+                    .unwrap_or(Scalar::ZERO);
+
+                if distance < min_distance {
+                    let [a, b] = if a.id() < b.id() { [a, b] } else { [b, a] };
+                    curve_pairs.entry([a.id(), b.id()]).or_insert_with(|| {
+                        DuplicatePair {
+                            a: a.clone().into(),
+                            b: b.clone().into(),
+                            distance,
+                        }
+                    });
+                }
+            }
+        }
+        duplicates.extend(curve_pairs.into_values());
+
+        duplicates
+    }
+
+    fn add(&mut self, object: Object<BehindHandle>) -> usize {
+        if let Some(index) = self.indices_by_id.get(&object.id()) {
+            return *index;
+        }
+
+        let index = self.nodes.len();
+        self.indices_by_id.insert(object.id(), index);
+        self.nodes.push(object.clone());
+
+        for referenced in references_of(&object) {
+            let referenced = self.add(referenced);
+            self.references.push([index, referenced]);
+        }
+
+        index
+    }
+}
+
+/// Find objects referenced by `root` that are coincident, but not identical
+///
+/// Convenience wrapper around [`ObjectGraph::find_unshared_duplicates`].
+pub fn find_unshared_duplicates(
+    root: impl Into<Object<Bare>>,
+    context: &GeometryContext,
+) -> Vec<DuplicatePair> {
+    ObjectGraph::from_root(root).find_unshared_duplicates(context)
+}
+
+/// A pair of objects that are coincident, but not identical
+///
+/// Returned by [`ObjectGraph::find_unshared_duplicates`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicatePair {
+    /// The first object of the pair
+    pub a: Object<BehindHandle>,
+
+    /// The second object of the pair
+    pub b: Object<BehindHandle>,
+
+    /// The largest distance between the objects
+    pub distance: Scalar,
+}
+
+fn max_distance(a: &[Point<3>; 3], b: &[Point<3>; 3]) -> Scalar {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.distance_to(b))
+        .max()
+        .unwrap_or(Scalar::ZERO)
+}
+
+fn references_of(object: &Object<BehindHandle>) -> Vec<Object<BehindHandle>> {
+    references_of_bare(&match object {
+        Object::Curve(curve) => Object::Curve(curve.clone_object()),
+        Object::Cycle(cycle) => Object::Cycle(cycle.clone_object()),
+        Object::Face(face) => Object::Face(face.clone_object()),
+        Object::Edge(edge) => Object::Edge(edge.clone_object()),
+        Object::Region(region) => Object::Region(region.clone_object()),
+        Object::Shell(shell) => Object::Shell(shell.clone_object()),
+        Object::Sketch(sketch) => Object::Sketch(sketch.clone_object()),
+        Object::Solid(solid) => Object::Solid(solid.clone_object()),
+        Object::Surface(surface) => Object::Surface(surface.clone_object()),
+        Object::Vertex(vertex) => Object::Vertex(vertex.clone_object()),
+    })
+}
+
+fn references_of_bare(object: &Object<Bare>) -> Vec<Object<BehindHandle>> {
+    match object {
+        Object::Curve(_) | Object::Surface(_) | Object::Vertex(_) => Vec::new(),
+        Object::Cycle(cycle) => {
+            cycle.edges().iter().cloned().map(Into::into).collect()
+        }
+        Object::Face(face) => {
+            vec![face.surface().clone().into(), face.region().clone().into()]
+        }
+        Object::Edge(edge) => vec![
+            edge.curve().clone().into(),
+            edge.start_vertex().clone().into(),
+        ],
+        Object::Region(region) => {
+            region.all_cycles().cloned().map(Into::into).collect()
+        }
+        Object::Shell(shell) => {
+            shell.faces().iter().cloned().map(Into::into).collect()
+        }
+        Object::Sketch(sketch) => {
+            sketch.regions().iter().cloned().map(Into::into).collect()
+        }
+        Object::Solid(solid) => {
+            solid.shells().iter().cloned().map(Into::into).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::fixtures,
+        geometry::GeometryContext,
+        objects::{Face, Object, Shell},
+        operations::{BuildFace, Insert},
+        services::Services,
+    };
+
+    use super::{find_unshared_duplicates, ObjectGraph};
+
+    #[test]
+    fn unshared_square() {
+        let mut services = Services::new();
+
+        // A square made from two triangles, that don't share the vertices and
+        // the curve along their common edge.
+        let a = Face::triangle(
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]],
+            &mut services,
+        );
+        let b = Face::triangle(
+            [[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            &mut services,
+        );
+        let square =
+            Shell::new([a.face, b.face].map(|face| face.insert(&mut services)));
+
+        let duplicates =
+            find_unshared_duplicates(square, &GeometryContext::default());
+
+        let count = |type_name| {
+            duplicates
+                .iter()
+                .filter(|pair| pair.a.type_name() == type_name)
+                .count()
+        };
+        assert_eq!(count("vertex"), 2);
+        assert_eq!(count("curve"), 1);
+        assert_eq!(duplicates.len(), 3);
+
+        services.drop_and_validate().unwrap();
+    }
+
+    #[test]
+    fn cube() {
+        let mut services = Services::new();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let graph = ObjectGraph::from_root(cube.clone_object());
+        let count = |type_name| {
+            graph
+                .nodes()
+                .iter()
+                .filter(|node| node.type_name() == type_name)
+                .count()
+        };
+        assert_eq!(graph.roots().len(), 1);
+        assert_eq!(count("face"), 6);
+        assert_eq!(count("vertex"), 8);
+        assert_eq!(count("curve"), 12);
+
+        let [shell] = graph.roots() else {
+            unreachable!()
+        };
+        assert!(matches!(graph.nodes()[*shell], Object::Shell(_)));
+        assert_eq!(graph.referenced_by(*shell).count(), 6);
+
+        assert!(graph
+            .find_unshared_duplicates(&GeometryContext::default())
+            .is_empty());
+
+        services.drop_and_validate().unwrap();
+    }
+}
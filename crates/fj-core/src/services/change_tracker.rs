@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    objects::{
+        Curve, Cycle, Edge, Face, ObjectSet, Objects, Region, Shell, Sketch,
+        Solid, Surface, Vertex,
+    },
+    storage::{Handle, ObjectId},
+};
+
+use super::Service;
+
+/// Tracks which objects have been inserted into `Service<Objects>`
+///
+/// Records the objects that exist when it is created. Later, it can determine
+/// which objects have been inserted since then. This can be used to decide,
+/// whether work that depends on some objects (like approximating them) needs to
+/// be redone.
+pub struct ChangeTracker {
+    known: BTreeSet<ObjectId>,
+}
+
+impl ChangeTracker {
+    /// Construct an instance of `ChangeTracker`
+    ///
+    /// All objects that are currently in the stores are considered unchanged.
+    pub fn new(objects: &Service<Objects>) -> Self {
+        let mut known = BTreeSet::new();
+
+        known.extend(objects.curves.iter().map(|handle| handle.id()));
+        known.extend(objects.cycles.iter().map(|handle| handle.id()));
+        known.extend(objects.edges.iter().map(|handle| handle.id()));
+        known.extend(objects.faces.iter().map(|handle| handle.id()));
+        known.extend(objects.regions.iter().map(|handle| handle.id()));
+        known.extend(objects.shells.iter().map(|handle| handle.id()));
+        known.extend(objects.sketches.iter().map(|handle| handle.id()));
+        known.extend(objects.solids.iter().map(|handle| handle.id()));
+        known.extend(objects.surfaces.iter().map(|handle| handle.id()));
+        known.extend(objects.vertices.iter().map(|handle| handle.id()));
+
+        Self { known }
+    }
+
+    /// Determine which objects have been inserted since the tracker was created
+    pub fn changed_since(&self, objects: &Service<Objects>) -> ChangedObjects {
+        ChangedObjects {
+            curves: self.unknown(objects.curves.iter()),
+            cycles: self.unknown(objects.cycles.iter()),
+            edges: self.unknown(objects.edges.iter()),
+            faces: self.unknown(objects.faces.iter()),
+            regions: self.unknown(objects.regions.iter()),
+            shells: self.unknown(objects.shells.iter()),
+            sketches: self.unknown(objects.sketches.iter()),
+            solids: self.unknown(objects.solids.iter()),
+            surfaces: self.unknown(objects.surfaces.iter()),
+            vertices: self.unknown(objects.vertices.iter()),
+        }
+    }
+
+    fn unknown<T>(
+        &self,
+        handles: impl Iterator<Item = Handle<T>>,
+    ) -> Vec<Handle<T>> {
+        handles
+            .filter(|handle| !self.known.contains(&handle.id()))
+            .collect()
+    }
+}
+
+/// The objects that have been inserted since a [`ChangeTracker`] was created
+///
+/// Returned by [`ChangeTracker::changed_since`].
+#[derive(Debug, Default)]
+pub struct ChangedObjects {
+    /// The [`Curve`]s that have been inserted
+    pub curves: Vec<Handle<Curve>>,
+
+    /// The [`Cycle`]s that have been inserted
+    pub cycles: Vec<Handle<Cycle>>,
+
+    /// The [`Edge`]s that have been inserted
+    pub edges: Vec<Handle<Edge>>,
+
+    /// The [`Face`]s that have been inserted
+    pub faces: Vec<Handle<Face>>,
+
+    /// The [`Region`]s that have been inserted
+    pub regions: Vec<Handle<Region>>,
+
+    /// The [`Shell`]s that have been inserted
+    pub shells: Vec<Handle<Shell>>,
+
+    /// The [`Sketch`]es that have been inserted
+    pub sketches: Vec<Handle<Sketch>>,
+
+    /// The [`Solid`]s that have been inserted
+    pub solids: Vec<Handle<Solid>>,
+
+    /// The [`Surface`]s that have been inserted
+    pub surfaces: Vec<Handle<Surface>>,
+
+    /// The [`Vertex`] objects that have been inserted
+    pub vertices: Vec<Handle<Vertex>>,
+}
+
+impl ChangedObjects {
+    /// Indicate whether no objects have been inserted
+    pub fn is_empty(&self) -> bool {
+        self.ids().next().is_none()
+    }
+
+    /// Indicate whether any of the provided objects have been inserted
+    pub fn affects(&self, objects: &ObjectSet) -> bool {
+        let changed = self.ids().collect::<BTreeSet<_>>();
+        objects.iter().any(|object| changed.contains(&object.id()))
+    }
+
+    fn ids(&self) -> impl Iterator<Item = ObjectId> + '_ {
+        let Self {
+            curves,
+            cycles,
+            edges,
+            faces,
+            regions,
+            shells,
+            sketches,
+            solids,
+            surfaces,
+            vertices,
+        } = self;
+
+        curves
+            .iter()
+            .map(Handle::id)
+            .chain(cycles.iter().map(Handle::id))
+            .chain(edges.iter().map(Handle::id))
+            .chain(faces.iter().map(Handle::id))
+            .chain(regions.iter().map(Handle::id))
+            .chain(shells.iter().map(Handle::id))
+            .chain(sketches.iter().map(Handle::id))
+            .chain(solids.iter().map(Handle::id))
+            .chain(surfaces.iter().map(Handle::id))
+            .chain(vertices.iter().map(Handle::id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{ObjectSet, Solid},
+        operations::{BuildSolid, Insert},
+        services::Services,
+    };
+
+    use super::ChangeTracker;
+
+    #[test]
+    fn affects() {
+        let mut services = Services::new();
+
+        let a = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .solid
+        .insert(&mut services);
+
+        let tracker = ChangeTracker::new(&services.objects);
+        assert!(tracker.changed_since(&services.objects).is_empty());
+
+        let b = Solid::tetrahedron(
+            [[2., 0., 0.], [3., 0., 0.], [2., 1., 0.], [2., 0., 1.]],
+            &mut services,
+        )
+        .solid
+        .insert(&mut services);
+
+        let changed = tracker.changed_since(&services.objects);
+        assert!(!changed.affects(&ObjectSet::from(&a)));
+        assert!(changed.affects(&ObjectSet::from(&b)));
+
+        let a = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .solid
+        .insert(&mut services);
+
+        let changed = tracker.changed_since(&services.objects);
+        assert!(changed.affects(&ObjectSet::from(&a)));
+    }
+}
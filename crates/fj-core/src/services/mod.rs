@@ -2,16 +2,18 @@
 //!
 //! See [`Service`].
 
+mod change_tracker;
 mod objects;
 mod service;
 mod validation;
 
 use crate::{
     objects::{Object, ObjectSet, Objects, WithHandle},
-    validate::ValidationErrors,
+    validate::{ValidationConfig, ValidationErrors},
 };
 
 pub use self::{
+    change_tracker::{ChangeTracker, ChangedObjects},
     objects::{InsertObject, Operation},
     service::{Service, State},
     validation::{Validation, ValidationCommand, ValidationEvent},
@@ -33,8 +35,20 @@ pub struct Services {
 impl Services {
     /// Construct an instance of `Services`
     pub fn new() -> Self {
+        Self::with_validation_config(ValidationConfig::default())
+    }
+
+    /// Construct an instance of `Services`, using a custom validation config
+    ///
+    /// Objects that are inserted are validated using the provided
+    /// configuration. For models that are much larger or smaller than one
+    /// unit, derive the configuration from a [`GeometryContext`] that is
+    /// adapted to the size of the model.
+    ///
+    /// [`GeometryContext`]: crate::geometry::GeometryContext
+    pub fn with_validation_config(config: ValidationConfig) -> Self {
         let objects = Service::<Objects>::default();
-        let validation = Service::default();
+        let validation = Service::new(Validation::with_config(config));
 
         Self {
             objects,
@@ -3,7 +3,7 @@ use std::{collections::BTreeMap, error::Error, thread};
 use crate::{
     objects::{BehindHandle, Object, ObjectSet},
     storage::ObjectId,
-    validate::ValidationError,
+    validate::{ValidationConfig, ValidationError},
 };
 
 use super::State;
@@ -13,6 +13,19 @@ use super::State;
 pub struct Validation {
     /// All unhandled validation errors
     pub errors: BTreeMap<ObjectId, ValidationError>,
+
+    /// The configuration that objects are validated with
+    pub config: ValidationConfig,
+}
+
+impl Validation {
+    /// Construct an instance of `Validation`, using the provided configuration
+    pub fn with_config(config: ValidationConfig) -> Self {
+        Self {
+            errors: BTreeMap::new(),
+            config,
+        }
+    }
 }
 
 impl Drop for Validation {
@@ -52,7 +65,7 @@ impl State for Validation {
 
         match command {
             ValidationCommand::ValidateObject { object } => {
-                object.validate(&mut errors);
+                object.validate_with_config(&self.config, &mut errors);
 
                 for err in errors {
                     events.push(ValidationEvent::ValidationFailed {
@@ -65,7 +78,7 @@ impl State for Validation {
                 events.push(ValidationEvent::ClearErrors);
 
                 for object in objects {
-                    object.validate(&mut errors);
+                    object.validate_with_config(&self.config, &mut errors);
 
                     for err in errors.drain(..) {
                         events.push(ValidationEvent::ValidationFailed {
@@ -118,3 +131,44 @@ pub enum ValidationEvent {
     /// All stored validation errors are being cleared
     ClearErrors,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        objects::{Cycle, Edge},
+        operations::{BuildCycle, BuildEdge, Insert, UpdateCycle},
+        services::Services,
+        validate::{Validate, ValidationConfig},
+    };
+
+    #[test]
+    fn validate_with_config() -> anyhow::Result<()> {
+        let config = ValidationConfig {
+            identical_max_distance: Scalar::from(1e-9),
+            ..ValidationConfig::default()
+        };
+        let mut services = Services::with_validation_config(config);
+
+        // The edges don't quite connect, which is only acceptable with the
+        // relaxed configuration.
+        let edges = [
+            [[0., 0.], [1., 0.]],
+            [[1., 1e-10], [0., 1.]],
+            [[0., 1.], [0., 0.]],
+        ]
+        .map(|points| {
+            Edge::line_segment(points, None, &mut services)
+                .insert(&mut services)
+        });
+        let cycle = Cycle::empty().add_edges(edges);
+
+        assert!(cycle.validate_and_return_first_error().is_err());
+
+        let _ = cycle.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+}
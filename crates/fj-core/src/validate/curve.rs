@@ -8,5 +8,10 @@ impl Validate for Curve {
         _: &ValidationConfig,
         _: &mut Vec<ValidationError>,
     ) {
+        // `Curve` holds no geometry, so there's nothing to check here. Whether
+        // the edges that refer to a curve agree on its position in global space
+        // can only be checked where their surfaces are known. That happens in
+        // the shell validation, which reports any deviation as
+        // `ShellValidationError::CurveCoordinateSystemMismatch`.
     }
 }
@@ -5,6 +5,7 @@ mod cycle;
 mod edge;
 mod face;
 mod region;
+mod report;
 mod shell;
 mod sketch;
 mod solid;
@@ -12,8 +13,14 @@ mod surface;
 mod vertex;
 
 pub use self::{
-    cycle::CycleValidationError, edge::EdgeValidationError,
-    face::FaceValidationError, shell::ShellValidationError,
+    cycle::CycleValidationError,
+    edge::EdgeValidationError,
+    face::FaceValidationError,
+    report::{
+        validate_solid_to_report, validate_to_report, Severity,
+        ValidationFinding, ValidationReport,
+    },
+    shell::ShellValidationError,
     solid::SolidValidationError,
 };
 
@@ -21,6 +28,11 @@ use std::{convert::Infallible, fmt};
 
 use fj_math::Scalar;
 
+use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
+    geometry::GeometryContext,
+};
+
 /// Assert that some object has a validation error which matches a specific
 /// pattern. This is preferred to matching on [`Validate::validate_and_return_first_error`], since usually we don't care about the order.
 #[macro_export]
@@ -62,9 +74,34 @@ pub trait Validate: Sized {
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
     );
+
+    /// Validate the object, unless cancelled
+    ///
+    /// Like [`Validate::validate_with_config`], but checks the provided token
+    /// and returns [`Cancelled`], if it has been cancelled. When validating
+    /// many objects, this makes it possible to stop in between any two of them.
+    ///
+    /// The default implementation checks the token once, before validating.
+    /// Objects whose validation is expensive, like [`Shell`] and [`Solid`],
+    /// check it within their validation loops too.
+    ///
+    /// [`Shell`]: crate::objects::Shell
+    /// [`Solid`]: crate::objects::Solid
+    fn validate_cancellable(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        CancellationToken::check_optional(token)?;
+        self.validate_with_config(config, errors);
+        Ok(())
+    }
 }
 
 /// Configuration required for the validation process
+///
+/// The default configuration is derived from the default [`GeometryContext`].
 #[derive(Debug, Clone, Copy)]
 pub struct ValidationConfig {
     /// The minimum distance between distinct objects
@@ -82,20 +119,22 @@ pub struct ValidationConfig {
     pub identical_max_distance: Scalar,
 }
 
-impl Default for ValidationConfig {
-    fn default() -> Self {
+impl ValidationConfig {
+    /// Derive the validation configuration from a [`GeometryContext`]
+    pub fn from_context(context: &GeometryContext) -> Self {
         Self {
-            distinct_min_distance: Scalar::from_f64(5e-7), // 0.5 µm,
-
-            // This value was chosen pretty arbitrarily. Seems small enough to
-            // catch errors. If it turns out it's too small (because it produces
-            // false positives due to floating-point accuracy issues), we can
-            // adjust it.
-            identical_max_distance: Scalar::from_f64(5e-14),
+            distinct_min_distance: context.distinct_min_distance,
+            identical_max_distance: context.identical_max_distance,
         }
     }
 }
 
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self::from_context(&GeometryContext::default())
+    }
+}
+
 /// An error that can occur during a validation
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum ValidationError {
@@ -0,0 +1,460 @@
+use std::fmt;
+
+use fj_math::{Point, Scalar};
+
+use crate::objects::Solid;
+
+use super::{
+    CycleValidationError, EdgeValidationError, FaceValidationError,
+    ShellValidationError, SolidValidationError, Validate, ValidationConfig,
+    ValidationError,
+};
+
+/// Validate an object and collect all findings into a report
+///
+/// Unlike [`Validate::validate_and_return_first_error`], this never fails. Use
+/// [`ValidationReport::is_ok`] to find out whether the object is valid.
+pub fn validate_to_report(
+    object: &impl Validate,
+    config: &ValidationConfig,
+) -> ValidationReport {
+    let mut errors = Vec::new();
+    object.validate_with_config(config, &mut errors);
+
+    let mut report = ValidationReport::default();
+    report.add_errors(Vec::new(), errors);
+    report
+}
+
+/// Validate a solid and all objects it references, collecting all findings
+///
+/// Each finding's breadcrumb is the path from the solid to the object whose
+/// validation produced it, like `["Solid", "Shell 0", "Face 3", "Region",
+/// "Cycle 1", "Edge 2"]`. The first cycle of a region is its exterior, the
+/// others are its interiors.
+pub fn validate_solid_to_report(
+    solid: &Solid,
+    config: &ValidationConfig,
+) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    let mut path = vec!["Solid".to_string()];
+    report.add_object(&path, solid, config);
+
+    for (i, shell) in solid.shells().iter().enumerate() {
+        path.push(format!("Shell {i}"));
+        report.add_object(&path, &**shell, config);
+
+        for (j, face) in shell.faces().iter().enumerate() {
+            path.push(format!("Face {j}"));
+            report.add_object(&path, &**face, config);
+
+            path.push("Region".to_string());
+            report.add_object(&path, &**face.region(), config);
+
+            for (k, cycle) in face.region().all_cycles().enumerate() {
+                path.push(format!("Cycle {k}"));
+                report.add_object(&path, &**cycle, config);
+
+                for (l, edge) in cycle.edges().iter().enumerate() {
+                    path.push(format!("Edge {l}"));
+                    report.add_object(&path, &**edge, config);
+                    path.pop();
+                }
+
+                path.pop();
+            }
+
+            path.pop();
+            path.pop();
+        }
+
+        path.pop();
+    }
+
+    report
+}
+
+/// A report of validation findings, meant to be consumed by tooling
+///
+/// With the `serde` feature enabled, the report can be serialized, for example
+/// to JSON for consumption in a CI pipeline. Its [`Display`] implementation
+/// provides a human-readable summary.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidationReport {
+    /// The findings of the validation
+    pub findings: Vec<ValidationFinding>,
+}
+
+impl ValidationReport {
+    /// Add validation errors to the report
+    ///
+    /// `breadcrumb` is prepended to the breadcrumb of each finding. Callers can
+    /// use it to describe where in a larger structure the validated object is
+    /// located, for example `["Solid", "Shell 0"]`.
+    pub fn add_errors(
+        &mut self,
+        breadcrumb: Vec<String>,
+        errors: impl IntoIterator<Item = ValidationError>,
+    ) {
+        for error in errors {
+            let mut finding = ValidationFinding::from_error(&error);
+
+            let mut full_breadcrumb = breadcrumb.clone();
+            full_breadcrumb.append(&mut finding.breadcrumb);
+            finding.breadcrumb = full_breadcrumb;
+
+            self.findings.push(finding);
+        }
+    }
+
+    /// Indicate whether the report contains no errors
+    pub fn is_ok(&self) -> bool {
+        self.findings
+            .iter()
+            .all(|finding| finding.severity != Severity::Error)
+    }
+
+    fn add_object(
+        &mut self,
+        path: &[String],
+        object: &impl Validate,
+        config: &ValidationConfig,
+    ) {
+        let mut errors = Vec::new();
+        object.validate_with_config(config, &mut errors);
+
+        for error in errors {
+            let mut finding = ValidationFinding::from_error(&error);
+            finding.breadcrumb = path.to_vec();
+            self.findings.push(finding);
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.findings.is_empty() {
+            return writeln!(f, "Validation found no issues");
+        }
+
+        writeln!(f, "Validation found {} issues:", self.findings.len())?;
+        for finding in &self.findings {
+            writeln!(f, "- {finding}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single finding in a [`ValidationReport`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidationFinding {
+    /// The severity of the finding
+    pub severity: Severity,
+
+    /// The path to the object that the finding applies to
+    ///
+    /// The last element identifies the object whose validation produced the
+    /// finding. See [`validate_solid_to_report`].
+    pub breadcrumb: Vec<String>,
+
+    /// The kind of finding
+    ///
+    /// This is the name of the variant of the respective validation error,
+    /// like `EdgesDisconnected`.
+    pub kind: String,
+
+    /// The distance that caused the finding, if applicable
+    pub distance: Option<f64>,
+
+    /// The positions that are relevant to the finding, if any
+    pub positions: Vec<Vec<f64>>,
+
+    /// The full error message
+    pub message: String,
+}
+
+impl ValidationFinding {
+    /// Construct a finding from a validation error
+    pub fn from_error(error: &ValidationError) -> Self {
+        let (object, kind, distance, positions) = match error {
+            ValidationError::Cycle(error) => {
+                let (kind, distance, positions) = match error {
+                    CycleValidationError::EdgesDisconnected {
+                        end_of_first,
+                        start_of_second,
+                        distance,
+                        ..
+                    } => (
+                        "EdgesDisconnected",
+                        Some(*distance),
+                        coords_of([*end_of_first, *start_of_second]),
+                    ),
+                    CycleValidationError::NotEnoughEdges => {
+                        ("NotEnoughEdges", None, Vec::new())
+                    }
+                };
+                ("Cycle", kind, distance, positions)
+            }
+            ValidationError::Edge(error) => {
+                let (kind, distance, positions) = match error {
+                    EdgeValidationError::VerticesAreCoincident {
+                        back_position,
+                        front_position,
+                        distance,
+                        ..
+                    } => (
+                        "VerticesAreCoincident",
+                        Some(*distance),
+                        coords_of([*back_position, *front_position]),
+                    ),
+                };
+                ("Edge", kind, distance, positions)
+            }
+            ValidationError::Face(error) => {
+                let kind = match error {
+                    FaceValidationError::InvalidInteriorWinding { .. } => {
+                        "InvalidInteriorWinding"
+                    }
+                };
+                ("Face", kind, None, Vec::new())
+            }
+            ValidationError::Shell(error) => {
+                let (kind, distance, positions) = match error {
+                    ShellValidationError::CurveCoordinateSystemMismatch(
+                        mismatches,
+                    ) => (
+                        "CurveCoordinateSystemMismatch",
+                        mismatches
+                            .iter()
+                            .map(|mismatch| mismatch.distance)
+                            .max(),
+                        mismatches
+                            .iter()
+                            .flat_map(|mismatch| {
+                                coords_of([mismatch.point_a, mismatch.point_b])
+                            })
+                            .collect(),
+                    ),
+                    ShellValidationError::NotWatertight => {
+                        ("NotWatertight", None, Vec::new())
+                    }
+                    ShellValidationError::CoincidentEdgesNotIdentical(..) => {
+                        ("CoincidentEdgesNotIdentical", None, Vec::new())
+                    }
+                    ShellValidationError::IdenticalEdgesNotCoincident {
+                        ..
+                    } => ("IdenticalEdgesNotCoincident", None, Vec::new()),
+                    ShellValidationError::VertexPositionMismatch(mismatch) => (
+                        "VertexPositionMismatch",
+                        Some(mismatch.distance),
+                        coords_of([mismatch.point_a, mismatch.point_b]),
+                    ),
+                    ShellValidationError::MixedOrientations => {
+                        ("MixedOrientations", None, Vec::new())
+                    }
+                };
+                ("Shell", kind, distance, positions)
+            }
+            ValidationError::Solid(error) => {
+                let (kind, position_a, position_b) = match error {
+                    SolidValidationError::DistinctVerticesCoincide {
+                        position_a,
+                        position_b,
+                        ..
+                    } => ("DistinctVerticesCoincide", position_a, position_b),
+                };
+                (
+                    "Solid",
+                    kind,
+                    Some(position_a.distance_to(position_b)),
+                    coords_of([*position_a, *position_b]),
+                )
+            }
+        };
+
+        Self {
+            severity: Severity::Error,
+            breadcrumb: vec![object.to_string()],
+            kind: kind.to_string(),
+            distance: distance.map(Scalar::into_f64),
+            positions,
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationFinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} in {}: {}",
+            self.severity,
+            self.breadcrumb.join(" > "),
+            self.kind
+        )?;
+
+        if let Some(distance) = self.distance {
+            write!(f, " (distance: {distance})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The severity of a [`ValidationFinding`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Severity {
+    /// The finding makes the object invalid
+    Error,
+}
+
+fn coords_of<const D: usize>(
+    points: impl IntoIterator<Item = Point<D>>,
+) -> Vec<Vec<f64>> {
+    points
+        .into_iter()
+        .map(|point| {
+            point
+                .coords
+                .components
+                .into_iter()
+                .map(Scalar::into_f64)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::fixtures,
+        objects::{Cycle, Edge, Shell, Solid},
+        operations::{BuildCycle, BuildEdge, Insert, UpdateCycle},
+        services::Services,
+        validate::ValidationConfig,
+    };
+
+    use super::{validate_solid_to_report, validate_to_report, Severity};
+
+    #[test]
+    fn report() {
+        let mut services = Services::new();
+        let config = ValidationConfig::default();
+
+        let valid =
+            Cycle::polygon([[0., 0.], [1., 0.], [1., 1.]], &mut services);
+        let report = validate_to_report(&valid, &config);
+        assert!(report.is_ok());
+        assert!(report.findings.is_empty());
+
+        let disconnected = {
+            let edges = [
+                Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services),
+                Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services),
+            ];
+            let edges = edges.map(|edge| edge.insert(&mut services));
+
+            Cycle::empty().add_edges(edges)
+        };
+        let report = validate_to_report(&disconnected, &config);
+        assert!(!report.is_ok());
+        assert_eq!(report.findings.len(), 2);
+
+        for finding in &report.findings {
+            assert_eq!(finding.severity, Severity::Error);
+            assert_eq!(finding.breadcrumb, ["Cycle"]);
+            assert_eq!(finding.kind, "EdgesDisconnected");
+            assert_eq!(finding.distance, Some(1.));
+            assert_eq!(finding.positions, [[1., 0.], [0., 0.]]);
+        }
+
+        assert!(report
+            .to_string()
+            .contains("Error in Cycle: EdgesDisconnected (distance: 1)"));
+    }
+
+    #[test]
+    fn solid_breadcrumbs() {
+        let mut services = Services::new();
+        let config = ValidationConfig::default();
+
+        let cube = fixtures::cube([0., 0.], &mut services);
+
+        let report = validate_solid_to_report(&cube, &config);
+        assert!(report.is_ok());
+
+        let cube_shell = cube.shells().nth(0).unwrap();
+        let open_shell = Shell::new(cube_shell.faces().iter().skip(1).cloned())
+            .insert(&mut services);
+        let open_box = Solid::new([cube_shell.clone(), open_shell]);
+
+        let report = validate_solid_to_report(&open_box, &config);
+        assert!(!report.is_ok());
+        assert!(report.findings.iter().all(|finding| {
+            finding.breadcrumb == ["Solid", "Shell 1"]
+                && finding.kind == "NotWatertight"
+        }));
+
+        services.only_validate(
+            cube_shell
+                .faces()
+                .iter()
+                .map(|face| (**face).clone())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_to_json() -> anyhow::Result<()> {
+        use crate::validate::Validate;
+
+        let mut services = Services::new();
+        let config = ValidationConfig::default();
+
+        let disconnected = {
+            let edges = [
+                Edge::line_segment([[0., 0.], [1., 0.]], None, &mut services),
+                Edge::line_segment([[1., 1.], [0., 1.]], None, &mut services),
+            ];
+            let edges = edges.map(|edge| edge.insert(&mut services));
+
+            Cycle::empty().add_edges(edges)
+        };
+        let mut errors = Vec::new();
+        disconnected.validate_with_config(&config, &mut errors);
+        errors.truncate(1);
+
+        let mut report = validate_to_report(&Cycle::new([]), &config);
+        report.add_errors(vec!["Face".to_string()], errors);
+
+        let json = serde_json::to_value(&report)?;
+        let findings = json["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), 2);
+
+        assert_eq!(findings[0]["severity"], "Error");
+        assert_eq!(findings[0]["breadcrumb"], serde_json::json!(["Cycle"]));
+        assert_eq!(findings[0]["kind"], "NotEnoughEdges");
+        assert_eq!(findings[0]["distance"], serde_json::Value::Null);
+
+        assert_eq!(
+            findings[1]["breadcrumb"],
+            serde_json::json!(["Face", "Cycle"])
+        );
+        assert_eq!(findings[1]["kind"], "EdgesDisconnected");
+        assert_eq!(findings[1]["distance"], 1.);
+        assert_eq!(
+            findings[1]["positions"],
+            serde_json::json!([[1., 0.], [1., 1.]])
+        );
+
+        Ok(())
+    }
+}
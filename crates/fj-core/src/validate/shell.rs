@@ -3,8 +3,9 @@ use std::collections::BTreeMap;
 use fj_math::{Point, Scalar};
 
 use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
     geometry::SurfaceGeometry,
-    objects::{Edge, Shell, Surface},
+    objects::{Edge, Shell, Surface, Vertex},
     queries::{AllEdgesWithSurface, BoundingVerticesOfEdge},
     storage::{Handle, HandleWrapper},
 };
@@ -17,10 +18,27 @@ impl Validate for Shell {
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
     ) {
-        ShellValidationError::validate_curve_coordinates(self, config, errors);
-        ShellValidationError::validate_edges_coincident(self, config, errors);
+        // Without a token, validation can't be cancelled.
+        let _ = self.validate_cancellable(config, errors, None);
+    }
+
+    fn validate_cancellable(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        ShellValidationError::validate_curve_coordinates(
+            self, config, errors, token,
+        )?;
+        ShellValidationError::validate_edges_coincident(
+            self, config, errors, token,
+        )?;
+        ShellValidationError::validate_vertex_positions(self, config, errors);
         ShellValidationError::validate_watertight(self, config, errors);
         ShellValidationError::validate_same_orientation(self, errors);
+
+        Ok(())
     }
 }
 
@@ -70,6 +88,16 @@ pub enum ShellValidationError {
         surface_b: Handle<Surface>,
     },
 
+    /// [`Shell`] contains a vertex that is at different positions, depending
+    /// on which surface it is evaluated on
+    #[error(
+        "Shell contains `Vertex` whose position differs between surfaces \
+        (distance: {}): {:#?}",
+        .0.distance,
+        .0
+    )]
+    VertexPositionMismatch(Box<VertexPositionMismatch>),
+
     /// [`Shell`] contains faces of mixed orientation (inwards and outwards)
     #[error("Shell has mixed face orientations")]
     MixedOrientations,
@@ -115,11 +143,14 @@ impl ShellValidationError {
         shell: &Shell,
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
-    ) {
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
         let mut edges_and_surfaces = Vec::new();
         shell.all_edges_with_surface(&mut edges_and_surfaces);
 
         for (edge_a, surface_a) in &edges_and_surfaces {
+            CancellationToken::check_optional(token)?;
+
             for (edge_b, surface_b) in &edges_and_surfaces {
                 // We only care about edges referring to the same curve.
                 if edge_a.curve().id() != edge_b.curve().id() {
@@ -201,13 +232,16 @@ impl ShellValidationError {
                 }
             }
         }
+
+        Ok(())
     }
 
     fn validate_edges_coincident(
         shell: &Shell,
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
-    ) {
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
         let mut edges_and_surfaces = Vec::new();
         shell.all_edges_with_surface(&mut edges_and_surfaces);
 
@@ -215,6 +249,8 @@ impl ShellValidationError {
         // need to deal with float inaccuracies. Maybe we could use some smarter
         // data-structure like an octree.
         for (edge_a, surface_a) in &edges_and_surfaces {
+            CancellationToken::check_optional(token)?;
+
             for (edge_b, surface_b) in &edges_and_surfaces {
                 // No need to check an edge against itself.
                 if edge_a.id() == edge_b.id() {
@@ -287,6 +323,61 @@ impl ShellValidationError {
                 }
             }
         }
+
+        Ok(())
+    }
+
+    fn validate_vertex_positions(
+        shell: &Shell,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut edges_and_surfaces = Vec::new();
+        shell.all_edges_with_surface(&mut edges_and_surfaces);
+
+        // Every vertex is the start vertex of at least one edge in each face it
+        // is part of. Evaluating the start of each of those edges on the
+        // respective surface must yield the same position.
+        let mut positions_by_vertex = BTreeMap::new();
+
+        for (edge, surface) in edges_and_surfaces {
+            let position = surface
+                .geometry()
+                .point_from_surface_coords(edge.start_position());
+
+            positions_by_vertex
+                .entry(HandleWrapper::from(edge.start_vertex().clone()))
+                .or_insert(Vec::new())
+                .push((position, surface));
+        }
+
+        for (vertex, positions) in positions_by_vertex {
+            let mut positions = positions.into_iter();
+
+            let Some((point_a, surface_a)) = positions.next() else {
+                continue;
+            };
+
+            for (point_b, surface_b) in positions {
+                let distance = point_a.distance_to(&point_b);
+
+                if distance > config.identical_max_distance {
+                    errors.push(
+                        Self::VertexPositionMismatch(Box::new(
+                            VertexPositionMismatch {
+                                vertex: vertex.0.clone(),
+                                point_a,
+                                surface_a: surface_a.clone(),
+                                point_b,
+                                surface_b,
+                                distance,
+                            },
+                        ))
+                        .into(),
+                    );
+                }
+            }
+        }
     }
 
     fn validate_watertight(
@@ -370,17 +461,45 @@ pub struct CurveCoordinateSystemMismatch {
     pub distance: Scalar,
 }
 
+/// A vertex whose position differs between two of the surfaces it is on
+///
+/// See [`ShellValidationError::VertexPositionMismatch`].
+#[derive(Clone, Debug)]
+pub struct VertexPositionMismatch {
+    /// The vertex
+    pub vertex: Handle<Vertex>,
+
+    /// The position of the vertex on the first surface
+    pub point_a: Point<3>,
+
+    /// The first surface
+    pub surface_a: Handle<Surface>,
+
+    /// The position of the vertex on the second surface
+    pub point_b: Point<3>,
+
+    /// The second surface
+    pub surface_b: Handle<Surface>,
+
+    /// The distance between the two positions
+    pub distance: Scalar,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
+        algorithms::cancellation::{CancellationToken, Cancelled},
         assert_contains_err,
-        objects::{Curve, Shell},
+        objects::{Curve, Face, Shell},
         operations::{
             BuildShell, Insert, Reverse, UpdateCycle, UpdateEdge, UpdateFace,
             UpdateRegion, UpdateShell,
         },
         services::Services,
-        validate::{shell::ShellValidationError, Validate, ValidationError},
+        validate::{
+            shell::ShellValidationError, Validate, ValidationConfig,
+            ValidationError,
+        },
     };
 
     #[test]
@@ -467,6 +586,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vertex_position_mismatch() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+        let invalid = valid.shell.replace_face(
+            &valid.abc.face,
+            Face::new(
+                services.objects.surfaces.xz_plane(),
+                valid.abc.face.region().clone(),
+            )
+            .insert(&mut services),
+        );
+
+        valid.shell.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Shell(
+                ShellValidationError::VertexPositionMismatch(..)
+            )
+        );
+
+        let mut errors = Vec::new();
+        invalid.validate(&mut errors);
+        let (message, distance) = errors
+            .iter()
+            .find_map(|error| match error {
+                ValidationError::Shell(
+                    error @ ShellValidationError::VertexPositionMismatch(
+                        mismatch,
+                    ),
+                ) => Some((error.to_string(), mismatch.distance)),
+                _ => None,
+            })
+            .expect("Expected vertex position mismatch");
+        assert!(message.contains(&format!("(distance: {distance})")));
+
+        Ok(())
+    }
+
     #[test]
     fn shell_not_watertight() -> anyhow::Result<()> {
         let mut services = Services::new();
@@ -506,4 +668,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn validate_cancellable() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .shell;
+        let config = ValidationConfig::default();
+
+        // The token is checked once per edge, in each of the two loops over
+        // all pairs of edges.
+        let token = CancellationToken::new();
+        let mut errors = Vec::new();
+        shell.validate_cancellable(&config, &mut errors, Some(&token))?;
+        assert!(errors.is_empty());
+        assert_eq!(token.num_checks(), 2 * 12);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let result =
+            shell.validate_cancellable(&config, &mut errors, Some(&token));
+        assert_eq!(result, Err(Cancelled));
+        assert_eq!(token.num_checks(), 1);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use std::iter::repeat;
 
 use crate::{
+    algorithms::cancellation::{CancellationToken, Cancelled},
     objects::{Solid, Vertex},
     storage::Handle,
 };
@@ -14,7 +15,17 @@ impl Validate for Solid {
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
     ) {
-        SolidValidationError::check_vertices(self, config, errors)
+        // Without a token, validation can't be cancelled.
+        let _ = self.validate_cancellable(config, errors, None);
+    }
+
+    fn validate_cancellable(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
+        SolidValidationError::check_vertices(self, config, errors, token)
     }
 }
 
@@ -40,26 +51,6 @@ pub enum SolidValidationError {
         /// Position of second vertex
         position_b: Point<3>,
     },
-
-    /// [`Solid`] contains vertices that are identical, but do not coincide
-    #[error(
-        "Solid contains Vertices that are identical but do not coincide\n
-        Vertex 1: {vertex_a:#?} ({position_a:?})
-        Vertex 2: {vertex_b:#?} ({position_b:?})"
-    )]
-    IdenticalVerticesNotCoincident {
-        /// The first vertex
-        vertex_a: Handle<Vertex>,
-
-        /// The second vertex
-        vertex_b: Handle<Vertex>,
-
-        /// Position of first vertex
-        position_a: Point<3>,
-
-        /// Position of second vertex
-        position_b: Point<3>,
-    },
 }
 
 impl SolidValidationError {
@@ -67,7 +58,8 @@ impl SolidValidationError {
         solid: &Solid,
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
-    ) {
+        token: Option<&CancellationToken>,
+    ) -> Result<(), Cancelled> {
         let vertices: Vec<(Point<3>, Handle<Vertex>)> = solid
             .shells()
             .iter()
@@ -90,30 +82,18 @@ impl SolidValidationError {
         // need to deal with float inaccuracies. Maybe we could use some smarter
         // data-structure like an octree.
         for (position_a, vertex_a) in &vertices {
-            for (position_b, vertex_b) in &vertices {
-                let vertices_are_identical = vertex_a.id() == vertex_b.id();
-                let vertices_are_not_identical = !vertices_are_identical;
+            CancellationToken::check_optional(token)?;
 
-                let too_far_to_be_identical = position_a
-                    .distance_to(position_b)
-                    > config.identical_max_distance;
-                let too_close_to_be_distinct = position_a
-                    .distance_to(position_b)
-                    < config.distinct_min_distance;
-
-                if vertices_are_identical && too_far_to_be_identical {
-                    errors.push(
-                        Self::IdenticalVerticesNotCoincident {
-                            vertex_a: vertex_a.clone(),
-                            vertex_b: vertex_b.clone(),
-                            position_a: *position_a,
-                            position_b: *position_b,
-                        }
-                        .into(),
-                    )
+            for (position_b, vertex_b) in &vertices {
+                // Whether identical vertices coincide is checked per shell.
+                // Here, we only care about distinct ones.
+                if vertex_a.id() == vertex_b.id() {
+                    continue;
                 }
 
-                if vertices_are_not_identical && too_close_to_be_distinct {
+                if position_a.distance_to(position_b)
+                    < config.distinct_min_distance
+                {
                     errors.push(
                         Self::DistinctVerticesCoincide {
                             vertex_a: vertex_a.clone(),
@@ -126,5 +106,50 @@ impl SolidValidationError {
                 }
             }
         }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::cancellation::{CancellationToken, Cancelled},
+        objects::{Shell, Solid},
+        operations::{BuildShell, Insert},
+        services::Services,
+        validate::{Validate, ValidationConfig},
+    };
+
+    #[test]
+    fn validate_cancellable() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .shell;
+        let solid = Solid::new([shell.insert(&mut services)]);
+        let config = ValidationConfig::default();
+
+        // The token is checked once per vertex of every face.
+        let token = CancellationToken::new();
+        let mut errors = Vec::new();
+        solid.validate_cancellable(&config, &mut errors, Some(&token))?;
+        assert!(errors.is_empty());
+        assert_eq!(token.num_checks(), 12);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let result =
+            solid.validate_cancellable(&config, &mut errors, Some(&token));
+        assert_eq!(result, Err(Cancelled));
+        assert_eq!(token.num_checks(), 1);
+
+        let _ = solid.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
     }
 }
@@ -15,7 +15,7 @@ use std::{fs::File, path::Path};
 use thiserror::Error;
 
 use fj_interop::mesh::Mesh;
-use fj_math::{Point, Triangle};
+use fj_math::{Point, Scalar, Triangle};
 
 /// Export the provided mesh to the file at the given path.
 ///
@@ -24,6 +24,29 @@ use fj_math::{Point, Triangle};
 /// Currently 3MF & STL file types are supported. The case insensitive file extension of
 /// the provided path is used to switch between supported types.
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+    export_with_options(mesh, path, &ExportOptions::default())
+}
+
+/// Export the provided mesh to the file at the given path, using options
+///
+/// Like [`export`], but allows configuring the export using [`ExportOptions`].
+pub fn export_with_options(
+    mesh: &Mesh<Point<3>>,
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let quantized;
+    let mesh = match options.quantization_grid {
+        Some(grid) => {
+            let mut mesh = mesh.clone();
+            mesh.quantize(grid);
+
+            quantized = mesh;
+            &quantized
+        }
+        None => mesh,
+    };
+
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
             export_3mf(mesh, path)
@@ -32,7 +55,12 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
             export_stl(mesh, path)
         }
         Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
-            export_obj(mesh, path)
+            match options.obj_planar_polygons {
+                Some(angle_tolerance) => {
+                    export_obj_polygons(mesh, angle_tolerance, path)
+                }
+                None => export_obj(mesh, path),
+            }
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -41,6 +69,28 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
+/// Options for [`export_with_options`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExportOptions {
+    /// Snap the vertices of the mesh to a grid of this size before exporting
+    ///
+    /// This makes the exported files independent of tiny numerical
+    /// differences, like those between different platforms. See
+    /// [`Mesh::quantize`].
+    pub quantization_grid: Option<Scalar>,
+
+    /// Write planar polygons instead of triangles to OBJ files
+    ///
+    /// Adjacent triangles whose normals differ by at most this angle, in
+    /// radians, are merged into a single polygonal face. Polygons with holes
+    /// can't be represented as a single face, and are written as triangles
+    /// instead. See [`Mesh::extract_planar_polygons`].
+    ///
+    /// Texture coordinates are only written to OBJ files, if this is `None`.
+    /// Has no effect on other file formats.
+    pub obj_planar_polygons: Option<Scalar>,
+}
+
 fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     let vertices = mesh
         .vertices()
@@ -120,11 +170,14 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
 
 fn export_obj(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     let mut f = File::create(path)?;
+    let writer = wavefront_rs::obj::writer::Writer { auto_newline: true };
+
+    let mut num_uvs = 0;
 
     for (cnt, t) in mesh.triangles().enumerate() {
         // write each point of the triangle
         for v in t.inner.points() {
-            wavefront_rs::obj::writer::Writer { auto_newline: true }
+            writer
                 .write(
                     &mut f,
                     &wavefront_rs::obj::entity::Entity::Vertex {
@@ -137,33 +190,101 @@ fn export_obj(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
                 .or(Err(Error::OBJ))?;
         }
 
+        // write the texture coordinates of each point, if available
+        let first_uv = match t.uv {
+            Some(uv) => {
+                for uv in uv {
+                    writer
+                        .write(
+                            &mut f,
+                            &wavefront_rs::obj::entity::Entity::VertexTexture {
+                                u: uv.u.into_f64(),
+                                v: Some(uv.v.into_f64()),
+                                w: None,
+                            },
+                        )
+                        .or(Err(Error::OBJ))?;
+                }
+
+                num_uvs += 3;
+                Some(num_uvs - 3)
+            }
+            None => None,
+        };
+
         // write the triangle
-        wavefront_rs::obj::writer::Writer { auto_newline: true }
+        let vertices = (1..=3)
+            .map(|k| wavefront_rs::obj::entity::FaceVertex {
+                vertex: (cnt * 3 + k) as i64,
+                texture: first_uv.map(|first| (first + k) as i64),
+                normal: None,
+            })
+            .collect();
+        writer
             .write(
                 &mut f,
-                &wavefront_rs::obj::entity::Entity::Face {
-                    vertices: vec![
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 1) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 2) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 3) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                    ],
+                &wavefront_rs::obj::entity::Entity::Face { vertices },
+            )
+            .or(Err(Error::OBJ))?;
+    }
+
+    Ok(())
+}
+
+fn export_obj_polygons(
+    mesh: &Mesh<Point<3>>,
+    angle_tolerance: Scalar,
+    path: &Path,
+) -> Result<(), Error> {
+    let mut f = File::create(path)?;
+    let writer = wavefront_rs::obj::writer::Writer { auto_newline: true };
+
+    for v in mesh.vertices() {
+        writer
+            .write(
+                &mut f,
+                &wavefront_rs::obj::entity::Entity::Vertex {
+                    x: v.x.into_f64(),
+                    y: v.y.into_f64(),
+                    z: v.z.into_f64(),
+                    w: None,
                 },
             )
             .or(Err(Error::OBJ))?;
     }
 
+    let indices = mesh.indices().collect::<Vec<_>>();
+
+    for polygon in mesh.extract_planar_polygons(angle_tolerance) {
+        let faces = if polygon.interiors.is_empty() {
+            vec![polygon.exterior]
+        } else {
+            polygon
+                .triangles
+                .iter()
+                .map(|&t| indices[t * 3..t * 3 + 3].to_vec())
+                .collect()
+        };
+
+        for face in faces {
+            let vertices = face
+                .into_iter()
+                .map(|index| wavefront_rs::obj::entity::FaceVertex {
+                    vertex: i64::from(index) + 1,
+                    texture: None,
+                    normal: None,
+                })
+                .collect();
+
+            writer
+                .write(
+                    &mut f,
+                    &wavefront_rs::obj::entity::Entity::Face { vertices },
+                )
+                .or(Err(Error::OBJ))?;
+        }
+    }
+
     Ok(())
 }
 
@@ -194,3 +315,175 @@ pub enum Error {
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use fj_interop::mesh::{Color, Mesh};
+    use fj_math::{Point, Scalar};
+
+    use super::{export_with_options, ExportOptions};
+
+    #[test]
+    fn export_quantized() {
+        let options = ExportOptions {
+            quantization_grid: Some(Scalar::from(1e-6)),
+            ..ExportOptions::default()
+        };
+
+        // These meshes only differ in the last bit of one coordinate, like
+        // meshes from different platforms might.
+        let meshes = [0., f64::EPSILON].map(|offset| {
+            let points = [[0., 0., 0.], [1. + offset, 0., 0.], [0., 1., 0.]]
+                .map(Point::from);
+
+            let mut mesh = Mesh::new();
+            mesh.push_triangle(points, Color::default());
+            mesh
+        });
+
+        for extension in ["stl", "obj"] {
+            let files = meshes.iter().enumerate().map(|(i, mesh)| {
+                let path =
+                    temp_path(&format!("export_quantized_{i}.{extension}"));
+                export_with_options(mesh, &path, &options).unwrap();
+
+                let file = fs::read(&path).unwrap();
+                fs::remove_file(&path).unwrap();
+                file
+            });
+            let [a, b]: [Vec<u8>; 2] =
+                files.collect::<Vec<_>>().try_into().unwrap();
+
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn export_obj_polygons() {
+        let options = ExportOptions {
+            obj_planar_polygons: Some(Scalar::from(1e-6)),
+            ..ExportOptions::default()
+        };
+
+        let sides = [
+            [[0., 0., 0.], [0., 1., 0.], [1., 1., 0.], [1., 0., 0.]],
+            [[0., 0., 1.], [1., 0., 1.], [1., 1., 1.], [0., 1., 1.]],
+            [[0., 0., 0.], [1., 0., 0.], [1., 0., 1.], [0., 0., 1.]],
+            [[0., 1., 0.], [0., 1., 1.], [1., 1., 1.], [1., 1., 0.]],
+            [[0., 0., 0.], [0., 0., 1.], [0., 1., 1.], [0., 1., 0.]],
+            [[1., 0., 0.], [1., 1., 0.], [1., 1., 1.], [1., 0., 1.]],
+        ];
+        let mut mesh = Mesh::new();
+        for [a, b, c, d] in sides {
+            for triangle in [[a, b, c], [a, c, d]] {
+                mesh.push_triangle(triangle.map(Point::from), Color::default());
+            }
+        }
+
+        let path = temp_path("export_obj_polygons.obj");
+        export_with_options(&mesh, &path, &options).unwrap();
+        let file = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Parse the file again, resolving the indices of each face.
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+        for line in file.lines() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let coords = tokens
+                        .map(|token| token.parse::<f64>().unwrap())
+                        .collect::<Vec<_>>();
+                    vertices.push(coords);
+                }
+                Some("f") => {
+                    let face = tokens
+                        .map(|token| {
+                            let index = token
+                                .split('/')
+                                .next()
+                                .unwrap()
+                                .parse::<usize>()
+                                .unwrap();
+                            vertices[index - 1].clone()
+                        })
+                        .collect::<Vec<_>>();
+                    faces.push(face);
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(vertices.len(), 8);
+        assert_eq!(faces.len(), 6);
+        for face in faces {
+            assert_eq!(face.len(), 4);
+        }
+    }
+
+    #[test]
+    fn export_obj_uv() {
+        let points = [[0., 0., 0.], [2., 0., 0.], [2., 2., 0.], [0., 2., 0.]]
+            .map(Point::from);
+        let uv = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(Point::from);
+
+        let mut mesh = Mesh::new();
+        for [a, b, c] in [[0, 1, 2], [0, 2, 3]] {
+            mesh.push_triangle_with_uv(
+                [points[a], points[b], points[c]],
+                [uv[a], uv[b], uv[c]],
+                Color::default(),
+            );
+        }
+
+        let path = temp_path("export_obj_uv.obj");
+        export_with_options(&mesh, &path, &ExportOptions::default()).unwrap();
+        let file = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let mut uvs = Vec::new();
+        let mut faces = Vec::new();
+        for line in file.lines() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("vt") => {
+                    let coords = tokens
+                        .map(|token| token.parse::<f64>().unwrap())
+                        .collect::<Vec<_>>();
+                    uvs.push(coords);
+                }
+                Some("f") => {
+                    let face = tokens
+                        .map(|token| {
+                            let index = token
+                                .split('/')
+                                .nth(1)
+                                .unwrap()
+                                .parse::<usize>()
+                                .unwrap();
+                            uvs[index - 1].clone()
+                        })
+                        .collect::<Vec<_>>();
+                    faces.push(face);
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(uvs.len(), 6);
+        assert_eq!(
+            faces,
+            [
+                [[0., 0.], [1., 0.], [1., 1.]],
+                [[0., 0.], [1., 1.], [0., 1.]]
+            ]
+        );
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("fj-export-{}-{name}", std::process::id()))
+    }
+}
@@ -0,0 +1,20 @@
+//! Fixtures that are shared between the tests of this crate
+
+use fj_math::Point;
+
+/// The triangles of a unit cube, counter-clockwise when viewed from the outside
+pub fn cube_triangles() -> impl Iterator<Item = [Point<3>; 3]> {
+    let sides = [
+        [[0., 0., 0.], [0., 1., 0.], [1., 1., 0.], [1., 0., 0.]],
+        [[0., 0., 1.], [1., 0., 1.], [1., 1., 1.], [0., 1., 1.]],
+        [[0., 0., 0.], [1., 0., 0.], [1., 0., 1.], [0., 0., 1.]],
+        [[0., 1., 0.], [0., 1., 1.], [1., 1., 1.], [1., 1., 0.]],
+        [[0., 0., 0.], [0., 0., 1.], [0., 1., 1.], [0., 1., 0.]],
+        [[1., 0., 0.], [1., 1., 0.], [1., 1., 1.], [1., 0., 1.]],
+    ];
+
+    sides
+        .into_iter()
+        .flat_map(|[a, b, c, d]| [[a, b, c], [a, c, d]])
+        .map(|triangle| triangle.map(Point::from))
+}
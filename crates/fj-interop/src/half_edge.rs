@@ -0,0 +1,277 @@
+//! Half-edge adjacency structure for triangle meshes
+//!
+//! See [`HalfEdgeMesh`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::mesh::Index;
+
+/// A half-edge data structure, built from the index buffer of a triangle mesh
+///
+/// Each triangle of the mesh is represented by three half-edges, which run
+/// along its edges in order. Half-edges that run along the same edge in
+/// opposite directions are each other's twins. Half-edges on the boundary of
+/// the mesh have no twin.
+///
+/// The half-edges of the triangle with index `t` are located at indices `3 * t`
+/// to `3 * t + 2`.
+///
+/// Can be built using [`Mesh::build_half_edge_structure`].
+///
+/// [`Mesh::build_half_edge_structure`]: crate::mesh::Mesh::build_half_edge_structure
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HalfEdgeMesh {
+    half_edges: Vec<HalfEdge>,
+}
+
+impl HalfEdgeMesh {
+    /// Build a half-edge structure from the index buffer of a triangle mesh
+    ///
+    /// Every three indices form a triangle.
+    ///
+    /// Returns an error, if the mesh is not manifold, or if its triangles are
+    /// not oriented consistently. Either of those means that a directed edge
+    /// occurs more than once.
+    pub fn from_indices(
+        indices: impl IntoIterator<Item = Index>,
+    ) -> Result<Self, NonManifoldError> {
+        let indices = indices.into_iter().collect::<Vec<_>>();
+
+        let mut half_edges = Vec::new();
+        let mut half_edges_by_vertices = BTreeMap::new();
+        let mut non_manifold_edges = BTreeSet::new();
+
+        for (face, triangle) in indices.chunks_exact(3).enumerate() {
+            for i in 0..3 {
+                let origin = triangle[i];
+                let target = triangle[(i + 1) % 3];
+
+                let index = half_edges.len();
+                half_edges.push(HalfEdge {
+                    origin,
+                    face,
+                    next: face * 3 + (i + 1) % 3,
+                    twin: None,
+                });
+
+                if half_edges_by_vertices
+                    .insert([origin, target], index)
+                    .is_some()
+                {
+                    non_manifold_edges.insert([origin, target]);
+                }
+            }
+        }
+
+        if !non_manifold_edges.is_empty() {
+            return Err(NonManifoldError {
+                edges: non_manifold_edges.into_iter().collect(),
+            });
+        }
+
+        for (&[origin, target], &index) in &half_edges_by_vertices {
+            half_edges[index].twin =
+                half_edges_by_vertices.get(&[target, origin]).copied();
+        }
+
+        Ok(Self { half_edges })
+    }
+
+    /// Access the half-edges
+    pub fn half_edges(&self) -> &[HalfEdge] {
+        &self.half_edges
+    }
+
+    /// Access the vertex that the half-edge with the given index points to
+    pub fn target(&self, half_edge: usize) -> Index {
+        self.half_edges[self.half_edges[half_edge].next].origin
+    }
+
+    /// Compute the vertices that are connected to the given vertex by an edge
+    ///
+    /// The returned vertices are sorted by index.
+    pub fn vertex_ring(&self, vertex: Index) -> Vec<Index> {
+        let mut ring = BTreeSet::new();
+
+        for (index, half_edge) in self.half_edges.iter().enumerate() {
+            let target = self.target(index);
+
+            if half_edge.origin == vertex {
+                ring.insert(target);
+            }
+            if target == vertex {
+                ring.insert(half_edge.origin);
+            }
+        }
+
+        ring.into_iter().collect()
+    }
+
+    /// Compute the faces that share an edge with the given face
+    ///
+    /// Faces are identified by the index of their triangle in the mesh.
+    pub fn face_neighbors(&self, face: usize) -> Vec<usize> {
+        self.half_edges[face * 3..face * 3 + 3]
+            .iter()
+            .filter_map(|half_edge| half_edge.twin)
+            .map(|twin| self.half_edges[twin].face)
+            .collect()
+    }
+
+    /// Compute the loops of vertices that bound the mesh
+    ///
+    /// Each loop is made up of the origins of the half-edges that don't have a
+    /// twin, in the order of those half-edges. A closed mesh has no boundary
+    /// loops.
+    pub fn boundary_loops(&self) -> Vec<Vec<Index>> {
+        let mut boundary = self
+            .half_edges
+            .iter()
+            .enumerate()
+            .filter(|(_, half_edge)| half_edge.twin.is_none())
+            .map(|(index, half_edge)| (half_edge.origin, self.target(index)))
+            .collect::<Vec<_>>();
+
+        let mut loops = Vec::new();
+
+        while let Some((origin, mut target)) = boundary.pop() {
+            let mut boundary_loop = vec![origin];
+
+            while target != origin {
+                let Some(next) =
+                    boundary.iter().position(|&(o, _)| o == target)
+                else {
+                    break;
+                };
+
+                let (next_origin, next_target) = boundary.swap_remove(next);
+                boundary_loop.push(next_origin);
+                target = next_target;
+            }
+
+            loops.push(boundary_loop);
+        }
+
+        loops
+    }
+}
+
+/// A half-edge
+///
+/// See [`HalfEdgeMesh`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct HalfEdge {
+    /// The vertex that the half-edge starts at
+    pub origin: Index,
+
+    /// The index of the triangle that the half-edge belongs to
+    pub face: usize,
+
+    /// The index of the next half-edge of the same triangle
+    pub next: usize,
+
+    /// The index of the half-edge that runs along the same edge in the opposite
+    /// direction, if any
+    pub twin: Option<usize>,
+}
+
+/// Error building a [`HalfEdgeMesh`]
+///
+/// Returned by [`HalfEdgeMesh::from_indices`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonManifoldError {
+    /// The directed edges that occur more than once, as pairs of vertices
+    pub edges: Vec<[Index; 2]>,
+}
+
+impl fmt::Display for NonManifoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Mesh is not manifold or not consistently oriented; these edges \
+            occur more than once: {:?}",
+            self.edges
+        )
+    }
+}
+
+impl std::error::Error for NonManifoldError {}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        fixtures::cube_triangles,
+        mesh::{Color, Mesh},
+    };
+
+    use super::NonManifoldError;
+
+    #[test]
+    fn cube() {
+        let mesh = cube_mesh(12);
+        let half_edges = mesh.build_half_edge_structure().unwrap();
+
+        assert_eq!(half_edges.half_edges().len(), 36);
+        assert!(half_edges
+            .half_edges()
+            .iter()
+            .all(|half_edge| half_edge.twin.is_some()));
+        assert!(half_edges.boundary_loops().is_empty());
+
+        // Whether a corner is connected to the corners diagonally across from
+        // it depends on how the sides of the cube are triangulated.
+        let index_of = |point: [f64; 3]| {
+            let point = Point::from(point);
+            mesh.vertices().position(|v| v == point).unwrap() as u32
+        };
+        assert_eq!(half_edges.vertex_ring(index_of([0., 0., 0.])).len(), 6);
+        assert_eq!(half_edges.vertex_ring(index_of([1., 0., 0.])).len(), 4);
+
+        for face in 0..12 {
+            assert_eq!(half_edges.face_neighbors(face).len(), 3);
+        }
+    }
+
+    #[test]
+    fn cube_with_missing_triangle() {
+        let mesh = cube_mesh(11);
+        let half_edges = mesh.build_half_edge_structure().unwrap();
+
+        let boundary_loops = half_edges.boundary_loops();
+        assert_eq!(boundary_loops.len(), 1);
+        assert_eq!(boundary_loops[0].len(), 3);
+    }
+
+    #[test]
+    fn non_manifold() {
+        let mut mesh = Mesh::new();
+        for _ in 0..2 {
+            mesh.push_triangle(
+                [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+                Color::default(),
+            );
+        }
+
+        let result = mesh.build_half_edge_structure();
+        assert_eq!(
+            result,
+            Err(NonManifoldError {
+                edges: vec![[0, 1], [1, 2], [2, 0]],
+            })
+        );
+    }
+
+    fn cube_mesh(num_triangles: usize) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles().take(num_triangles) {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        mesh
+    }
+}
@@ -12,5 +12,9 @@
 #![warn(missing_docs)]
 
 pub mod ext;
+pub mod half_edge;
 pub mod mesh;
 pub mod model;
+
+#[cfg(test)]
+mod fixtures;
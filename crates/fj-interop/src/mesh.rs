@@ -1,8 +1,15 @@
 //! A triangle mesh
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap},
+    hash::Hash,
+    ops,
+};
 
-use fj_math::Point;
+use fj_math::{Plane, Point, PolyChain, Scalar, Segment, Vector};
+
+use crate::half_edge::{HalfEdgeMesh, NonManifoldError};
 
 /// A triangle mesh
 #[derive(Clone, Debug)]
@@ -12,6 +19,9 @@ pub struct Mesh<V> {
 
     indices_by_vertex: HashMap<V, Index>,
     triangles: Vec<Triangle>,
+
+    weld_tolerance: Option<Scalar>,
+    vertices_by_cell: HashMap<[i64; 3], Vec<V>>,
 }
 
 impl<V> Mesh<V>
@@ -69,16 +79,588 @@ where
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.triangles.iter().copied()
     }
+
+    /// Build a half-edge structure that describes the adjacency of the mesh
+    ///
+    /// See [`HalfEdgeMesh::from_indices`].
+    pub fn build_half_edge_structure(
+        &self,
+    ) -> Result<HalfEdgeMesh, NonManifoldError> {
+        HalfEdgeMesh::from_indices(self.indices())
+    }
+
+    /// Determine whether the mesh is watertight
+    ///
+    /// This is the case, if every edge is shared by exactly two triangles, and
+    /// the windings of those triangles are consistent, meaning they traverse
+    /// the edge in opposite directions.
+    ///
+    /// Use [`Mesh::build_half_edge_structure`] to find out where a mesh is not
+    /// watertight.
+    pub fn is_watertight(&self) -> bool {
+        let Ok(half_edges) = self.build_half_edge_structure() else {
+            return false;
+        };
+
+        half_edges
+            .half_edges()
+            .iter()
+            .all(|half_edge| half_edge.twin.is_some())
+    }
 }
 
 impl Mesh<Point<3>> {
+    /// Construct a new instance of `Mesh` that welds near-coincident vertices
+    ///
+    /// By default, vertices are only deduplicated, if they are exactly equal.
+    /// A mesh constructed with this method will also treat vertices as equal,
+    /// if their distance is within `tolerance`. This prevents cracks between
+    /// triangles, that can result from tiny numerical inaccuracies.
+    ///
+    /// If welding vertices collapses a triangle that is added using
+    /// [`Mesh::push_triangle`], that triangle is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `tolerance` is not positive.
+    pub fn with_weld_tolerance(tolerance: impl Into<Scalar>) -> Self {
+        let tolerance = tolerance.into();
+        assert!(tolerance > Scalar::ZERO, "Weld tolerance must be positive");
+
+        Self {
+            weld_tolerance: Some(tolerance),
+            ..Self::default()
+        }
+    }
+
     /// Add a triangle to the mesh
     pub fn push_triangle(
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
     ) {
-        let triangle = triangle.into();
+        self.push_triangle_inner(triangle.into(), None, color);
+    }
+
+    /// Add a triangle with texture coordinates to the mesh
+    ///
+    /// `uv` contains the texture coordinates of each point of the triangle, in
+    /// the same order. Since they are stored per triangle, points that are
+    /// shared between triangles can have different texture coordinates in each
+    /// of them, as is required at texture seams.
+    pub fn push_triangle_with_uv(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        uv: [Point<2>; 3],
+        color: Color,
+    ) {
+        self.push_triangle_inner(triangle.into(), Some(uv), color);
+    }
+
+    /// Flip the normals of the mesh
+    ///
+    /// Reverses the winding of every triangle, which makes its normal point
+    /// the other way. Texture coordinates are reversed along with the points
+    /// of each triangle.
+    pub fn flip_normals(&mut self) {
+        for triangle in &mut self.triangles {
+            triangle.inner = triangle.inner.reverse();
+            triangle.uv = triangle.uv.map(|[a, b, c]| [a, c, b]);
+        }
+
+        for triangle in self.indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+    }
+
+    /// Compute the signed volume enclosed by the mesh
+    ///
+    /// Sums up the signed volumes of the tetrahedra that each triangle forms
+    /// with the origin. For a closed mesh, whose triangles are wound
+    /// counter-clockwise when viewed from outside, this is its volume. If the
+    /// triangles are wound the other way, the result is negative.
+    ///
+    /// The result is only meaningful for a closed mesh. It is also useful for
+    /// parts of a closed mesh, whose contributions add up to the total.
+    pub fn signed_volume(&self) -> Scalar {
+        Scalar::sum_stable(self.triangles.iter().map(|triangle| {
+            let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+            a.dot(&b.cross(&c)) / 6.
+        }))
+    }
+
+    /// Compute the normal of every vertex
+    ///
+    /// The normal of a vertex is the average of the normals of the triangles
+    /// that share it, weighted by their area. Returns one unit-length normal
+    /// per vertex, in the same order as [`Mesh::vertices`].
+    ///
+    /// If the normals of the triangles that share a vertex cancel each other
+    /// out, like where both sides of a thin sheet meet, their average has no
+    /// direction. The normal of the first triangle that shares the vertex is
+    /// used for it instead.
+    ///
+    /// Normals are not stored in the mesh. They follow from the winding of its
+    /// triangles, so they need to be computed again, after any change to those.
+    pub fn vertex_normals(&self) -> Vec<Vector<3>> {
+        let mut sums = vec![Vector::zero(); self.vertices.len()];
+        let mut weights = vec![Scalar::ZERO; self.vertices.len()];
+        let mut fallbacks = vec![None; self.vertices.len()];
+
+        for (triangle, indices) in
+            self.triangles.iter().zip(self.indices.chunks_exact(3))
+        {
+            // The length of the cross product is twice the triangle's area,
+            // which provides the weighting.
+            let [a, b, c] = triangle.inner.points();
+            let normal = (b - a).cross(&(c - a));
+
+            for &index in indices {
+                let index = index as usize;
+
+                sums[index] = sums[index] + normal;
+                weights[index] += normal.magnitude();
+                fallbacks[index].get_or_insert(normal);
+            }
+        }
+
+        sums.into_iter()
+            .zip(weights)
+            .zip(fallbacks)
+            .map(|((sum, weight), fallback)| {
+                // Allow for rounding errors, when comparing the sum to the
+                // magnitudes of the normals it is made up of.
+                let has_direction = sum.magnitude() > weight * 1e-12;
+
+                match (has_direction, fallback) {
+                    (true, _) => sum.normalize(),
+                    (false, Some(fallback)) => fallback.normalize(),
+                    (false, None) => Vector::zero(),
+                }
+            })
+            .collect()
+    }
+
+    /// Snap all vertices of the mesh to a grid
+    ///
+    /// Every coordinate of every vertex is rounded to the nearest multiple of
+    /// `grid`. Vertices that become identical are merged, and triangles that
+    /// collapse as a result are dropped. Since normals are derived from the
+    /// points of a triangle, they reflect the snapped vertices too. Texture
+    /// coordinates are kept as they are.
+    ///
+    /// This makes the mesh independent of tiny numerical differences, like
+    /// those between different platforms. Quantizing a mesh that has already
+    /// been quantized with the same grid doesn't change it.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `grid` is not positive.
+    pub fn quantize(&mut self, grid: impl Into<Scalar>) {
+        let grid = grid.into();
+        assert!(grid > Scalar::ZERO, "Quantization grid must be positive");
+
+        let triangles = std::mem::take(&mut self.triangles);
+        *self = Self {
+            weld_tolerance: self.weld_tolerance,
+            ..Self::default()
+        };
+
+        for triangle in triangles {
+            let points = triangle
+                .inner
+                .points()
+                .map(|point| point.snap_to_grid(grid));
+            let Ok(inner) = fj_math::Triangle::from_points(points) else {
+                // Snapping the vertices has collapsed the triangle.
+                continue;
+            };
+
+            self.push_triangle_inner(inner, triangle.uv, triangle.color);
+        }
+    }
+
+    /// Split the mesh along a plane
+    ///
+    /// Returns the part of the mesh that is behind the plane, meaning on the
+    /// side its normal points away from, and the outline of the cut.
+    /// Triangles that straddle the plane are clipped, and the remaining part of
+    /// them is triangulated again. The returned mesh has no texture
+    /// coordinates.
+    ///
+    /// The segments of the outline are oriented such that, for a closed mesh
+    /// whose triangles wind counter-clockwise when viewed from the outside,
+    /// they wind counter-clockwise around the cut, when viewed from the
+    /// direction the plane's normal points to. Segments that share a point,
+    /// share it exactly.
+    pub fn split_by_plane(&self, plane: &Plane) -> (Self, Vec<Segment<3>>) {
+        let mut mesh = Self {
+            weld_tolerance: self.weld_tolerance,
+            ..Self::default()
+        };
+        let mut outline = Vec::new();
+
+        for triangle in &self.triangles {
+            let (clipped, segment) = cut_triangle(triangle.inner, plane);
+
+            for k in 1..clipped.len().saturating_sub(1) {
+                let points = [clipped[0], clipped[k], clipped[k + 1]];
+                let Ok(inner) = fj_math::Triangle::from_points(points) else {
+                    // The plane goes through a vertex of the triangle.
+                    continue;
+                };
+
+                mesh.push_triangle(inner, triangle.color);
+            }
+
+            outline.extend(segment);
+        }
+
+        (mesh, outline)
+    }
+
+    /// Slice the mesh into a stack of parallel cross-sections
+    ///
+    /// The layers are perpendicular to `axis`, and `spacing` apart from each
+    /// other. They cover the whole extent of the mesh along the axis, with the
+    /// first and last layers half a spacing away from the mesh's boundary.
+    ///
+    /// The triangles are sorted by height once. Each layer only considers the
+    /// triangles that actually span its height, which is much cheaper than
+    /// cutting the whole mesh for every layer.
+    ///
+    /// Contours are stitched together based on the edges of the mesh that they
+    /// cross. Triangles that share an edge must refer to the exact same points
+    /// for that to work. Use [`Mesh::with_weld_tolerance`], if that's not
+    /// guaranteed.
+    ///
+    /// The coordinate system of each layer's plane is derived from the axis,
+    /// using [`Vector::orthonormal_basis`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `axis` has zero length, or if `spacing` is not positive.
+    pub fn slice_stack(
+        &self,
+        axis: impl Into<Vector<3>>,
+        spacing: impl Into<Scalar>,
+    ) -> Vec<SliceLayer> {
+        let axis = axis.into();
+        let spacing = spacing.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Slicing axis has zero length"
+        );
+        assert!(spacing > Scalar::ZERO, "Slice spacing must be positive");
+
+        let normal = axis.normalize();
+        let (u, v) = normal.orthonormal_basis();
+
+        let mut triangles = self
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let points = triangle.inner.points();
+                let heights = points.map(|point| point.coords.dot(&normal));
+                SliceTriangle { points, heights }
+            })
+            .collect::<Vec<_>>();
+        triangles.sort_by_key(SliceTriangle::min_height);
+
+        let Some(min) = triangles.first().map(SliceTriangle::min_height) else {
+            return Vec::new();
+        };
+        let max = triangles
+            .iter()
+            .map(SliceTriangle::max_height)
+            .max()
+            .unwrap_or(min);
+
+        let mut layers = Vec::new();
+        let mut active = Vec::new();
+        let mut next_triangle = 0;
+
+        for i in 0.. {
+            let height = min + spacing * (f64::from(i) + 0.5);
+            if height >= max {
+                break;
+            }
+
+            // Layers are processed in ascending order, so triangles only ever
+            // enter the active set once, and leave it once.
+            while let Some(triangle) = triangles.get(next_triangle) {
+                if triangle.min_height() > height {
+                    break;
+                }
+                active.push(triangle);
+                next_triangle += 1;
+            }
+            active.retain(|triangle| triangle.max_height() >= height);
+
+            let segments = active
+                .iter()
+                .filter_map(|triangle| triangle.intersect(height))
+                .collect::<Vec<_>>();
+
+            let plane =
+                Plane::from_parametric(Point::origin() + normal * height, u, v);
+            let (contours, open_contours) = stitch(segments, &plane);
+
+            layers.push(SliceLayer {
+                height,
+                plane,
+                contours,
+                open_contours,
+            });
+        }
+
+        layers
+    }
+
+    /// Slice the mesh into horizontal layers
+    ///
+    /// Like [`Mesh::slice_stack`], with the layers parallel to the xy-plane,
+    /// and `layer_height` apart from each other.
+    ///
+    /// Returns the closed contours of each layer, in xy-coordinates. They wind
+    /// counter-clockwise around the inside of the mesh, when viewed from above.
+    /// Contours that can't be closed, which can happen if the mesh has cracks,
+    /// are omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `layer_height` is not positive.
+    pub fn slice_layers(
+        &self,
+        layer_height: impl Into<Scalar>,
+    ) -> Vec<Vec<PolyChain<2>>> {
+        self.slice_stack(Vector::unit_z(), layer_height)
+            .into_iter()
+            .map(|layer| {
+                layer
+                    .contours
+                    .into_iter()
+                    .map(|contour| PolyChain::from_points(contour).close())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Group adjacent triangles with the same orientation into polygons
+    ///
+    /// Triangles that share an edge are put into the same group, if the angle
+    /// between their normal and the normal of the group's first triangle is at
+    /// most `angle_tolerance`, in radians. Each group is returned as a
+    /// [`PlanarPolygon`], with its boundary split into an exterior loop and
+    /// any number of interior loops (holes).
+    ///
+    /// Triangles are adjacent, if their vertices are the same. Use
+    /// [`Mesh::with_weld_tolerance`] or [`Mesh::quantize`] to make sure that is
+    /// the case for triangles that are only nearly adjacent.
+    pub fn extract_planar_polygons(
+        &self,
+        angle_tolerance: impl Into<Scalar>,
+    ) -> Vec<PlanarPolygon> {
+        let min_cos = angle_tolerance.into().into_f64().cos();
+
+        let triangles = self
+            .indices
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect::<Vec<_>>();
+        let normals = self
+            .triangles
+            .iter()
+            .map(|triangle| triangle.inner.normal())
+            .collect::<Vec<_>>();
+
+        let triangles_by_edge = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(t, &[a, b, c])| [([a, b], t), ([b, c], t), ([c, a], t)])
+            .collect::<BTreeMap<_, _>>();
+
+        let mut group_by_triangle = vec![None; triangles.len()];
+        let mut polygons = Vec::new();
+
+        for seed in 0..triangles.len() {
+            if group_by_triangle[seed].is_some() {
+                continue;
+            }
+
+            let group = polygons.len();
+            let normal = normals[seed];
+
+            // Flood-fill the group, starting from the seed triangle.
+            let mut group_triangles = Vec::new();
+            let mut pending = vec![seed];
+            group_by_triangle[seed] = Some(group);
+
+            while let Some(t) = pending.pop() {
+                group_triangles.push(t);
+
+                let [a, b, c] = triangles[t];
+                for [origin, target] in [[a, b], [b, c], [c, a]] {
+                    let Some(&neighbor) =
+                        triangles_by_edge.get(&[target, origin])
+                    else {
+                        continue;
+                    };
+
+                    if group_by_triangle[neighbor].is_some()
+                        || normals[neighbor].dot(&normal).into_f64() < min_cos
+                    {
+                        continue;
+                    }
+
+                    group_by_triangle[neighbor] = Some(group);
+                    pending.push(neighbor);
+                }
+            }
+            group_triangles.sort();
+
+            // The boundary of the group consists of those edges, whose twin is
+            // not part of the group.
+            let mut boundary = group_triangles
+                .iter()
+                .flat_map(|&t| {
+                    let [a, b, c] = triangles[t];
+                    [[a, b], [b, c], [c, a]]
+                })
+                .filter(|&[origin, target]| {
+                    triangles_by_edge
+                        .get(&[target, origin])
+                        .and_then(|&neighbor| group_by_triangle[neighbor])
+                        != Some(group)
+                })
+                .collect::<Vec<_>>();
+            boundary.sort();
+
+            let mut loops = Vec::new();
+            while !boundary.is_empty() {
+                let [origin, mut target] = boundary.remove(0);
+                let mut boundary_loop = vec![origin];
+
+                while target != origin {
+                    let Some(next) =
+                        boundary.iter().position(|&[o, _]| o == target)
+                    else {
+                        break;
+                    };
+
+                    let [next_origin, next_target] = boundary.remove(next);
+                    boundary_loop.push(next_origin);
+                    target = next_target;
+                }
+
+                loops.push(boundary_loop);
+            }
+
+            // The exterior loop winds counter-clockwise around the normal,
+            // holes wind clockwise. The exterior also encloses the largest
+            // area.
+            let loop_area = |boundary_loop: &Vec<Index>| {
+                let points = boundary_loop
+                    .iter()
+                    .map(|&index| self.vertices[index as usize])
+                    .collect::<Vec<_>>();
+                let area = Vector::sum_stable((1..points.len()).map(|i| {
+                    let j = (i + 1) % points.len();
+                    (points[i] - points[0]).cross(&(points[j] - points[0]))
+                }));
+                area.dot(&normal)
+            };
+            let exterior = loops
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, boundary_loop)| loop_area(boundary_loop))
+                .map(|(i, _)| i)
+                .expect("Group of triangles must have a boundary");
+            let exterior = loops.remove(exterior);
+
+            let origin = self.vertices[exterior[0] as usize];
+            let (u, v) = normal.orthonormal_basis();
+
+            polygons.push(PlanarPolygon {
+                plane: Plane::from_parametric(origin, u, v),
+                exterior,
+                interiors: loops,
+                triangles: group_triangles,
+            });
+        }
+
+        polygons
+    }
+
+    /// Reduce the number of triangles of the mesh
+    ///
+    /// Repeatedly collapses the edge whose removal changes the shape of the
+    /// mesh the least, until the mesh has at most `target_triangles` triangles,
+    /// or no more edges can be collapsed. The change in shape is estimated
+    /// using quadric error metrics, as described by Garland and Heckbert.
+    ///
+    /// Vertices on the boundary of the mesh are never moved or removed, so the
+    /// boundary is preserved exactly. An edge is not collapsed, if that would
+    /// make the mesh non-manifold, or flip any of its triangles. The remaining
+    /// triangles keep their color. Texture coordinates are dropped, as they
+    /// don't apply to moved vertices.
+    ///
+    /// The result only depends on the mesh and `target_triangles`, so
+    /// decimating the same mesh again produces the same result.
+    ///
+    /// Returns an error, if the mesh is not manifold. See
+    /// [`HalfEdgeMesh::from_indices`].
+    pub fn decimate(
+        &self,
+        target_triangles: usize,
+    ) -> Result<Self, NonManifoldError> {
+        let half_edges = self.build_half_edge_structure()?;
+
+        let mut decimation = Decimation::new(
+            self.vertices.clone(),
+            self.indices
+                .chunks_exact(3)
+                .map(|triangle| [0, 1, 2].map(|i| triangle[i] as usize))
+                .collect(),
+        );
+
+        for (index, half_edge) in half_edges.half_edges().iter().enumerate() {
+            if half_edge.twin.is_none() {
+                for vertex in [half_edge.origin, half_edges.target(index)] {
+                    decimation.is_locked[vertex as usize] = true;
+                }
+            }
+        }
+
+        decimation.run(target_triangles);
+
+        let mut mesh = Self::new();
+        for (i, triangle) in decimation.triangles.iter().enumerate() {
+            if !decimation.is_alive[i] {
+                continue;
+            }
+
+            let points = triangle.map(|vertex| decimation.positions[vertex]);
+            if let Ok(triangle) = fj_math::Triangle::from_points(points) {
+                mesh.push_triangle(triangle, self.triangles[i].color);
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    fn push_triangle_inner(
+        &mut self,
+        triangle: fj_math::Triangle<3>,
+        uv: Option<[Point<2>; 3]>,
+        color: Color,
+    ) {
+        let points = triangle.points().map(|point| self.weld(point));
+        let Ok(triangle) = fj_math::Triangle::from_points(points) else {
+            // Welding the vertices has collapsed the triangle.
+            return;
+        };
 
         for point in triangle.points() {
             self.push_vertex(point);
@@ -86,9 +668,553 @@ impl Mesh<Point<3>> {
 
         self.triangles.push(Triangle {
             inner: triangle,
+            uv,
             color,
         });
     }
+
+    fn weld(&mut self, point: Point<3>) -> Point<3> {
+        let Some(tolerance) = self.weld_tolerance else {
+            return point;
+        };
+
+        // Vertices are sorted into cells with the size of the tolerance. Any
+        // vertex that is within the tolerance of `point` must be in the same
+        // cell, or one of the neighboring ones.
+        let cell = point
+            .coords
+            .components
+            .map(|coord| (coord / tolerance).floor().into_f64() as i64);
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let neighbor = [cell[0] + x, cell[1] + y, cell[2] + z];
+
+                    let Some(vertices) = self.vertices_by_cell.get(&neighbor)
+                    else {
+                        continue;
+                    };
+
+                    for vertex in vertices {
+                        if vertex.distance_to(&point) <= tolerance {
+                            return *vertex;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.vertices_by_cell.entry(cell).or_default().push(point);
+        point
+    }
+}
+
+/// Cut a triangle along a plane
+///
+/// Returns the polygon that remains of the triangle behind the plane, and the
+/// segment where the plane crosses the triangle, if any.
+fn cut_triangle(
+    triangle: fj_math::Triangle<3>,
+    plane: &Plane,
+) -> (Vec<Point<3>>, Option<Segment<3>>) {
+    let points = triangle.points();
+    let distances = points.map(|point| plane.signed_distance_to_point(point));
+
+    // Points on the plane are considered to be in front of it. That way, every
+    // edge of the mesh is either crossed by the plane or not, regardless of
+    // which triangle it is looked at from.
+    let behind = distances.map(|distance| distance < Scalar::ZERO);
+
+    let mut clipped = Vec::new();
+    let mut start = None;
+    let mut end = None;
+
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+
+        if behind[i] {
+            clipped.push(points[i]);
+        }
+        if behind[i] == behind[j] {
+            continue;
+        }
+
+        let crossing =
+            crossing((points[i], distances[i]), (points[j], distances[j]));
+        clipped.push(crossing);
+
+        if behind[i] {
+            end = Some(crossing);
+        } else {
+            start = Some(crossing);
+        }
+    }
+
+    let segment = match (start, end) {
+        (Some(start), Some(end)) if start != end => {
+            Some(Segment::from_points([start, end]))
+        }
+        _ => None,
+    };
+
+    (clipped, segment)
+}
+
+/// Compute where the plane crosses the edge between two points
+///
+/// Takes each point along with its signed distance from the plane. The result
+/// does not depend on the order of the points, so triangles that share an edge
+/// compute the exact same crossing.
+fn crossing(a: (Point<3>, Scalar), b: (Point<3>, Scalar)) -> Point<3> {
+    let [(p, dp), (q, dq)] = if a.0 <= b.0 { [a, b] } else { [b, a] };
+
+    if dp == Scalar::ZERO {
+        return p;
+    }
+    if dq == Scalar::ZERO {
+        return q;
+    }
+
+    let t = dp / (dp - dq);
+    p + (q - p) * t
+}
+
+/// A triangle of a sliced mesh, with the heights of its points precomputed
+///
+/// See [`Mesh::slice_stack`].
+struct SliceTriangle {
+    points: [Point<3>; 3],
+    heights: [Scalar; 3],
+}
+
+impl SliceTriangle {
+    fn min_height(&self) -> Scalar {
+        let [a, b, c] = self.heights;
+        a.min(b).min(c)
+    }
+
+    fn max_height(&self) -> Scalar {
+        let [a, b, c] = self.heights;
+        a.max(b).max(c)
+    }
+
+    /// Intersect the triangle with the plane at the given height
+    ///
+    /// Points that lie exactly on the plane are treated as being above it.
+    /// That way, every edge of the mesh is either crossed by the plane or not,
+    /// regardless of which triangle it is looked at from.
+    fn intersect(&self, height: Scalar) -> Option<SliceSegment> {
+        let distances = self.heights.map(|h| h - height);
+        let above = distances.map(|distance| distance >= Scalar::ZERO);
+
+        let mut start = None;
+        let mut end = None;
+
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            if above[i] == above[j] {
+                continue;
+            }
+
+            let a = (self.points[i], distances[i]);
+            let b = (self.points[j], distances[j]);
+
+            // Identify the crossing by the edge it is on, in a way that
+            // doesn't depend on the direction of the edge. Then both
+            // triangles that share the edge agree on it.
+            let edge = if a.0 <= b.0 { [a.0, b.0] } else { [b.0, a.0] };
+            let crossing = (edge, crossing(a, b));
+
+            // With the triangle winding counter-clockwise around its outward
+            // normal, this makes the contour wind counter-clockwise around the
+            // inside of the mesh, as seen from above.
+            if above[i] {
+                start = Some(crossing);
+            } else {
+                end = Some(crossing);
+            }
+        }
+
+        let (start, start_point) = start?;
+        let (end, _) = end?;
+
+        Some(SliceSegment {
+            start,
+            end,
+            start_point,
+        })
+    }
+}
+
+/// A segment of a contour, identified by the mesh edges it connects
+///
+/// Keying segments by mesh edge, rather than by point, keeps them apart when
+/// the plane goes through a vertex of the mesh. Multiple segments can start at
+/// that vertex, but each of them starts on a different edge.
+struct SliceSegment {
+    start: [Point<3>; 2],
+    end: [Point<3>; 2],
+    start_point: Point<3>,
+}
+
+type Contours = Vec<Vec<Point<2>>>;
+
+/// Stitch segments into contours, returning closed and open contours
+fn stitch(segments: Vec<SliceSegment>, plane: &Plane) -> (Contours, Contours) {
+    let ends = segments
+        .iter()
+        .map(|segment| segment.end)
+        .collect::<BTreeSet<_>>();
+
+    let mut segments_by_start = BTreeMap::new();
+    let mut chain_starts = Vec::new();
+    for segment in segments {
+        if !ends.contains(&segment.start) {
+            chain_starts.push(segment.start);
+        }
+        segments_by_start.insert(segment.start, segment);
+    }
+
+    let mut contours = Vec::new();
+    let mut open_contours = Vec::new();
+
+    // Any chain that doesn't start at the end of another segment is open.
+    // Handle those first, so what remains are closed contours.
+    for start in chain_starts {
+        let (points, _) = follow_chain(start, &mut segments_by_start, plane);
+        open_contours.push(points);
+    }
+
+    while let Some(&start) = segments_by_start.keys().next() {
+        let (points, end) = follow_chain(start, &mut segments_by_start, plane);
+
+        if end == start {
+            contours.push(points);
+        } else {
+            open_contours.push(points);
+        }
+    }
+
+    (contours, open_contours)
+}
+
+/// Follow a chain of segments, removing them from the map
+///
+/// Returns the points of the chain, and the mesh edge where it ended.
+fn follow_chain(
+    start: [Point<3>; 2],
+    segments_by_start: &mut BTreeMap<[Point<3>; 2], SliceSegment>,
+    plane: &Plane,
+) -> (Vec<Point<2>>, [Point<3>; 2]) {
+    let mut points: Vec<Point<2>> = Vec::new();
+    let mut current = start;
+
+    while let Some(segment) = segments_by_start.remove(&current) {
+        let point = plane.project_point(segment.start_point);
+
+        // If the plane goes through a vertex of the mesh, consecutive segments
+        // can start at the same point.
+        if points.last() != Some(&point) {
+            points.push(point);
+        }
+
+        current = segment.end;
+    }
+
+    // The same goes for the end of a closed contour.
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    (points, current)
+}
+
+/// The state of a decimation
+///
+/// See [`Mesh::decimate`].
+struct Decimation {
+    positions: Vec<Point<3>>,
+    triangles: Vec<[usize; 3]>,
+    is_alive: Vec<bool>,
+    num_alive: usize,
+
+    is_locked: Vec<bool>,
+    is_removed: Vec<bool>,
+    quadrics: Vec<Quadric>,
+    triangles_by_vertex: Vec<BTreeSet<usize>>,
+
+    // Candidate edges, ordered by the cost of collapsing them. Entries might
+    // be outdated, so they need to be checked before being used.
+    queue: BinaryHeap<Reverse<(Scalar, usize, usize)>>,
+}
+
+impl Decimation {
+    fn new(positions: Vec<Point<3>>, triangles: Vec<[usize; 3]>) -> Self {
+        let mut quadrics = vec![Quadric::default(); positions.len()];
+        let mut triangles_by_vertex = vec![BTreeSet::new(); positions.len()];
+
+        for (t, triangle) in triangles.iter().enumerate() {
+            let quadric = Quadric::from_triangle(
+                triangle.map(|vertex| positions[vertex]),
+            );
+
+            for &vertex in triangle {
+                quadrics[vertex] = quadrics[vertex] + quadric;
+                triangles_by_vertex[vertex].insert(t);
+            }
+        }
+
+        Self {
+            is_alive: vec![true; triangles.len()],
+            num_alive: triangles.len(),
+            is_locked: vec![false; positions.len()],
+            is_removed: vec![false; positions.len()],
+            quadrics,
+            triangles_by_vertex,
+            queue: BinaryHeap::new(),
+            positions,
+            triangles,
+        }
+    }
+
+    fn run(&mut self, target_triangles: usize) {
+        let edges = self
+            .triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [[a, b], [b, c], [c, a]])
+            .map(|[a, b]| [a.min(b), a.max(b)])
+            .collect::<BTreeSet<_>>();
+        for [a, b] in edges {
+            self.push_candidate(a, b);
+        }
+
+        while self.num_alive > target_triangles {
+            let Some(Reverse((cost, a, b))) = self.queue.pop() else {
+                break;
+            };
+
+            if self.is_removed[a]
+                || self.is_removed[b]
+                || !self.neighbors(a).contains(&b)
+            {
+                continue;
+            }
+            let Some(collapse) = self.collapse(a, b) else {
+                continue;
+            };
+            if collapse.cost != cost {
+                // The vertices have changed, since this entry was added.
+                self.queue.push(Reverse((collapse.cost, a, b)));
+                continue;
+            }
+
+            if self.is_valid(&collapse) {
+                self.apply(collapse);
+            }
+        }
+    }
+
+    fn neighbors(&self, vertex: usize) -> BTreeSet<usize> {
+        let mut neighbors = self.triangles_by_vertex[vertex]
+            .iter()
+            .flat_map(|&t| self.triangles[t])
+            .collect::<BTreeSet<_>>();
+        neighbors.remove(&vertex);
+        neighbors
+    }
+
+    fn push_candidate(&mut self, a: usize, b: usize) {
+        let [a, b] = [a.min(b), a.max(b)];
+
+        if let Some(collapse) = self.collapse(a, b) {
+            self.queue.push(Reverse((collapse.cost, a, b)));
+        }
+    }
+
+    /// Determine the best way to collapse an edge, if it can be collapsed
+    fn collapse(&self, a: usize, b: usize) -> Option<Collapse> {
+        let quadric = self.quadrics[a] + self.quadrics[b];
+
+        // Locked vertices must stay where they are, so any other vertex can
+        // only be collapsed into them.
+        let options = match (self.is_locked[a], self.is_locked[b]) {
+            (true, true) => return None,
+            (true, false) => vec![(a, b, self.positions[a])],
+            (false, true) => vec![(b, a, self.positions[b])],
+            (false, false) => {
+                let midpoint = self.positions[a]
+                    + (self.positions[b] - self.positions[a]) / 2.;
+                vec![
+                    (b, a, self.positions[b]),
+                    (a, b, self.positions[a]),
+                    (a, b, midpoint),
+                ]
+            }
+        };
+
+        options
+            .into_iter()
+            .map(|(keep, remove, position)| Collapse {
+                cost: quadric.error(&position),
+                keep,
+                remove,
+                position,
+            })
+            .min_by_key(|collapse| collapse.cost)
+    }
+
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        let [a, b] = [collapse.keep, collapse.remove];
+        let shared = self.shared_triangles(a, b);
+
+        // Vertices that are connected to both ends of the edge must belong to
+        // the triangles that are removed along with it. Otherwise, the
+        // collapse would create edges that are shared by more than two
+        // triangles.
+        let opposite = shared
+            .iter()
+            .flat_map(|&t| self.triangles[t])
+            .filter(|&vertex| vertex != a && vertex != b)
+            .collect::<BTreeSet<_>>();
+        let common = self
+            .neighbors(a)
+            .intersection(&self.neighbors(b))
+            .copied()
+            .collect::<BTreeSet<_>>();
+        if common != opposite {
+            return false;
+        }
+
+        // None of the remaining triangles must flip.
+        [a, b].into_iter().all(|vertex| {
+            self.triangles_by_vertex[vertex]
+                .difference(&shared)
+                .all(|&t| {
+                    let triangle = self.triangles[t];
+                    let before = triangle.map(|v| self.positions[v]);
+                    let after = triangle.map(|v| {
+                        if v == a || v == b {
+                            collapse.position
+                        } else {
+                            self.positions[v]
+                        }
+                    });
+
+                    let [before, after] = [before, after]
+                        .map(|[p, q, r]| (q - p).cross(&(r - p)));
+                    before.dot(&after) > Scalar::ZERO
+                })
+        })
+    }
+
+    fn apply(&mut self, collapse: Collapse) {
+        let Collapse {
+            keep,
+            remove,
+            position,
+            ..
+        } = collapse;
+
+        for t in self.shared_triangles(keep, remove) {
+            self.is_alive[t] = false;
+            self.num_alive -= 1;
+
+            for vertex in self.triangles[t] {
+                self.triangles_by_vertex[vertex].remove(&t);
+            }
+        }
+
+        for t in std::mem::take(&mut self.triangles_by_vertex[remove]) {
+            for vertex in &mut self.triangles[t] {
+                if *vertex == remove {
+                    *vertex = keep;
+                }
+            }
+            self.triangles_by_vertex[keep].insert(t);
+        }
+
+        self.is_removed[remove] = true;
+        self.positions[keep] = position;
+        self.quadrics[keep] = self.quadrics[keep] + self.quadrics[remove];
+
+        for neighbor in self.neighbors(keep) {
+            self.push_candidate(keep, neighbor);
+        }
+    }
+
+    fn shared_triangles(&self, a: usize, b: usize) -> BTreeSet<usize> {
+        self.triangles_by_vertex[a]
+            .intersection(&self.triangles_by_vertex[b])
+            .copied()
+            .collect()
+    }
+}
+
+/// A possible collapse of an edge into a single vertex
+struct Collapse {
+    cost: Scalar,
+    keep: usize,
+    remove: usize,
+    position: Point<3>,
+}
+
+/// The quadric error metric of a vertex
+///
+/// Stores the upper triangle of a symmetric 4x4 matrix, which describes the
+/// sum of squared distances to a set of planes.
+#[derive(Clone, Copy)]
+struct Quadric([Scalar; 10]);
+
+impl Quadric {
+    fn from_triangle([a, b, c]: [Point<3>; 3]) -> Self {
+        let normal = (b - a).cross(&(c - a));
+        if normal.magnitude() == Scalar::ZERO {
+            return Self::default();
+        }
+
+        let [x, y, z] = normal.normalize().components;
+        let w = -normal.normalize().dot(&a.coords);
+
+        Self([
+            x * x,
+            x * y,
+            x * z,
+            x * w,
+            y * y,
+            y * z,
+            y * w,
+            z * z,
+            z * w,
+            w * w,
+        ])
+    }
+
+    fn error(&self, point: &Point<3>) -> Scalar {
+        let [xx, xy, xz, xw, yy, yz, yw, zz, zw, ww] = self.0;
+        let [x, y, z] = point.coords.components;
+
+        xx * x * x
+            + yy * y * y
+            + zz * z * z
+            + (xy * x * y + xz * x * z + xw * x + yz * y * z + yw * y + zw * z)
+                * 2.
+            + ww
+    }
+}
+
+impl Default for Quadric {
+    fn default() -> Self {
+        Self([Scalar::ZERO; 10])
+    }
+}
+
+impl ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self([0, 1, 2, 3, 4, 5, 6, 7, 8, 9].map(|i| self.0[i] + other.0[i]))
+    }
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -100,6 +1226,8 @@ impl<V> Default for Mesh<V> {
             indices: Vec::default(),
             indices_by_vertex: HashMap::default(),
             triangles: Vec::default(),
+            weld_tolerance: None,
+            vertices_by_cell: HashMap::default(),
         }
     }
 }
@@ -109,16 +1237,78 @@ pub type Index = u32;
 
 /// A triangle
 ///
-/// Extension of [`fj_math::Triangle`] that also includes a color.
+/// Extension of [`fj_math::Triangle`] that also includes a color, and
+/// optionally texture coordinates.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Triangle {
     /// The points of the triangle
     pub inner: fj_math::Triangle<3>,
 
+    /// The texture coordinates of the points of the triangle, if available
+    ///
+    /// See [`Mesh::push_triangle_with_uv`].
+    pub uv: Option<[Point<2>; 3]>,
+
     /// The color of the triangle
     pub color: Color,
 }
 
+/// A planar polygon, made up of adjacent triangles of a mesh
+///
+/// Returned by [`Mesh::extract_planar_polygons`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanarPolygon {
+    /// The plane that the polygon is in
+    ///
+    /// The plane's normal is the normal of the polygon's first triangle.
+    pub plane: Plane,
+
+    /// The exterior boundary of the polygon, as indices into the mesh vertices
+    ///
+    /// Winds counter-clockwise, as seen from the direction that the normal of
+    /// the plane points to.
+    pub exterior: Vec<Index>,
+
+    /// The holes in the polygon, as indices into the mesh vertices
+    ///
+    /// Wind clockwise, as seen from the direction that the normal of the plane
+    /// points to.
+    pub interiors: Vec<Vec<Index>>,
+
+    /// The triangles that make up the polygon, as indices into the triangles
+    /// of the mesh
+    pub triangles: Vec<usize>,
+}
+
+/// A single layer of a stack of cross-sections
+///
+/// Returned by [`Mesh::slice_stack`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SliceLayer {
+    /// The height of the layer along the slicing axis
+    pub height: Scalar,
+
+    /// The plane that the layer lies in
+    ///
+    /// The points of all contours are in the coordinates of this plane.
+    pub plane: Plane,
+
+    /// The closed contours of the layer
+    ///
+    /// Each contour is a closed polyline. The last point connects back to the
+    /// first, without being repeated. Contours wind counter-clockwise around
+    /// the inside of the mesh, when viewed from the direction the slicing axis
+    /// points to.
+    pub contours: Vec<Vec<Point<2>>>,
+
+    /// Contours of the layer that could not be closed
+    ///
+    /// This happens, if the mesh has cracks, for example due to numerical
+    /// inaccuracies. These contours are reported here, instead of being closed
+    /// silently.
+    pub open_contours: Vec<Vec<Point<2>>>,
+}
+
 /// RGBA color
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Color(pub [u8; 4]);
@@ -129,3 +1319,498 @@ impl Default for Color {
         Self([255, 0, 0, 255])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_abs_diff, Plane, Point, Scalar, Transform, Vector};
+
+    use crate::{fixtures::cube_triangles, half_edge::NonManifoldError};
+
+    use super::{Color, Mesh};
+
+    #[test]
+    fn weld_tolerance() {
+        let a = Point::from([0., 0., 0.]);
+        let b = Point::from([1., 0., 0.]);
+        let c = Point::from([0., 1., 0.]);
+        let d = Point::from([1., 1., 0.]);
+
+        let offset = Point::from([1e-7, -1e-7, 0.]).coords;
+        let triangles = [[a, b, c], [b + offset, d, c + offset]];
+
+        let mut mesh = Mesh::new();
+        for triangle in triangles {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        assert_eq!(mesh.vertices().count(), 6);
+
+        let mut mesh = Mesh::with_weld_tolerance(1e-5);
+        for triangle in triangles {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.indices().collect::<Vec<_>>(), [0, 1, 2, 1, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weld_tolerance_zero() {
+        Mesh::with_weld_tolerance(0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weld_tolerance_negative() {
+        Mesh::with_weld_tolerance(-1e-5);
+    }
+
+    #[test]
+    fn quantize() {
+        let a = Point::from([0., 0., 0.]);
+        let b = Point::from([1., 0., 0.]);
+        let c = Point::from([0., 1., 0.]);
+        let d = Point::from([1., 1., 0.]);
+
+        let offset = Point::from([1e-9, -1e-9, 0.]).coords;
+        let degenerate = [a, a + offset, b];
+
+        let mut mesh = Mesh::new();
+        for triangle in [[a, b, c], [b + offset, d, c + offset], degenerate] {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        assert_eq!(mesh.vertices().count(), 7);
+
+        mesh.quantize(1e-6);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.triangles().count(), 2);
+        assert_eq!(mesh.indices().collect::<Vec<_>>(), [0, 1, 2, 1, 3, 2]);
+    }
+
+    #[test]
+    fn quantize_keeps_uv() {
+        let points = [[0., 0., 0.], [1. + 1e-9, 0., 0.], [0., 1., 0.]];
+        let uv = [[0., 0.], [1., 0.], [0., 1.]].map(Point::from);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle_with_uv(points, uv, Color::default());
+        mesh.push_triangle(
+            [[1., 1., 0.], [0., 1., 0.], [1., 0., 0.]],
+            Color::default(),
+        );
+
+        mesh.quantize(1e-6);
+        let uvs = mesh
+            .triangles()
+            .map(|triangle| triangle.uv)
+            .collect::<Vec<_>>();
+        assert_eq!(uvs, [Some(uv), None]);
+    }
+
+    #[test]
+    fn flip_normals() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        assert_abs_diff!(mesh.signed_volume(), Scalar::ONE);
+
+        mesh.flip_normals();
+        assert_abs_diff!(mesh.signed_volume(), -Scalar::ONE);
+        for triangle in mesh.triangles() {
+            // The bottom of the cube now faces up.
+            if triangle.inner.points().iter().all(|p| p.z == Scalar::ZERO) {
+                assert_abs_diff!(triangle.inner.normal(), Vector::unit_z());
+            }
+        }
+
+        for normal in mesh.vertex_normals() {
+            assert_abs_diff!(normal.magnitude(), Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn vertex_normals() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        let center = Point::from([0.5, 0.5, 0.5]);
+        let normals = mesh.vertex_normals();
+        assert_eq!(normals.len(), 8);
+
+        // Every vertex of the cube is shared by one or two triangles of each of
+        // its three adjacent sides, so the normal doesn't exactly point away
+        // from the center. But it points outward.
+        for (vertex, normal) in mesh.vertices().zip(normals) {
+            assert_abs_diff!(normal.magnitude(), Scalar::ONE);
+            assert!(normal.dot(&(vertex - center)) > Scalar::ZERO);
+        }
+    }
+
+    #[test]
+    fn vertex_normals_of_opposing_triangles() {
+        let points = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+
+        // A triangle that is visible from both sides. The normals of both
+        // triangles cancel each other out at every vertex.
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(points, Color::default());
+        mesh.push_triangle([points[0], points[2], points[1]], Color::default());
+
+        let normals = mesh.vertex_normals();
+        assert_eq!(normals.len(), 3);
+        for normal in normals {
+            assert_eq!(normal, Vector::unit_z());
+        }
+    }
+
+    #[test]
+    fn split_by_plane() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        let plane = Plane::from_parametric(
+            Point::from([0., 0., 0.5]),
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+        let (lower, outline) = mesh.split_by_plane(&plane);
+
+        for point in lower.vertices() {
+            assert!(point.z <= Scalar::from(0.5));
+        }
+        assert!(lower.contains_triangle([
+            [0., 0., 0.],
+            [0., 1., 0.],
+            [1., 1., 0.]
+        ]));
+
+        // Each side of the cube consists of two triangles, which are both cut.
+        assert_eq!(outline.len(), 8);
+
+        // The outline is a closed square, that goes around the cut once.
+        let mut segment = outline[0];
+        let mut length = Scalar::ZERO;
+        for _ in 0..outline.len() {
+            let [start, end] = segment.points();
+            for point in [start, end] {
+                assert_eq!(point.z, Scalar::from(0.5));
+                assert!(
+                    point.x == Scalar::ZERO
+                        || point.x == Scalar::ONE
+                        || point.y == Scalar::ZERO
+                        || point.y == Scalar::ONE
+                );
+            }
+            length += start.distance_to(&end);
+
+            let next = outline
+                .iter()
+                .filter(|next| next.points()[0] == end)
+                .collect::<Vec<_>>();
+            assert_eq!(next.len(), 1);
+            segment = *next[0];
+        }
+        assert_eq!(segment, outline[0]);
+        assert_abs_diff!(length, Scalar::from(4.));
+
+        // Going around the cut counter-clockwise, as seen from above.
+        let area = Scalar::sum_stable(outline.iter().map(|segment| {
+            let [a, b] = segment.points();
+            a.coords.xy().cross2d(&b.coords.xy())
+        })) / 2.;
+        assert_abs_diff!(area, Scalar::ONE);
+    }
+
+    #[test]
+    fn slice_layers() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        let layers = mesh.slice_layers(0.25);
+        assert_eq!(layers.len(), 4);
+
+        for layer in layers {
+            assert_eq!(layer.len(), 1);
+
+            let contour = &layer[0];
+            assert!(contour.is_closed());
+
+            let mut min = Point::from([1., 1.]);
+            let mut max = Point::from([0., 0.]);
+            for point in contour.points() {
+                min = Point::from([point.u.min(min.u), point.v.min(min.v)]);
+                max = Point::from([point.u.max(max.u), point.v.max(max.v)]);
+            }
+            assert_eq!(min, Point::from([0., 0.]));
+            assert_eq!(max, Point::from([1., 1.]));
+
+            let area =
+                Scalar::sum_stable(contour.segments().iter().map(|segment| {
+                    let [a, b] = segment.points();
+                    a.coords.cross2d(&b.coords)
+                })) / 2.;
+            assert_abs_diff!(area, Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn slice_through_vertex() {
+        // A box with a saddle-shaped top. Two opposite corners of the top are
+        // higher than the center, the other two are lower.
+        let corners =
+            [[0., 0., 1.], [1., 0., 0.5], [1., 1., 1.], [0., 1., 0.5]]
+                .map(Point::from);
+        let center = Point::from([0.5, 0.5, 0.75]);
+
+        let mut mesh = Mesh::new();
+        let bottom = corners
+            .map(|corner| Point::from([corner.x, corner.y, Scalar::ZERO]));
+        mesh.push_triangle([bottom[0], bottom[2], bottom[1]], Color::default());
+        mesh.push_triangle([bottom[0], bottom[3], bottom[2]], Color::default());
+        for i in 0..4 {
+            let j = (i + 1) % 4;
+            mesh.push_triangle(
+                [bottom[i], bottom[j], corners[j]],
+                Color::default(),
+            );
+            mesh.push_triangle(
+                [bottom[i], corners[j], corners[i]],
+                Color::default(),
+            );
+            mesh.push_triangle(
+                [corners[i], corners[j], center],
+                Color::default(),
+            );
+        }
+
+        // The plane of the second layer goes through the center of the top,
+        // which is a vertex of the mesh. The cross-section there consists of
+        // two parts that touch at that vertex.
+        let layers = mesh.slice_stack([0., 0., 1.], 0.5);
+        assert_eq!(layers.len(), 2);
+
+        let layer = &layers[1];
+        assert_eq!(layer.height, Scalar::from(0.75));
+        assert!(layer.open_contours.is_empty());
+
+        // Both parts must be complete. Whether they end up in one contour that
+        // touches itself, or in two separate ones, depends on the order in
+        // which the segments at the vertex are stitched together.
+        let vertex = Point::from([0.5, 0.5]);
+        let num_visits = layer
+            .contours
+            .iter()
+            .flatten()
+            .filter(|&&point| point == vertex)
+            .count();
+        assert_eq!(num_visits, 2);
+
+        let area =
+            Scalar::sum_stable(layer.contours.iter().flat_map(|contour| {
+                (0..contour.len()).map(|i| {
+                    let [a, b] = [contour[i], contour[(i + 1) % contour.len()]];
+                    a.coords.cross2d(&b.coords)
+                })
+            })) / 2.;
+        assert_abs_diff!(area, Scalar::from(0.5));
+    }
+
+    #[test]
+    fn extract_planar_polygons_cube() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        let polygons = mesh.extract_planar_polygons(1e-6);
+        assert_eq!(polygons.len(), 6);
+
+        for polygon in polygons {
+            assert_eq!(polygon.exterior.len(), 4);
+            assert!(polygon.interiors.is_empty());
+            assert_eq!(polygon.triangles.len(), 2);
+
+            // Each side of the cube is a unit square, that is perpendicular
+            // to one of the axes.
+            let normal = polygon.plane.normal();
+            assert_abs_diff!(normal.abs().max_component().1, Scalar::ONE);
+
+            let points = polygon
+                .exterior
+                .iter()
+                .map(|&index| mesh.vertices[index as usize])
+                .collect::<Vec<_>>();
+            for (i, &a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+                assert_abs_diff!(a.distance_to(&b), Scalar::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn extract_planar_polygons_with_hole() {
+        let outer = [[0., 0.], [3., 0.], [3., 3.], [0., 3.]];
+        let inner = [[1., 1.], [2., 1.], [2., 2.], [1., 2.]];
+        let [outer, inner] = [outer, inner]
+            .map(|points| points.map(|[x, y]| Point::from([x, y, 0.])));
+
+        let mut mesh = Mesh::new();
+        for i in 0..4 {
+            let j = (i + 1) % 4;
+            for triangle in [
+                [outer[i], outer[j], inner[j]],
+                [outer[i], inner[j], inner[i]],
+            ] {
+                mesh.push_triangle(triangle, Color::default());
+            }
+        }
+
+        let polygons = mesh.extract_planar_polygons(1e-6);
+        assert_eq!(polygons.len(), 1);
+
+        let polygon = &polygons[0];
+        assert_abs_diff!(polygon.plane.normal(), Vector::unit_z());
+        assert_eq!(polygon.triangles.len(), 8);
+
+        let points = |indices: &[u32]| {
+            indices
+                .iter()
+                .map(|&index| mesh.vertices[index as usize])
+                .collect::<Vec<_>>()
+        };
+
+        // The exterior starts at the first vertex, and goes around the
+        // outside counter-clockwise.
+        assert_eq!(points(&polygon.exterior), outer);
+
+        assert_eq!(polygon.interiors.len(), 1);
+        let mut hole = points(&polygon.interiors[0]);
+        assert_eq!(hole.len(), 4);
+        hole.reverse();
+        let start = hole.iter().position(|&point| point == inner[0]).unwrap();
+        hole.rotate_left(start);
+        assert_eq!(hole, inner);
+    }
+
+    #[test]
+    fn quantize_is_idempotent() {
+        // Rotate the cube, so its coordinates are not already on the
+        // quantization grid.
+        let rotation = Transform::rotation([0.1, 0.2, 0.3]);
+
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            let triangle =
+                triangle.map(|point| rotation.transform_point(&point));
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        mesh.quantize(1e-6);
+        let quantized = mesh.clone();
+        mesh.quantize(1e-6);
+
+        let to_bits = |mesh: &Mesh<Point<3>>| {
+            mesh.vertices()
+                .map(|point| point.coords.components.map(|s| s.to_bits()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(mesh.vertices().count(), 8);
+        assert_eq!(to_bits(&mesh), to_bits(&quantized));
+        assert_eq!(
+            mesh.indices().collect::<Vec<_>>(),
+            quantized.indices().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn is_watertight() {
+        let mut mesh = Mesh::new();
+        for triangle in cube_triangles() {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        assert!(mesh.is_watertight());
+
+        let mut open = Mesh::new();
+        for triangle in cube_triangles().skip(1) {
+            open.push_triangle(triangle, Color::default());
+        }
+        assert!(!open.is_watertight());
+
+        let mut flipped = Mesh::new();
+        for (i, [a, b, c]) in cube_triangles().enumerate() {
+            let triangle = if i == 0 { [a, c, b] } else { [a, b, c] };
+            flipped.push_triangle(triangle, Color::default());
+        }
+        assert!(!flipped.is_watertight());
+    }
+
+    #[test]
+    fn decimate_tube() -> Result<(), NonManifoldError> {
+        // An open tube, whose rims are the boundary of the mesh.
+        let segments = 64;
+        let rings = 20;
+
+        let point = |i: usize, j: usize| {
+            let angle = Scalar::TAU / segments as f64 * i as f64;
+            let (sin, cos) = angle.sin_cos();
+            Point::from([cos, sin, Scalar::from(j as f64 / 10.)])
+        };
+
+        let mut mesh = Mesh::new();
+        for i in 0..segments {
+            for j in 0..rings - 1 {
+                let [a, b, c, d] = [
+                    point(i, j),
+                    point((i + 1) % segments, j),
+                    point((i + 1) % segments, j + 1),
+                    point(i, j + 1),
+                ];
+                mesh.push_triangle([a, b, c], Color::default());
+                mesh.push_triangle([a, c, d], Color::default());
+            }
+        }
+
+        let decimated = mesh.decimate(200)?;
+        assert!(decimated.triangles().count() <= 200);
+
+        // The rims are left as they are.
+        let vertices = decimated.vertices().collect::<Vec<_>>();
+        for i in 0..segments {
+            for j in [0, rings - 1] {
+                assert!(vertices.contains(&point(i, j)));
+            }
+        }
+
+        // All of the decimated triangles stay close to the tube.
+        for triangle in decimated.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            for [u, v] in [[1., 1.], [2., 1.], [1., 2.], [4., 1.], [1., 4.]] {
+                let [u, v] = [u, v].map(|s| Scalar::from(s / 6.));
+                let sample = a + (b - a) * u + (c - a) * v;
+
+                let radius = sample.coords.xy().magnitude();
+                assert!((radius - Scalar::ONE).abs() < Scalar::from(0.02));
+            }
+        }
+
+        // Decimation is deterministic.
+        let again = mesh.decimate(200)?;
+        assert_eq!(
+            decimated.vertices().collect::<Vec<_>>(),
+            again.vertices().collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            decimated.indices().collect::<Vec<_>>(),
+            again.indices().collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+}
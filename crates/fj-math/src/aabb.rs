@@ -1,7 +1,7 @@
 use parry2d_f64::bounding_volume::BoundingVolume as _;
 use parry3d_f64::bounding_volume::BoundingVolume as _;
 
-use super::{Point, Vector};
+use super::{Point, Scalar, Segment, Triangle, Vector};
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -45,6 +45,127 @@ impl<const D: usize> Aabb<D> {
 
         true
     }
+
+    /// Determine whether this AABB is equal to another, within a tolerance
+    ///
+    /// Returns `true`, if each coordinate of the minimum and maximum points
+    /// differs by at most `tolerance` from the respective coordinate of the
+    /// other AABB.
+    pub fn approx_eq(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        [(self.min, other.min), (self.max, other.max)]
+            .into_iter()
+            .flat_map(|(a, b)| {
+                a.coords.components.into_iter().zip(b.coords.components)
+            })
+            .all(|(a, b)| (a - b).abs() <= tolerance)
+    }
+
+    /// Determine whether the AABB intersects a segment
+    ///
+    /// Clips the segment against the slab between the minimum and maximum
+    /// coordinate, for each dimension. The segment intersects the AABB, if
+    /// part of it remains after all clipping. Segments that only touch the
+    /// boundary of the AABB intersect it.
+    pub fn intersects_segment(&self, segment: &Segment<D>) -> bool {
+        let [start, end] = segment.points();
+        let direction = end - start;
+
+        // The part of the segment that remains after clipping, as parameters
+        // along the segment.
+        let mut t_min = Scalar::ZERO;
+        let mut t_max = Scalar::ONE;
+
+        for i in 0..D {
+            let start = start.coords.components[i];
+            let direction = direction.components[i];
+            let min = self.min.coords.components[i];
+            let max = self.max.coords.components[i];
+
+            if direction == Scalar::ZERO {
+                // The segment is parallel to the slab. It either lies within
+                // it completely, or not at all.
+                if start < min || start > max {
+                    return false;
+                }
+
+                continue;
+            }
+
+            let a = (min - start) / direction;
+            let b = (max - start) / direction;
+            let (near, far) = if a <= b { (a, b) } else { (b, a) };
+
+            t_min = t_min.max(near);
+            t_max = t_max.min(far);
+
+            if t_min > t_max {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Aabb<1> {
+    /// Construct a 1-dimensional AABB from a list of points
+    ///
+    /// A 1-dimensional AABB is an interval, for example a range of curve
+    /// coordinates. The resulting interval contains all the points, regardless
+    /// of the order they are passed in.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if no points are passed.
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<1>>>,
+    ) -> Self {
+        points
+            .into_iter()
+            .map(|point| {
+                let point = point.into();
+                Self {
+                    min: point,
+                    max: point,
+                }
+            })
+            .reduce(|a, b| a.merged(&b))
+            .expect("Can't construct AABB from empty list of points")
+    }
+
+    /// Merge this AABB with another
+    ///
+    /// The result is the smallest interval that contains both intervals. If
+    /// the intervals are disjoint, that includes the gap between them.
+    pub fn merged(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Compute the intersection of this AABB with another
+    ///
+    /// Returns `None`, if the intervals are disjoint. Intervals that only touch
+    /// at their boundaries intersect, and their intersection has a length of
+    /// zero.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+
+        (min <= max).then_some(Self { min, max })
+    }
+
+    /// Compute the length of the AABB
+    pub fn length(&self) -> Scalar {
+        self.max.t - self.min.t
+    }
 }
 
 impl Aabb<2> {
@@ -81,6 +202,18 @@ impl Aabb<2> {
     pub fn merged(&self, other: &Self) -> Self {
         self.to_parry().merged(&other.to_parry()).into()
     }
+
+    /// Compute the area of the AABB
+    pub fn area(&self) -> Scalar {
+        let [x, y] = (self.max - self.min).components;
+        x * y
+    }
+
+    /// Compute the perimeter of the AABB
+    pub fn perimeter(&self) -> Scalar {
+        let [x, y] = (self.max - self.min).components;
+        (x + y) * 2.
+    }
 }
 
 impl Aabb<3> {
@@ -140,6 +273,54 @@ impl Aabb<3> {
     pub fn merged(&self, other: &Self) -> Self {
         self.to_parry().merged(&other.to_parry()).into()
     }
+
+    /// Compute the surface area of the AABB
+    pub fn surface_area(&self) -> Scalar {
+        let [x, y, z] = self.size().components;
+        (x * y + y * z + z * x) * 2.
+    }
+
+    /// Compute the volume of the AABB
+    pub fn volume(&self) -> Scalar {
+        let [x, y, z] = self.size().components;
+        x * y * z
+    }
+
+    /// Determine whether the AABB intersects a triangle
+    ///
+    /// Uses the separating axis theorem: The AABB and the triangle are
+    /// disjoint, if and only if their projections onto one of the candidate
+    /// axes are. The candidates are the axes of the AABB, the normal of the
+    /// triangle, and the cross products of each pair of AABB axis and triangle
+    /// edge. Triangles that only touch the boundary of the AABB intersect it.
+    pub fn intersects_triangle(&self, triangle: &Triangle<3>) -> bool {
+        let center = self.center();
+        let half_size = self.size() / 2.;
+
+        // Work relative to the center of the AABB. That way, the projection of
+        // the AABB onto any axis is an interval that is centered on zero.
+        let vertices = triangle.points().map(|point| point - center);
+        let edges = [0, 1, 2].map(|i| vertices[(i + 1) % 3] - vertices[i]);
+
+        let aabb_axes = [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()];
+        let normal = edges[0].cross(&edges[1]);
+        let edge_axes = edges
+            .into_iter()
+            .flat_map(|edge| aabb_axes.map(|axis| edge.cross(&axis)));
+
+        let mut axes = aabb_axes.into_iter().chain([normal]).chain(edge_axes);
+        !axes.any(|axis| {
+            let projections = vertices.map(|vertex| vertex.dot(&axis));
+            let min = projections.into_iter().min().expect("Array not empty");
+            let max = projections.into_iter().max().expect("Array not empty");
+
+            let radius = half_size.dot(&axis.abs());
+
+            // A degenerate axis projects everything to zero, and can't
+            // separate anything.
+            min > radius || max < -radius
+        })
+    }
 }
 
 impl From<parry2d_f64::bounding_volume::Aabb> for Aabb<2> {
@@ -156,8 +337,83 @@ impl From<parry3d_f64::bounding_volume::Aabb> for Aabb<3> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Scalar, Segment, Triangle};
+
     use super::Aabb;
 
+    #[test]
+    fn interval() {
+        let a = Aabb::<1>::from_points([[3.], [1.], [2.]]);
+        assert_eq!(a, Aabb::<1>::from_points([[1.], [3.]]));
+        assert_eq!(a.length(), Scalar::from(2.));
+
+        assert!(a.contains([1.]));
+        assert!(a.contains([3.]));
+        assert!(!a.contains([0.]));
+
+        // Overlapping
+        let b = Aabb::<1>::from_points([[2.], [4.]]);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Aabb::<1>::from_points([[2.], [3.]]))
+        );
+        assert_eq!(a.merged(&b), Aabb::<1>::from_points([[1.], [4.]]));
+
+        // Touching
+        let c = Aabb::<1>::from_points([[3.], [5.]]);
+        let touching = a.intersection(&c).unwrap();
+        assert_eq!(touching, Aabb::<1>::from_points([[3.]]));
+        assert_eq!(touching.length(), Scalar::ZERO);
+        assert_eq!(a.merged(&c), Aabb::<1>::from_points([[1.], [5.]]));
+
+        // Disjoint
+        let d = Aabb::<1>::from_points([[4.], [5.]]);
+        assert_eq!(a.intersection(&d), None);
+        assert_eq!(d.intersection(&a), None);
+        assert_eq!(a.merged(&d), Aabb::<1>::from_points([[1.], [5.]]));
+    }
+
+    #[test]
+    fn hash() {
+        let a = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+        let b = Aabb::<3>::from_points([[-0., 0., 0.], [2., 1., 1.]]);
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        set.insert(a);
+        assert_eq!(set.len(), 2);
+
+        assert!(set.contains(&Aabb::<3>::from_points([
+            [-0., -0., -0.],
+            [1., 1., 1.]
+        ])));
+        assert!(!set
+            .contains(&Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 2.]])));
+    }
+
+    #[test]
+    fn measures() {
+        let aabb = Aabb::<3>::from_points([[1., 1., 1.], [2., 3., 4.]]);
+        assert_eq!(aabb.surface_area(), Scalar::from(22.));
+        assert_eq!(aabb.volume(), Scalar::from(6.));
+
+        let aabb = Aabb::<2>::from_points([[-1., 1.], [1., 4.]]);
+        assert_eq!(aabb.area(), Scalar::from(6.));
+        assert_eq!(aabb.perimeter(), Scalar::from(10.));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let a = Aabb::<2>::from_points([[0., 0.], [1., 1.]]);
+        let b = Aabb::<2>::from_points([[0.001, -0.001], [1., 1.001]]);
+
+        assert!(a.approx_eq(&b, 0.01));
+        assert!(!a.approx_eq(&b, 0.0001));
+    }
+
     #[test]
     fn contains() {
         let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
@@ -174,4 +430,73 @@ mod tests {
         assert!(!aabb.contains([0., 2.]));
         assert!(!aabb.contains([4., 2.]));
     }
+
+    #[test]
+    fn parry_round_trip_2d() {
+        let aabb = Aabb::<2>::from_points([[-1., 2.], [3., -4.]]);
+
+        let parry = aabb.to_parry();
+        assert_eq!(parry.mins, aabb.min.to_na());
+        assert_eq!(parry.maxs, aabb.max.to_na());
+
+        let round_tripped = Aabb::<2>::from(parry);
+        assert_eq!(round_tripped.min, aabb.min);
+        assert_eq!(round_tripped.max, aabb.max);
+    }
+
+    #[test]
+    fn intersects_segment() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+
+        let crossing =
+            Segment::from_points([[-1., 0.5, 0.5], [2., 0.25, 0.75]]);
+        assert!(aabb.intersects_segment(&crossing));
+
+        let inside =
+            Segment::from_points([[0.25, 0.25, 0.25], [0.5, 0.5, 0.5]]);
+        assert!(aabb.intersects_segment(&inside));
+
+        let outside = Segment::from_points([[2., 2., 2.], [3., 0., 2.]]);
+        assert!(!aabb.intersects_segment(&outside));
+
+        // The infinite line through this segment crosses the box, but the
+        // segment ends before reaching it.
+        let short = Segment::from_points([[-2., 0.5, 0.5], [-1., 0.5, 0.5]]);
+        assert!(!aabb.intersects_segment(&short));
+
+        let aabb = Aabb::<2>::from_points([[0., 0.], [1., 1.]]);
+        let parallel = Segment::from_points([[-1., 2.], [2., 2.]]);
+        assert!(!aabb.intersects_segment(&parallel));
+    }
+
+    #[test]
+    fn intersects_triangle() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+
+        let straddling =
+            Triangle::from([[0.5, 0.5, 0.5], [2., 0.5, 0.5], [0.5, 2., 0.5]]);
+        assert!(aabb.intersects_triangle(&straddling));
+
+        // A large triangle that cuts through the box, without any of its
+        // vertices being inside.
+        let cutting = Triangle::from([
+            [-10., -10., 0.5],
+            [10., -10., 0.5],
+            [0., 10., 0.5],
+        ]);
+        assert!(aabb.intersects_triangle(&cutting));
+
+        let far_away =
+            Triangle::from([[10., 10., 10.], [11., 10., 10.], [10., 11., 10.]]);
+        assert!(!aabb.intersects_triangle(&far_away));
+
+        // The bounding boxes overlap, but the triangle passes by a corner of
+        // the box.
+        let past_corner = Triangle::from([
+            [1.65, 1.65, 0.],
+            [1.65, 0., 1.65],
+            [0., 1.65, 1.65],
+        ]);
+        assert!(!aabb.intersects_triangle(&past_corner));
+    }
 }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use num_traits::Float;
 
 use crate::{Point, Scalar, Vector};
@@ -81,13 +83,128 @@ impl Arc {
             end_angle,
         }
     }
+
+    /// Validate the arc against the endpoints it is supposed to connect
+    ///
+    /// An arc doesn't store its endpoints, so they need to be provided. Checks
+    /// that the radius is positive, that the arc sweeps an angle that is not
+    /// zero and at most a full turn, and that each endpoint lies on the circle
+    /// at the respective angle, within `tolerance`.
+    ///
+    /// This catches inconsistencies early, which would otherwise lead to
+    /// downstream code silently misbehaving.
+    pub fn validate(
+        &self,
+        endpoints: [impl Into<Point<2>>; 2],
+        tolerance: impl Into<Scalar>,
+    ) -> Result<(), ArcValidationError> {
+        let tolerance = tolerance.into();
+
+        if self.radius <= Scalar::ZERO {
+            return Err(ArcValidationError::NonPositiveRadius {
+                radius: self.radius,
+            });
+        }
+
+        let swept_angle = self.end_angle - self.start_angle;
+        if swept_angle == Scalar::ZERO || swept_angle.abs() > Scalar::TAU {
+            return Err(ArcValidationError::InvalidSweptAngle { swept_angle });
+        }
+
+        let [start, end] = endpoints.map(Into::into);
+        for (endpoint, angle) in
+            [(start, self.start_angle), (end, self.end_angle)]
+        {
+            let distance = endpoint.distance_to(&self.center);
+            if (distance - self.radius).abs() > tolerance {
+                return Err(ArcValidationError::EndpointNotOnCircle {
+                    endpoint,
+                    distance,
+                });
+            }
+
+            let (sin, cos) = angle.sin_cos();
+            let expected = self.center + Vector::from([cos, sin]) * self.radius;
+            if endpoint.distance_to(&expected) > tolerance {
+                return Err(ArcValidationError::EndpointAngleMismatch {
+                    endpoint,
+                    expected,
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
+/// An [`Arc`] that is inconsistent
+///
+/// Returned by [`Arc::validate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArcValidationError {
+    /// The radius is zero or negative
+    NonPositiveRadius {
+        /// The radius of the arc
+        radius: Scalar,
+    },
+
+    /// The arc sweeps no angle, or more than a full turn
+    InvalidSweptAngle {
+        /// The angle from the start to the end of the arc
+        swept_angle: Scalar,
+    },
+
+    /// An endpoint is not at the radius from the center
+    EndpointNotOnCircle {
+        /// The endpoint
+        endpoint: Point<2>,
+
+        /// The distance of the endpoint from the center
+        distance: Scalar,
+    },
+
+    /// An endpoint doesn't lie at the respective angle of the arc
+    EndpointAngleMismatch {
+        /// The endpoint
+        endpoint: Point<2>,
+
+        /// The point on the circle at the angle of the endpoint
+        expected: Point<2>,
+    },
+}
+
+impl fmt::Display for ArcValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NonPositiveRadius { radius } => {
+                write!(f, "Invalid arc: radius ({radius}) must be positive")
+            }
+            Self::InvalidSweptAngle { swept_angle } => write!(
+                f,
+                "Invalid arc: swept angle ({swept_angle}) must be non-zero and \
+                at most a full turn"
+            ),
+            Self::EndpointNotOnCircle { endpoint, distance } => write!(
+                f,
+                "Invalid arc: endpoint {endpoint:?} has distance {distance} \
+                from center, which doesn't match the radius"
+            ),
+            Self::EndpointAngleMismatch { endpoint, expected } => write!(
+                f,
+                "Invalid arc: endpoint {endpoint:?} doesn't match point at \
+                its angle ({expected:?})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArcValidationError {}
+
 #[cfg(test)]
 mod tests {
     use crate::{Point, Scalar, Vector};
 
-    use super::Arc;
+    use super::{Arc, ArcValidationError};
 
     use approx::{assert_abs_diff_eq, AbsDiffEq};
 
@@ -125,6 +242,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate() {
+        let p0 = Point::from([1., 0.]);
+        let p1 = Point::from([0., 1.]);
+        let arc = Arc::from_endpoints_and_angle(p0, p1, Scalar::PI / 2.);
+
+        let tolerance = 1e-12;
+        assert_eq!(arc.validate([p0, p1], tolerance), Ok(()));
+
+        let off_circle = Point::from([0., 1.1]);
+        assert!(matches!(
+            arc.validate([p0, off_circle], tolerance),
+            Err(ArcValidationError::EndpointNotOnCircle { .. })
+        ));
+
+        let wrong_angle = Point::from([-1., 0.]);
+        assert!(matches!(
+            arc.validate([p0, wrong_angle], tolerance),
+            Err(ArcValidationError::EndpointAngleMismatch { .. })
+        ));
+    }
+
     fn check_arc_calculation(
         center: impl Into<Point<2>>,
         radius: f64,
@@ -1,11 +1,20 @@
 use approx::AbsDiffEq;
+use num_traits::Float;
 
-use crate::{Aabb, Point, Scalar, Vector};
+use crate::{Aabb, Arc, Line, Point, Scalar, Vector};
 
 /// An n-dimensional circle
 ///
 /// The dimensionality of the circle is defined by the const generic `D`
 /// parameter.
+///
+/// # Equality
+///
+/// Circles are equal, if their center and the vectors `a` and `b` are equal.
+/// This means equality takes the parameterization of the circle into account:
+/// Two circles that describe the same geometry, but have their coordinate
+/// origin at a different point, or go around in opposite directions, are not
+/// equal. Use [`Circle::same_geometry`] to compare only the geometry.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Circle<const D: usize> {
     center: Point<D>,
@@ -100,6 +109,34 @@ impl<const D: usize> Circle<D> {
         self.b
     }
 
+    /// Determine whether this circle has the same geometry as another one
+    ///
+    /// Returns `true`, if both circles have the same center and radius, and lie
+    /// in the same plane, regardless of their parameterization. Unlike
+    /// equality, this ignores where the coordinate origin of the circle is, and
+    /// which direction it goes around in.
+    ///
+    /// The comparison allows for differences within the default epsilon of
+    /// [`Scalar`], relative to the radius of the circles.
+    pub fn same_geometry(&self, other: &Self) -> bool {
+        let radius = self.radius();
+        let epsilon = Scalar::default_epsilon() * radius.max(Scalar::ONE);
+
+        if !self.center.abs_diff_eq(&other.center, epsilon)
+            || !radius.abs_diff_eq(&other.radius(), epsilon)
+        {
+            return false;
+        }
+
+        // The circles lie in the same plane, if the vectors that define the
+        // other circle have no component outside of the plane of this one.
+        [other.a, other.b].into_iter().all(|v| {
+            let in_plane = self.a * (v.dot(&self.a) / (radius * radius))
+                + self.b * (v.dot(&self.b) / (radius * radius));
+            (v - in_plane).magnitude() <= epsilon
+        })
+    }
+
     /// Create a new instance that is reversed
     #[must_use]
     pub fn reverse(mut self) -> Self {
@@ -163,6 +200,71 @@ impl<const D: usize> Circle<D> {
     }
 }
 
+impl Circle<2> {
+    /// Construct an arc on this circle
+    ///
+    /// `start_angle` and `end_angle` are in circle coordinates, meaning the
+    /// arc starts at `self.point_from_circle_coords([start_angle])` and ends
+    /// at `self.point_from_circle_coords([end_angle])`. It goes in the
+    /// direction of increasing circle coordinates. For a circle whose `b` is
+    /// clockwise from its `a`, that means the arc goes clockwise.
+    ///
+    /// The angles of the returned [`Arc`] are relative to the x-axis, like
+    /// those of any other `Arc`.
+    pub fn arc(&self, start_angle: Scalar, end_angle: Scalar) -> Arc {
+        let start = self.point_from_circle_coords([start_angle]) - self.center;
+        let start_angle_arc = Scalar::atan2(start.v, start.u);
+
+        let winding = if self.a.cross2d(&self.b) < Scalar::ZERO {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        Arc {
+            center: self.center,
+            radius: self.radius(),
+            start_angle: start_angle_arc,
+            end_angle: start_angle_arc + (end_angle - start_angle) * winding,
+        }
+    }
+
+    /// Compute the intersection of the circle with a line
+    ///
+    /// If the line barely touches the circle, within a tolerance derived from
+    /// [`Scalar::default_epsilon`] and the radius of the circle, this is
+    /// considered a tangent, resulting in a single intersection point.
+    pub fn intersect_line(&self, line: &Line<2>) -> LineCircleIntersection {
+        let radius = self.radius();
+
+        // Find the point on the line that is closest to the circle center. Any
+        // intersection points are symmetric around that point.
+        let direction = line.direction().normalize();
+        let t = (self.center - line.origin()).dot(&direction);
+        let closest = line.origin() + direction * t;
+
+        let distance = (self.center - closest).magnitude();
+
+        // This is the discriminant of the quadratic equation that describes the
+        // intersection. It is the squared half-length of the chord.
+        let discriminant = radius * radius - distance * distance;
+        let tolerance = Scalar::default_epsilon() * radius * radius;
+
+        if discriminant < -tolerance {
+            return LineCircleIntersection::None;
+        }
+        if discriminant <= tolerance {
+            return LineCircleIntersection::Tangent(closest);
+        }
+
+        let half_chord = direction * discriminant.sqrt();
+        LineCircleIntersection::Two([
+            closest - half_chord,
+            closest + half_chord,
+        ])
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Circle<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
@@ -177,13 +279,30 @@ impl<const D: usize> approx::AbsDiffEq for Circle<D> {
     }
 }
 
+/// The intersection of a [`Circle`] and a [`Line`]
+///
+/// Returned by [`Circle::intersect_line`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum LineCircleIntersection {
+    /// The line and the circle don't intersect
+    None,
+
+    /// The line touches the circle at a single point
+    Tangent(Point<2>),
+
+    /// The line crosses the circle at two points
+    ///
+    /// The points are ordered along the direction of the line.
+    Two([Point<2>; 2]),
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{FRAC_PI_2, PI};
 
-    use crate::{Point, Vector};
+    use crate::{assert_abs_diff, Line, Point, Scalar, Vector};
 
-    use super::Circle;
+    use super::{Circle, LineCircleIntersection};
 
     #[test]
     fn point_to_circle_coords() {
@@ -210,4 +329,86 @@ mod tests {
             Point::from([FRAC_PI_2 * 3.]),
         );
     }
+
+    #[test]
+    fn intersect_line() {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+
+        let secant = Line::from_points([[-2., 0.], [2., 0.]]).0;
+        assert_eq!(
+            circle.intersect_line(&secant),
+            LineCircleIntersection::Two([
+                Point::from([-1., 0.]),
+                Point::from([1., 0.])
+            ]),
+        );
+
+        let tangent = Line::from_points([[-2., 1.], [2., 1.]]).0;
+        assert_eq!(
+            circle.intersect_line(&tangent),
+            LineCircleIntersection::Tangent(Point::from([0., 1.])),
+        );
+
+        let miss = Line::from_points([[-2., 2.], [2., 2.]]).0;
+        assert_eq!(circle.intersect_line(&miss), LineCircleIntersection::None);
+    }
+
+    #[test]
+    fn arc() {
+        let circle = Circle::from_center_and_radius([1., 2.], 2.);
+
+        for circle in [circle, circle.reverse()] {
+            let [start, end] = [0.25, 0.5].map(|angle| Scalar::PI * angle);
+            let arc = circle.arc(start, end);
+
+            assert_eq!(arc.center, circle.center());
+            assert_eq!(arc.radius, circle.radius());
+
+            for (arc_angle, circle_angle) in
+                [(arc.start_angle, start), (arc.end_angle, end)]
+            {
+                let (sin, cos) = arc_angle.sin_cos();
+                assert_abs_diff!(
+                    arc.center + Vector::from([cos, sin]) * arc.radius,
+                    circle.point_from_circle_coords([circle_angle]),
+                );
+            }
+
+            // A quarter arc.
+            assert_abs_diff!(
+                (arc.end_angle - arc.start_angle).abs(),
+                Scalar::PI / 4.
+            );
+        }
+
+        // The arc goes in the direction of the circle's parameterization.
+        let [ccw, cw] = [circle, circle.reverse()]
+            .map(|circle| circle.arc(Scalar::ZERO, Scalar::PI / 2.));
+        assert!(ccw.end_angle > ccw.start_angle);
+        assert!(cw.end_angle < cw.start_angle);
+    }
+
+    #[test]
+    fn same_geometry() {
+        let circle = Circle::new([1., 2., 3.], [2., 0., 0.], [0., 2., 0.]);
+
+        let reversed = circle.reverse();
+        assert_ne!(circle, reversed);
+        assert!(circle.same_geometry(&reversed));
+
+        let rotated = Circle::new([1., 2., 3.], [0., 2., 0.], [-2., 0., 0.]);
+        assert_ne!(circle, rotated);
+        assert!(circle.same_geometry(&rotated));
+
+        let other_plane = Circle::new([1., 2., 3.], [2., 0., 0.], [0., 0., 2.]);
+        assert!(!circle.same_geometry(&other_plane));
+
+        let other_radius =
+            Circle::new([1., 2., 3.], [1., 0., 0.], [0., 1., 0.]);
+        assert!(!circle.same_geometry(&other_radius));
+
+        let other_center =
+            Circle::new([1., 2., 4.], [2., 0., 0.], [0., 2., 0.]);
+        assert!(!circle.same_geometry(&other_center));
+    }
 }
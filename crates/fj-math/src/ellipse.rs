@@ -0,0 +1,282 @@
+use approx::AbsDiffEq;
+use num_traits::Float;
+
+use crate::{Aabb, Circle, Point, Scalar, Vector};
+
+/// An n-dimensional ellipse
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+///
+/// The ellipse is defined by its center and two vectors, `a` and `b`. A point
+/// on the ellipse at ellipse coordinate `t` is `center + a * cos(t) + b *
+/// sin(t)`. If `a` and `b` are perpendicular, they are the semi-axes of the
+/// ellipse. Otherwise, they are a pair of conjugate semi-diameters, which is
+/// what a non-uniform scaling of a circle results in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` or `b` have zero length, or if they are parallel to each
+    /// other.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        // This is the squared area of the parallelogram spanned by `a` and `b`.
+        // It is zero, if either vector is zero, or if they are parallel.
+        let [aa, ab, bb] = [a.dot(&a), a.dot(&b), b.dot(&b)];
+        let gram_determinant = aa * bb - ab * ab;
+        assert!(
+            gram_determinant > Scalar::default_epsilon() * aa * bb,
+            "`a` and `b` must be non-zero and not parallel to each other"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Construct an ellipse from a circle
+    pub fn from_circle(circle: Circle<D>) -> Self {
+        Self {
+            center: circle.center(),
+            a: circle.a(),
+            b: circle.b(),
+        }
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the starting point of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the zero
+    /// coordinate of the ellipse's coordinate system.
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is at
+    /// ellipse coordinate `PI / 2.`.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a `D`-dimensional point to ellipse coordinates
+    ///
+    /// Converts the provided point into ellipse coordinates between `0.`
+    /// (inclusive) and `PI * 2.` (exclusive).
+    ///
+    /// The point is expressed in terms of `a` and `b`, and the coordinate is
+    /// derived from the resulting weights. Like
+    /// [`Circle::point_to_circle_coords`], this doesn't check whether the point
+    /// is actually on the ellipse.
+    pub fn point_to_ellipse_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let vector = point.into() - self.center;
+
+        // Solve `vector = a * cos + b * sin` in the least-squares sense, using
+        // the normal equations.
+        let [aa, ab, bb] = [
+            self.a.dot(&self.a),
+            self.a.dot(&self.b),
+            self.b.dot(&self.b),
+        ];
+        let [va, vb] = [vector.dot(&self.a), vector.dot(&self.b)];
+
+        let determinant = aa * bb - ab * ab;
+        let cos = (va * bb - vb * ab) / determinant;
+        let sin = (vb * aa - va * ab) / determinant;
+
+        let atan = Scalar::atan2(sin, cos);
+        let coord = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+        Point::from([coord])
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional vector
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin
+    }
+
+    /// Compute the derivative of the ellipse at the given ellipse coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.b * cos - self.a * sin
+    }
+
+    /// Compute the curvature of the ellipse at the given ellipse coordinate
+    pub fn curvature_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        let point = point.into();
+
+        let first = self.derivative_at(point);
+        let second = -self.vector_from_ellipse_coords(point.coords);
+
+        // This is the magnitude of the cross product of both derivatives,
+        // computed in a way that works for any dimension.
+        let first_squared = first.dot(&first);
+        let cross = (first_squared * second.dot(&second)
+            - first.dot(&second) * first.dot(&second))
+        .max(Scalar::ZERO)
+        .sqrt();
+
+        cross / (first_squared * first_squared.sqrt())
+    }
+
+    /// Calculate an AABB for the ellipse
+    pub fn aabb(&self) -> Aabb<D> {
+        // Along each axis, the ellipse extends from its center by the length
+        // of the vector made up of the respective components of `a` and `b`.
+        let mut half_extent = Vector::from_component(Scalar::ZERO);
+        for i in 0..D {
+            let [a, b] = [self.a.components[i], self.b.components[i]];
+            half_extent.components[i] = (a * a + b * b).sqrt();
+        }
+
+        Aabb {
+            min: self.center - half_extent,
+            max: self.center + half_extent,
+        }
+    }
+}
+
+impl<const D: usize> From<Circle<D>> for Ellipse<D> {
+    fn from(circle: Circle<D>) -> Self {
+        Self::from_circle(circle)
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Ellipse<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_abs_diff, Circle, Point, Scalar, Transform, Vector};
+
+    use super::Ellipse;
+
+    #[test]
+    fn ellipse_coords_round_trip() {
+        let ellipse = Ellipse::new([1., 2., 3.], [5., 0., 0.], [0., 0.5, 0.5]);
+
+        for t in [0., 0.1, 1., 2.5, 3.5, 6.] {
+            let point = ellipse.point_from_ellipse_coords([t]);
+            assert_abs_diff!(
+                ellipse.point_to_ellipse_coords(point),
+                Point::from([t])
+            );
+        }
+
+        // Conjugate semi-diameters work too.
+        let ellipse = Ellipse::new([0., 0.], [3., 0.], [1., 1.]);
+        for t in [0., 1., 4.] {
+            let point = ellipse.point_from_ellipse_coords([t]);
+            assert_abs_diff!(
+                ellipse.point_to_ellipse_coords(point),
+                Point::from([t])
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn degenerate() {
+        Ellipse::new([0., 0.], [1., 1.], [2., 2.]);
+    }
+
+    #[test]
+    fn curvature() {
+        let ellipse = Ellipse::new([0., 0.], [4., 0.], [0., 2.]);
+
+        // At the ends of the major axis, the curvature is `a / b^2`. At the
+        // ends of the minor axis, it is `b / a^2`.
+        assert_abs_diff!(ellipse.curvature_at([0.]), Scalar::from(1.));
+        assert_abs_diff!(
+            ellipse.curvature_at([Scalar::PI / 2.]),
+            Scalar::from(0.125)
+        );
+
+        let circle =
+            Ellipse::from(Circle::from_center_and_radius([0., 0.], 2.));
+        assert_abs_diff!(circle.curvature_at([1.]), Scalar::from(0.5));
+    }
+
+    #[test]
+    fn aabb() {
+        let ellipse = Ellipse::new([1., 1.], [2., 0.], [0., 1.]);
+        let aabb = ellipse.aabb();
+
+        assert_eq!(aabb.min, Point::from([-1., 0.]));
+        assert_eq!(aabb.max, Point::from([3., 2.]));
+    }
+
+    #[test]
+    fn transform() {
+        let ellipse = Ellipse::new([1., 0., 0.], [3., 0., 0.], [0., 1., 0.]);
+        let transform = Transform::translation([0., 0., 2.])
+            .then_rotate(Vector::from([0., 0., Scalar::PI.into_f64() / 3.]));
+
+        let transformed = transform.transform_ellipse(&ellipse);
+
+        for t in [0., 1., 2., 5.] {
+            assert_abs_diff!(
+                transformed.point_from_ellipse_coords([t]),
+                transform
+                    .transform_point(&ellipse.point_from_ellipse_coords([t]))
+            );
+        }
+    }
+}
@@ -27,9 +27,17 @@
 //! [`From`]/[`Into`] documentation fails to provide any reasons for its
 //! mandate.
 //!
+//!
+//! ## Cargo features
+//!
+//! - `mint`: Enables conversions between [`Point`]/[`Vector`] and the
+//!   respective types from the [mint] crate. Conversions into the `f32`
+//!   flavor of the mint types are lossy.
+//!
 //! [Fornjot]: https://www.fornjot.app/
 //! [nalgebra]: https://nalgebra.org/
 //! [Parry]: https://www.parry.rs/
+//! [mint]: https://crates.io/crates/mint
 
 #![warn(missing_docs)]
 
@@ -37,7 +45,11 @@ mod aabb;
 mod arc;
 mod circle;
 mod coordinates;
+mod ellipse;
 mod line;
+mod macros;
+#[cfg(feature = "mint")]
+mod mint;
 mod plane;
 mod point;
 mod poly_chain;
@@ -49,16 +61,20 @@ mod vector;
 
 pub use self::{
     aabb::Aabb,
-    arc::Arc,
-    circle::Circle,
+    arc::{Arc, ArcValidationError},
+    circle::{Circle, LineCircleIntersection},
     coordinates::{Uv, Xyz, T},
+    ellipse::Ellipse,
     line::Line,
     plane::Plane,
     point::Point,
     poly_chain::PolyChain,
-    scalar::{Scalar, Sign},
+    scalar::{ParseScalarError, Scalar, Sign},
     segment::Segment,
-    transform::Transform,
+    transform::{FromBasisError, NonIsometry, Transform},
     triangle::{Triangle, Winding},
     vector::Vector,
 };
+
+#[doc(hidden)]
+pub use approx;
@@ -134,6 +134,16 @@ impl<const D: usize> Line<D> {
         }
     }
 
+    /// Compute the line parameter of a `D`-dimensional point
+    ///
+    /// This is the inverse of [`Line::point_from_line_coords`]. Like
+    /// [`Line::point_to_line_coords`], it projects the point onto the line
+    /// first, so for a point that is not on the line, the parameter of its
+    /// closest point on the line is returned.
+    pub fn parameter_at(&self, point: impl Into<Point<D>>) -> Scalar {
+        self.point_to_line_coords(point).t
+    }
+
     /// Convert a `D`-dimensional vector to line coordinates
     pub fn vector_to_line_coords(
         &self,
@@ -242,4 +252,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn parameter_at() {
+        let (line, _) = Line::from_points([[1., 1.], [3., 1.]]);
+        let point = Point::from([2., 5.]);
+
+        let t = line.parameter_at(point);
+        assert_abs_diff_eq!(t, Scalar::from(0.5), epsilon = Scalar::from(1e-8));
+        assert_abs_diff_eq!(
+            line.point_from_line_coords([t]),
+            Point::from([2., 1.]),
+            epsilon = Scalar::from(1e-8)
+        );
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 2., 3.]),
+            Vector::from([2., 3., 5.]),
+        );
+        let point = Point::from([4., -1., 2.]);
+
+        let projected = line.point_from_line_coords([line.parameter_at(point)]);
+        assert_abs_diff_eq!(
+            (point - projected).dot(&line.direction()),
+            Scalar::ZERO,
+            epsilon = Scalar::from(1e-8)
+        );
+    }
 }
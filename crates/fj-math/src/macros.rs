@@ -0,0 +1,63 @@
+/// Assert that two values are equal, within a tolerance
+///
+/// This is a thin wrapper around [`approx::assert_abs_diff_eq!`], for use with
+/// the types in this crate. Floating-point arithmetic is rarely exact, so this
+/// is usually the right choice for comparing computed geometry in tests.
+///
+/// If no epsilon is provided, a default of `1e-8` is used. An explicit epsilon
+/// can be passed as anything that converts into [`Scalar`](crate::Scalar).
+///
+/// ``` rust
+/// use fj_math::{assert_abs_diff, Vector};
+///
+/// let a = Vector::from([0.1, 0.2]) + Vector::from([0.2, 0.1]);
+/// let b = Vector::from([0.3, 0.3]);
+///
+/// assert_abs_diff!(a, b);
+/// assert_abs_diff!(a.u, b.u, epsilon = 1e-12);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_abs_diff!($left, $right, epsilon = 1e-8)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::approx::assert_abs_diff_eq!(
+            $left,
+            $right,
+            epsilon = $crate::Scalar::from($epsilon)
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar, Transform, Vector};
+
+    #[test]
+    fn scalar() {
+        let a = Scalar::from(0.1) + Scalar::from(0.2);
+        let b = Scalar::from(0.3);
+
+        assert_ne!(a, b);
+        assert_abs_diff!(a, b);
+    }
+
+    #[test]
+    fn point() {
+        let rotation = Transform::rotation(Vector::unit_z() * Scalar::PI);
+
+        let a = rotation.transform_point(&Point::from([1., 0., 0.]));
+        let b = Point::from([-1., 0., 0.]);
+
+        assert_ne!(a, b);
+        assert_abs_diff!(a, b);
+        assert_abs_diff!(a, b, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn outside_of_epsilon() {
+        assert_abs_diff!(Scalar::from(1.), Scalar::from(1.1));
+    }
+}
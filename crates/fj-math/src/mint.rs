@@ -0,0 +1,81 @@
+//! Conversions between fj-math types and [mint] types
+//!
+//! Only available, if the `mint` feature is enabled.
+//!
+//! Conversions are provided for both `f64` and `f32` flavors of the mint
+//! types. Converting into the `f32` flavor is lossy, as the `f64` values that
+//! fj-math uses internally are truncated to `f32` precision.
+//!
+//! [mint]: https://crates.io/crates/mint
+
+use crate::{Point, Scalar, Vector};
+
+macro_rules! impl_mint {
+    ($($mint:ident, $fj:ident, $d:expr, [$($component:ident),*];)*) => {
+        $(
+            impl From<mint::$mint<f64>> for $fj<$d> {
+                fn from(value: mint::$mint<f64>) -> Self {
+                    Self::from([$(value.$component),*])
+                }
+            }
+
+            impl From<mint::$mint<f32>> for $fj<$d> {
+                fn from(value: mint::$mint<f32>) -> Self {
+                    Self::from([$(Scalar::from(value.$component)),*])
+                }
+            }
+
+            impl From<$fj<$d>> for mint::$mint<f64> {
+                fn from(value: $fj<$d>) -> Self {
+                    let [$($component),*] = <[Scalar; $d]>::from(value);
+                    Self {
+                        $($component: $component.into_f64()),*
+                    }
+                }
+            }
+
+            impl From<$fj<$d>> for mint::$mint<f32> {
+                fn from(value: $fj<$d>) -> Self {
+                    let [$($component),*] = <[Scalar; $d]>::from(value);
+                    Self {
+                        $($component: $component.into_f32()),*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mint!(
+    Point2, Point, 2, [x, y];
+    Point3, Point, 3, [x, y, z];
+    Vector2, Vector, 2, [x, y];
+    Vector3, Vector, 3, [x, y, z];
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Vector};
+
+    #[test]
+    fn round_trip_f64() {
+        let point = Point::from([1., 2., 3.]);
+        let mint: mint::Point3<f64> = point.into();
+        assert_eq!(Point::from(mint), point);
+
+        let vector = Vector::from([1., 2.]);
+        let mint: mint::Vector2<f64> = vector.into();
+        assert_eq!(Vector::from(mint), vector);
+    }
+
+    #[test]
+    fn round_trip_f32() {
+        let point = Point::from([1.5, 2.5]);
+        let mint: mint::Point2<f32> = point.into();
+        assert_eq!(Point::from(mint), point);
+
+        let vector = Vector::from([1.5, 2.5, 3.5]);
+        let mint: mint::Vector3<f32> = vector.into();
+        assert_eq!(Vector::from(mint), vector);
+    }
+}
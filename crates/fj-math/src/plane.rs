@@ -65,6 +65,26 @@ impl Plane {
         self.normal().dot(vector) == Scalar::ZERO
     }
 
+    /// Compute the signed distance of the point from the plane
+    ///
+    /// The distance is positive, if the point is on the side of the plane that
+    /// its normal points to, negative, if it is on the other side.
+    pub fn signed_distance_to_point(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Scalar {
+        self.normal().dot(&(point.into() - self.origin()))
+    }
+
+    /// Determine whether the plane contains the point, within the tolerance
+    pub fn contains_point(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        self.signed_distance_to_point(point).abs() <= tolerance.into()
+    }
+
     /// Project a point into the plane
     pub fn project_point(&self, point: impl Into<Point<3>>) -> Point<2> {
         let origin_to_point = point.into() - self.origin();
@@ -113,7 +133,7 @@ impl Plane {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Plane, Point, Vector};
+    use crate::{Plane, Point, Scalar, Vector};
 
     #[test]
     fn project_point() {
@@ -136,4 +156,32 @@ mod tests {
             Plane::from_parametric([1., 1., 1.], [1., 0., 0.], [1., 1., 0.]);
         assert_eq!(plane.project_vector([0., 1., 0.]), Vector::from([-1., 1.]));
     }
+
+    #[test]
+    fn signed_distance_to_point() {
+        let plane =
+            Plane::from_parametric([1., 1., 1.], [1., 0., 0.], [0., 1., 0.]);
+
+        assert_eq!(
+            plane.signed_distance_to_point([3., 2., 3.]),
+            Scalar::from(2.)
+        );
+        assert_eq!(
+            plane.signed_distance_to_point([3., 2., 0.]),
+            Scalar::from(-1.)
+        );
+    }
+
+    #[test]
+    fn contains_point() {
+        let plane =
+            Plane::from_parametric([1., 1., 1.], [1., 0., 0.], [0., 1., 0.]);
+        let tolerance = 1e-6;
+
+        assert!(plane.contains_point([2., 3., 1.], tolerance));
+        assert!(plane.contains_point([2., 3., 1. + 0.5e-6], tolerance));
+        assert!(plane.contains_point([2., 3., 1. - 0.5e-6], tolerance));
+        assert!(!plane.contains_point([2., 3., 1. + 2e-6], tolerance));
+        assert!(!plane.contains_point([2., 3., 1. - 2e-6], tolerance));
+    }
 }
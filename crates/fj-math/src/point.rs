@@ -63,6 +63,21 @@ impl<const D: usize> Point<D> {
     pub fn distance_to(&self, other: &Self) -> Scalar {
         (self.coords - other.coords).magnitude()
     }
+
+    /// Snap the point to a grid
+    ///
+    /// Rounds each coordinate to the nearest multiple of `resolution`. This can
+    /// be used to make output reproducible, for example before welding the
+    /// vertices of a mesh.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `resolution` is zero.
+    pub fn snap_to_grid(&self, resolution: impl Into<Scalar>) -> Self {
+        Self {
+            coords: self.coords.snap_to_grid(resolution),
+        }
+    }
 }
 
 impl ops::Deref for Point<1> {
@@ -214,6 +229,12 @@ impl<const D: usize> fmt::Debug for Point<D> {
     }
 }
 
+impl<const D: usize> fmt::Display for Point<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.coords, f)
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Point<D> {
     type Epsilon = <Vector<D> as approx::AbsDiffEq>::Epsilon;
 
@@ -225,3 +246,32 @@ impl<const D: usize> approx::AbsDiffEq for Point<D> {
         self.coords.abs_diff_eq(&other.coords, epsilon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Vector};
+
+    #[test]
+    fn origin() {
+        assert_eq!(Point::<2>::origin().coords, Vector::zero());
+        assert_eq!(Point::<4>::origin().coords, Vector::zero());
+    }
+
+    #[test]
+    fn display() {
+        let point = Point::from([1., 2., 3.]);
+        assert_eq!(format!("{point}"), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let point = Point::from([0.4999999, 1.0000001, -0.7]);
+        assert_eq!(point.snap_to_grid(0.25), Point::from([0.5, 1., -0.75]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn snap_to_zero_grid() {
+        Point::from([1., 2.]).snap_to_grid(0.);
+    }
+}
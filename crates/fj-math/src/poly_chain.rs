@@ -1,4 +1,4 @@
-use crate::{Point, Segment};
+use crate::{Point, Scalar, Segment};
 
 /// A polygonal chain
 ///
@@ -38,6 +38,32 @@ impl<const D: usize> PolyChain<D> {
         Self { points }
     }
 
+    /// Iterate over the points of the polygonal chain
+    pub fn points(&self) -> impl Iterator<Item = Point<D>> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Return the number of points in the polygonal chain
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Indicate whether the polygonal chain has no points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Indicate whether the polygonal chain is closed
+    ///
+    /// A polygonal chain is considered closed, if it has more than one point,
+    /// and its first and last points are the same. See [`PolyChain::close`].
+    pub fn is_closed(&self) -> bool {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => self.points.len() > 1 && first == last,
+            _ => false,
+        }
+    }
+
     /// Access the segments of the polygonal chain
     pub fn segments(&self) -> Vec<Segment<D>> {
         let mut segments = Vec::new();
@@ -73,6 +99,54 @@ impl<const D: usize> PolyChain<D> {
     }
 }
 
+impl PolyChain<3> {
+    /// Construct a polygonal chain that approximates a helix
+    ///
+    /// The helix winds counter-clockwise around the z-axis, starting at
+    /// `[radius, 0, 0]` and rising by `pitch` with every turn. Each turn is
+    /// approximated by `points_per_turn` segments. If `turns` is not a whole
+    /// number, the number of segments is rounded up, and the segments of the
+    /// last turn are spread out accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius`, `turns`, or `points_per_turn` are not positive.
+    pub fn helix(
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+        points_per_turn: u64,
+    ) -> Self {
+        let radius = radius.into();
+        let pitch = pitch.into();
+        let turns = turns.into();
+
+        assert!(radius > Scalar::ZERO, "Helix radius must be positive");
+        assert!(
+            turns > Scalar::ZERO,
+            "Number of helix turns must be positive"
+        );
+        assert!(
+            points_per_turn > 0,
+            "Helix needs at least one point per turn"
+        );
+
+        let num_segments = (turns * Scalar::from_u64(points_per_turn))
+            .ceil()
+            .into_u64();
+
+        let points = (0..=num_segments).map(|i| {
+            let t =
+                turns * Scalar::from_u64(i) / Scalar::from_u64(num_segments);
+            let (sin, cos) = (Scalar::TAU * t).sin_cos();
+
+            Point::from([cos * radius, sin * radius, t * pitch])
+        });
+
+        Self::from_points(points)
+    }
+}
+
 impl<P, Ps, const D: usize> From<Ps> for PolyChain<D>
 where
     P: Into<Point<D>>,
@@ -82,3 +156,49 @@ where
         Self::from_points(points)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb, Point, Scalar, Vector};
+
+    use super::PolyChain;
+
+    #[test]
+    fn points() {
+        let points = [[0., 0.], [1., 0.], [1., 1.]].map(Point::from);
+        let chain = PolyChain::from_points(points);
+
+        assert_eq!(chain.points().collect::<Vec<_>>(), points);
+        assert_eq!(chain.len(), 3);
+        assert!(!chain.is_empty());
+        assert!(!chain.is_closed());
+
+        let chain = chain.close();
+        assert_eq!(chain.len(), 4);
+        assert!(chain.is_closed());
+
+        assert!(PolyChain::<2>::new().is_empty());
+        assert!(!PolyChain::<2>::new().is_closed());
+    }
+
+    #[test]
+    fn helix() {
+        let helix = PolyChain::helix(2., 0.5, 2., 8);
+        assert_eq!(helix.len(), 17);
+
+        for point in helix.points() {
+            let radius = Vector::from([point.x, point.y]).magnitude();
+            assert!((radius - Scalar::TWO).abs() < Scalar::from(1e-12));
+        }
+
+        let aabb = Aabb::<3>::from_points(helix.points());
+        assert!((aabb.min.z - Scalar::ZERO).abs() < Scalar::from(1e-12));
+        assert!((aabb.max.z - Scalar::ONE).abs() < Scalar::from(1e-12));
+
+        // A fractional number of turns still ends after the full height.
+        let helix = PolyChain::helix(1., 1., 1.25, 4);
+        assert_eq!(helix.len(), 6);
+        let end = helix.points().last().unwrap();
+        assert!((end.z - Scalar::from(1.25)).abs() < Scalar::from(1e-12));
+    }
+}
@@ -3,17 +3,33 @@ use std::{
     f64::consts::{PI, TAU},
     fmt,
     hash::Hash,
+    num::ParseFloatError,
     ops,
+    str::FromStr,
 };
 
-use decorum::R64;
-
 /// A rational, finite scalar value
 ///
 /// This is a wrapper around `f64`. On construction, it checks that the `f64`
 /// value is not NaN. This allows `Scalar` to provide implementations of [`Eq`],
 /// [`Ord`], and [`Hash`], enabling `Scalar` (and types built on top of it), to
 /// be used as keys in hash maps, hash sets, and similar types.
+///
+/// # Canonical representation
+///
+/// Every `Scalar` is constructed through [`Scalar::from_f64`], which rejects
+/// NaN and normalizes negative zero to positive zero. As a result, two scalars
+/// are equal if and only if their bits are equal. This is the foundation for
+/// the implementations of [`Eq`], [`Ord`], and [`Hash`], which are consistent
+/// with each other: equal scalars have the same hash, and compare as equal.
+///
+/// The other types in this crate, like [`Point`], [`Vector`], or [`Aabb`],
+/// derive those traits. They inherit the same guarantees, comparing their
+/// components in order.
+///
+/// [`Point`]: crate::Point
+/// [`Vector`]: crate::Vector
+/// [`Aabb`]: crate::Aabb
 #[derive(Clone, Copy, Default)]
 #[repr(C)]
 pub struct Scalar(f64);
@@ -39,12 +55,17 @@ impl Scalar {
 
     /// Construct a `Scalar` from an `f64`
     ///
+    /// Negative zero is normalized to positive zero, so all zero values are
+    /// represented by the same bits.
+    ///
     /// # Panics
     ///
     /// Panics, if `scalar` is NaN.
     pub fn from_f64(scalar: f64) -> Self {
         if scalar.is_nan() {
             panic!("Invalid scalar value: {scalar}");
+        } else if scalar == 0. {
+            Self(0.)
         } else {
             Self(scalar)
         }
@@ -65,6 +86,14 @@ impl Scalar {
         self.0
     }
 
+    /// Access the raw bits of the underlying `f64`
+    ///
+    /// Since negative zero is normalized on construction, equal scalars always
+    /// have equal bits.
+    pub fn to_bits(self) -> u64 {
+        self.0.to_bits()
+    }
+
     /// Convert the scalar into a `u64`
     pub fn into_u64(self) -> u64 {
         self.0 as u64
@@ -103,11 +132,53 @@ impl Scalar {
         unreachable!("Sign is neither negative, nor positive, nor zero.")
     }
 
+    /// Compute the negation of the scalar
+    ///
+    /// This is equivalent to the [`ops::Neg`] implementation.
+    pub fn negated(self) -> Self {
+        -self
+    }
+
     /// Compute the absolute value of the scalar
     pub fn abs(self) -> Self {
         self.0.abs().into()
     }
 
+    /// Compute the midpoint between this and another scalar
+    ///
+    /// Unlike `(self + other) / 2.`, this doesn't overflow, if both scalars
+    /// have a large magnitude.
+    ///
+    /// If one of the scalars is infinite, the result is that scalar.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if one of the scalars is positive infinity, and the other is
+    /// negative infinity. There is no midpoint between them.
+    pub fn midpoint(self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+
+        if self == other {
+            return self;
+        }
+        if !self.0.is_finite() || !other.0.is_finite() {
+            assert!(
+                self.0.is_finite() || other.0.is_finite(),
+                "No midpoint between {self} and {other}"
+            );
+
+            return if self.0.is_finite() { other } else { self };
+        }
+
+        // If the signs are different, the sum can't overflow. If they are the
+        // same, the difference can't.
+        if self.is_negative() != other.is_negative() {
+            (self + other) / 2.
+        } else {
+            self + (other - self) / 2.
+        }
+    }
+
     /// Compute the maximum of this and another scalar
     pub fn max(self, other: impl Into<Self>) -> Self {
         self.0.max(other.into().0).into()
@@ -128,6 +199,18 @@ impl Scalar {
         self.0.round().into()
     }
 
+    /// Round the scalar to the nearest multiple of the provided scalar
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `multiple` is zero.
+    pub fn round_to_multiple(self, multiple: impl Into<Self>) -> Self {
+        let multiple = multiple.into();
+        assert!(multiple != Self::ZERO, "Can't round to multiple of zero");
+
+        (self / multiple).round() * multiple
+    }
+
     /// Compute the cosine
     pub fn cos(self) -> Self {
         self.0.cos().into()
@@ -148,6 +231,33 @@ impl Scalar {
     pub fn atan2(self, other: Self) -> Self {
         self.0.atan2(other.0).into()
     }
+
+    /// Compute the sum of the provided scalars, compensating for rounding
+    ///
+    /// Uses Neumaier's variant of Kahan summation. This is slower than naive
+    /// summation, but much more precise when summing many values of different
+    /// magnitudes and signs.
+    pub fn sum_stable(scalars: impl IntoIterator<Item = Self>) -> Self {
+        let mut sum = 0.;
+        let mut compensation = 0.;
+
+        for scalar in scalars {
+            let scalar = scalar.into_f64();
+            let next = sum + scalar;
+
+            // Whichever of the two is smaller lost its low-order bits in the
+            // addition. Recover them.
+            if f64::abs(sum) >= f64::abs(scalar) {
+                compensation += (sum - next) + scalar;
+            } else {
+                compensation += (scalar - next) + sum;
+            }
+
+            sum = next;
+        }
+
+        Self::from_f64(sum + compensation)
+    }
 }
 
 impl PartialEq for Scalar {
@@ -160,23 +270,32 @@ impl Eq for Scalar {}
 
 impl PartialOrd for Scalar {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.0.partial_cmp(&other.0)
+        Some(self.cmp(other))
     }
 }
 
+/// Compares scalars by their numerical value
+///
+/// This is a total order, which is consistent with the [`PartialEq`]
+/// implementation: Two scalars compare as equal, if and only if they are
+/// equal. This makes the iteration order of ordered collections of scalars
+/// (and of types built on top of them) deterministic.
+///
+/// The order is implemented using [`f64::total_cmp`]. That method orders
+/// negative zero before positive zero, and defines an order for NaN values.
+/// Neither can occur in a `Scalar`, as NaN is rejected and negative zero is
+/// normalized on construction, so the result is the regular numerical order.
 impl Ord for Scalar {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
-        // Should never panic, as `from_f64` checks that the wrapped value is
-        // finite.
-        self.partial_cmp(other).expect("Invalid `Scalar`")
+        self.0.total_cmp(&other.0)
     }
 }
 
 impl Hash for Scalar {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        // To the best of my knowledge, this matches the `PartialEq`
-        // implementation.
-        R64::from_inner(self.0).hash(state);
+        // Scalars have a canonical representation, so equal scalars have equal
+        // bits. See the documentation of `Scalar`.
+        self.to_bits().hash(state);
     }
 }
 
@@ -198,6 +317,20 @@ impl From<Scalar> for f64 {
     }
 }
 
+impl FromStr for Scalar {
+    type Err = ParseScalarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scalar = f64::from_str(s).map_err(ParseScalarError::Float)?;
+
+        if scalar.is_nan() {
+            return Err(ParseScalarError::NaN);
+        }
+
+        Ok(Self::from_f64(scalar))
+    }
+}
+
 impl ops::Neg for Scalar {
     type Output = Self;
 
@@ -589,6 +722,36 @@ impl approx::AbsDiffEq for Scalar {
     }
 }
 
+/// Error parsing a [`Scalar`] from a string
+///
+/// Returned by the [`FromStr`] implementation of [`Scalar`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseScalarError {
+    /// The string could not be parsed as a floating-point number
+    Float(ParseFloatError),
+
+    /// The string was parsed as NaN, which is not a valid `Scalar`
+    NaN,
+}
+
+impl fmt::Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Float(err) => write!(f, "Invalid scalar: {err}"),
+            Self::NaN => write!(f, "Invalid scalar: NaN is not allowed"),
+        }
+    }
+}
+
+impl std::error::Error for ParseScalarError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Float(err) => Some(err),
+            Self::NaN => None,
+        }
+    }
+}
+
 /// The sign of a [`Scalar`]
 ///
 /// See [`Scalar::sign`]
@@ -614,3 +777,171 @@ impl Sign {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Ordering,
+        collections::{hash_map::DefaultHasher, BTreeSet},
+        hash::{Hash, Hasher},
+    };
+
+    use super::{ParseScalarError, Scalar};
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(Scalar::from(1.).midpoint(3.), Scalar::from(2.));
+        assert_eq!(Scalar::from(-1.).midpoint(2.), Scalar::from(0.5));
+        assert_eq!(Scalar::from(3.).midpoint(-3.), Scalar::ZERO);
+
+        let large = Scalar::MAX;
+        assert_eq!(large.midpoint(large), large);
+        assert_eq!((-large).midpoint(-large), -large);
+        assert_eq!(large.midpoint(-large), Scalar::ZERO);
+        assert!(large.midpoint(large * 0.5).into_f64().is_finite());
+
+        let inf = Scalar::from(f64::INFINITY);
+        assert_eq!(inf.midpoint(inf), inf);
+        assert_eq!((-inf).midpoint(-inf), -inf);
+        assert_eq!(inf.midpoint(1.), inf);
+        assert_eq!(Scalar::from(-1.).midpoint(-inf), -inf);
+        assert_eq!(Scalar::ZERO.midpoint(inf), inf);
+    }
+
+    #[test]
+    #[should_panic]
+    fn midpoint_of_opposite_infinities() {
+        let inf = Scalar::from(f64::INFINITY);
+        inf.midpoint(-inf);
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!((-Scalar::ZERO).to_bits(), Scalar::ZERO.to_bits());
+        assert_eq!(Scalar::ZERO.negated().to_bits(), Scalar::ZERO.to_bits());
+        assert_eq!(Scalar::from(-0.).to_bits(), Scalar::ZERO.to_bits());
+        assert_eq!(
+            (Scalar::from(-1.) * Scalar::ZERO).to_bits(),
+            Scalar::ZERO.to_bits()
+        );
+        assert_eq!(Scalar::ONE.negated(), Scalar::from(-1.));
+    }
+
+    #[test]
+    fn total_order() {
+        let mut scalars =
+            [2., -0., f64::INFINITY, -1., 0., -f64::INFINITY, 1e-300, -0.]
+                .map(Scalar::from);
+        scalars.sort();
+
+        let expected =
+            [-f64::INFINITY, -1., 0., 0., 0., 1e-300, 2., f64::INFINITY]
+                .map(Scalar::from);
+        assert_eq!(scalars, expected);
+
+        // All zeros are the same, regardless of their sign. The order is
+        // consistent with equality.
+        let [a, b] = [0., -0.].map(Scalar::from);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        assert_eq!(a, b);
+
+        let set = [0., -0., 1., -1.]
+            .map(Scalar::from)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [-1., 0., 1.].map(Scalar::from),
+        );
+    }
+
+    #[test]
+    fn order_and_hash_are_consistent() {
+        let mut scalars = vec![
+            0.,
+            -0.,
+            1.,
+            -1.,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        // Add some pseudo-random values, built from arbitrary bit patterns,
+        // to cover all kinds of magnitudes. NaN is not a valid scalar, so
+        // it's skipped.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        while scalars.len() < 100 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let value = f64::from_bits(state);
+            if !value.is_nan() {
+                scalars.push(value);
+            }
+        }
+
+        // Equal values are the most interesting case for consistency, so add
+        // a few duplicates.
+        let duplicates = scalars[..10].to_vec();
+        scalars.extend(duplicates);
+
+        let scalars = scalars.into_iter().map(Scalar::from).collect::<Vec<_>>();
+
+        for a in &scalars {
+            for b in &scalars {
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+
+                if a == b {
+                    assert_eq!(hash(a), hash(b));
+                }
+
+                for c in &scalars {
+                    if a <= b && b <= c {
+                        assert!(a <= c);
+                    }
+                }
+            }
+        }
+
+        fn hash(scalar: &Scalar) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            scalar.hash(&mut hasher);
+            hasher.finish()
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("1.5".parse::<Scalar>(), Ok(Scalar::from(1.5)));
+        assert!(matches!(
+            "abc".parse::<Scalar>(),
+            Err(ParseScalarError::Float(_))
+        ));
+        assert_eq!("NaN".parse::<Scalar>(), Err(ParseScalarError::NaN));
+    }
+
+    #[test]
+    fn sum_stable() {
+        // Each repetition of this pattern sums to 2, but the small values get
+        // lost next to the large ones, if the sum is computed naively.
+        let n = 1000;
+        let scalars = [1., 1e100, 1., -1e100]
+            .into_iter()
+            .cycle()
+            .take(4 * n)
+            .map(Scalar::from);
+
+        let naive = scalars.clone().fold(Scalar::ZERO, |sum, s| sum + s);
+        assert_eq!(naive, Scalar::ZERO);
+
+        assert_eq!(Scalar::sum_stable(scalars), Scalar::from(2. * n as f64));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::Scalar;
+use crate::{Line, Scalar};
 
 use super::Point;
 
@@ -40,6 +40,51 @@ impl<const D: usize> Segment<D> {
         a + (b - a) / Scalar::TWO
     }
 
+    /// Compute the points on this and the other segment that are closest
+    ///
+    /// Returns the point on `self` first, then the point on `other`.
+    pub fn closest_points(&self, other: &Self) -> [Point<D>; 2] {
+        let [a, b] = self.points.map(|point| point.to_na());
+        let [c, d] = other.points.map(|point| point.to_na());
+
+        let locations = parry3d_f64::query::details::closest_points_segment_segment_with_locations_nD(
+            (&a, &b),
+            (&c, &d),
+        );
+
+        let [[a, b], [c, d]] = [self.points, other.points];
+        let [self_location, other_location] = [locations.0, locations.1]
+            .map(|location| location.barycentric_coordinates());
+
+        [
+            point_from_weights([a, b], self_location),
+            point_from_weights([c, d], other_location),
+        ]
+    }
+
+    /// Determine whether this segment is collinear with another one
+    ///
+    /// Returns `true`, if both segments lie on the same line, within the
+    /// provided tolerance. The segments don't need to overlap.
+    pub fn is_collinear_with(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        // Checking in both directions makes sure the result is symmetric, even
+        // if one of the segments is much shorter than the other.
+        [(self, other), (other, self)].into_iter().all(|(a, b)| {
+            let (line, _) = Line::from_points(a.points);
+            b.points.into_iter().all(|point| {
+                let projected = line
+                    .point_from_line_coords(line.point_to_line_coords(point));
+                point.distance_to(&projected) <= tolerance
+            })
+        })
+    }
+
     /// Create a segment with the same points in the opposite order
     pub fn reverse(mut self) -> Self {
         self.points.reverse();
@@ -48,6 +93,45 @@ impl<const D: usize> Segment<D> {
 }
 
 impl Segment<2> {
+    /// Merge this segment with another, collinear one
+    ///
+    /// Returns the union of both segments, if they are collinear (see
+    /// [`Segment::is_collinear_with`]) and overlap or touch, within the
+    /// provided tolerance. Returns `None` otherwise.
+    ///
+    /// The returned segment has the same direction as `self`.
+    pub fn merge(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> Option<Self> {
+        let tolerance = tolerance.into();
+
+        if !self.is_collinear_with(other, tolerance) {
+            return None;
+        }
+
+        // In the coordinates of this line, `self` covers the range `[0, 1]`.
+        let (line, _) = Line::from_points(self.points);
+        let tolerance =
+            tolerance / (self.points[1] - self.points[0]).magnitude();
+
+        let [c, d] = other
+            .points
+            .map(|point| (line.point_to_line_coords(point).t, point));
+        let [min, max] = if c.0 < d.0 { [c, d] } else { [d, c] };
+
+        if max.0 < -tolerance || min.0 > Scalar::ONE + tolerance {
+            return None;
+        }
+
+        let [a, b] = self.points;
+        let start = if min.0 < Scalar::ZERO { min.1 } else { a };
+        let end = if max.0 > Scalar::ONE { max.1 } else { b };
+
+        Some(Self::from_points([start, end]))
+    }
+
     /// Convert the 2-dimensional segment to a Parry segment
     pub fn to_parry(self) -> parry2d_f64::shape::Segment {
         self.points.map(|point| point.to_na()).into()
@@ -61,6 +145,15 @@ impl Segment<3> {
     }
 }
 
+fn point_from_weights<const D: usize>(
+    [a, b]: [Point<D>; 2],
+    [weight_a, weight_b]: [f64; 2],
+) -> Point<D> {
+    Point {
+        coords: a.coords * weight_a + b.coords * weight_b,
+    }
+}
+
 impl<P, const D: usize> From<[P; 2]> for Segment<D>
 where
     P: Into<Point<D>>,
@@ -75,3 +168,84 @@ impl<const D: usize> fmt::Debug for Segment<D> {
         write!(f, "[{:?} -> {:?}]", self.points[0], self.points[1])
     }
 }
+
+impl<const D: usize> fmt::Display for Segment<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} -> {}]", self.points[0], self.points[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Point;
+
+    use super::Segment;
+
+    #[test]
+    fn closest_points() {
+        let a = Segment::from([[0., 0., 0.], [2., 0., 0.]]);
+        let b = Segment::from([[1., 1., 1.], [1., 1., 3.]]);
+
+        assert_eq!(
+            a.closest_points(&b),
+            [Point::from([1., 0., 0.]), Point::from([1., 1., 1.])]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let segment = Segment::from([[0., 0., 0.], [1., 0., 0.]]);
+        assert_eq!(format!("{segment}"), "[(0, 0, 0) -> (1, 0, 0)]");
+    }
+
+    #[test]
+    fn collinear_overlapping() {
+        let a = Segment::from([[0., 0.], [2., 0.]]);
+        let b = Segment::from([[3., 0.], [1., 0.]]);
+
+        assert!(a.is_collinear_with(&b, 1e-8));
+        assert_eq!(
+            a.merge(&b, 1e-8),
+            Some(Segment::from([[0., 0.], [3., 0.]]))
+        );
+        assert_eq!(
+            b.merge(&a, 1e-8),
+            Some(Segment::from([[3., 0.], [0., 0.]]))
+        );
+
+        // Touching segments are merged too.
+        let c = Segment::from([[2., 0.], [4., 0.]]);
+        assert_eq!(
+            a.merge(&c, 1e-8),
+            Some(Segment::from([[0., 0.], [4., 0.]]))
+        );
+
+        // If one segment contains the other, the union is the larger one.
+        let d = Segment::from([[0.5, 0.], [1.5, 0.]]);
+        assert_eq!(a.merge(&d, 1e-8), Some(a));
+    }
+
+    #[test]
+    fn collinear_disjoint() {
+        let a = Segment::from([[0., 0.], [1., 1.]]);
+        let b = Segment::from([[2., 2.], [3., 3.]]);
+
+        assert!(a.is_collinear_with(&b, 1e-8));
+        assert_eq!(a.merge(&b, 1e-8), None);
+    }
+
+    #[test]
+    fn not_collinear() {
+        let a = Segment::from([[0., 0.], [2., 0.]]);
+        let b = Segment::from([[1., 0.], [3., 0.1]]);
+
+        assert!(!a.is_collinear_with(&b, 1e-8));
+        assert!(a.is_collinear_with(&b, 0.1));
+        assert_eq!(a.merge(&b, 1e-8), None);
+
+        // Parallel, but not on the same line.
+        let c = Segment::from([[0., 0., 1.], [2., 0., 1.]]);
+        let d = Segment::from([[0., 0., 0.], [2., 0., 0.]]);
+        assert!(!c.is_collinear_with(&d, 1e-8));
+    }
+}
@@ -1,8 +1,8 @@
-use std::ops;
+use std::{fmt, ops};
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{Circle, Ellipse, Line, PolyChain, Scalar};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -40,6 +40,88 @@ impl Transform {
         ))
     }
 
+    /// Construct a transform from an origin and three basis vectors
+    ///
+    /// The returned transform maps the origin to `origin`, and the standard
+    /// x-, y-, and z-axes to `x`, `y`, and `z`, respectively.
+    ///
+    /// The basis vectors don't need to be normalized or exactly orthogonal.
+    /// They are orthonormalized using the Gram-Schmidt process, in the order
+    /// `x`, `y`, `z`. Returns an error, if the basis is degenerate (one of the
+    /// vectors is zero or linearly dependent on the others), or if it is
+    /// left-handed.
+    pub fn from_basis(
+        origin: impl Into<Point<3>>,
+        x: impl Into<Vector<3>>,
+        y: impl Into<Vector<3>>,
+        z: impl Into<Vector<3>>,
+    ) -> Result<Self, FromBasisError> {
+        let origin = origin.into();
+        let [x, y, z] = [x.into(), y.into(), z.into()];
+
+        let orthonormalize = |v: Vector<3>, basis: &[Vector<3>]| {
+            let orthogonal = basis.iter().fold(v, |v, b| v - *b * v.dot(b));
+
+            // Comparing with the original magnitude makes this check
+            // independent of the scale of the input vectors.
+            if orthogonal.magnitude() <= v.magnitude() * 1e-8 {
+                return Err(FromBasisError::Degenerate);
+            }
+
+            Ok(orthogonal.normalize())
+        };
+
+        let x = orthonormalize(x, &[])?;
+        let y = orthonormalize(y, &[x])?;
+        let z = orthonormalize(z, &[x, y])?;
+
+        if x.cross(&y).dot(&z) < Scalar::ZERO {
+            return Err(FromBasisError::LeftHanded);
+        }
+
+        let rotation =
+            nalgebra::Matrix3::from_columns(&[x.to_na(), y.to_na(), z.to_na()]);
+
+        Ok(Self::translation(origin.coords)
+            * Self(nalgebra::Transform::from_matrix_unchecked(
+                rotation.to_homogeneous(),
+            )))
+    }
+
+    /// Construct a transform from a homogeneous matrix
+    ///
+    /// Expects the matrix in row-major order, as returned by
+    /// [`Transform::to_matrix`]. Returns an error, if the matrix doesn't
+    /// describe an isometry, meaning a combination of rotation and
+    /// translation. Matrices that deviate from that by less than `1e-8` per
+    /// element are accepted. Reflections are rejected.
+    pub fn from_matrix(matrix: [[f64; 4]; 4]) -> Result<Self, NonIsometry> {
+        const TOLERANCE: f64 = 1e-8;
+
+        let homogeneous = nalgebra::Matrix4::from_fn(|i, j| matrix[i][j]);
+        let linear = homogeneous.fixed_resize::<3, 3>(0.);
+
+        let is_orthonormal = (linear.transpose() * linear
+            - nalgebra::Matrix3::identity())
+        .amax()
+            <= TOLERANCE;
+        let is_rotation = linear.determinant() > 0.;
+        let is_affine = (homogeneous.row(3)
+            - nalgebra::RowVector4::new(0., 0., 0., 1.))
+        .amax()
+            <= TOLERANCE;
+
+        if !(is_orthonormal && is_rotation && is_affine) {
+            return Err(NonIsometry {
+                matrix: Box::new(matrix),
+            });
+        }
+
+        Ok(Self(nalgebra::Transform::from_matrix_unchecked(
+            homogeneous,
+        )))
+    }
+
     /// Construct a scaling
     pub fn scale(scaling_factor: f64) -> Self {
         Self(nalgebra::Transform::from_matrix_unchecked(
@@ -47,6 +129,24 @@ impl Transform {
         ))
     }
 
+    /// Apply a translation after this transform
+    ///
+    /// Returns a transform that first applies `self`, then the translation.
+    /// This is equivalent to `Transform::translation(offset) * self`.
+    pub fn then_translate(self, offset: impl Into<Vector<3>>) -> Self {
+        Self::translation(offset) * self
+    }
+
+    /// Apply a rotation after this transform
+    ///
+    /// Returns a transform that first applies `self`, then the rotation. This
+    /// is equivalent to `Transform::rotation(axis_angle) * self`.
+    ///
+    /// See [`Transform::rotation`] for the meaning of `axis_angle`.
+    pub fn then_rotate(self, axis_angle: impl Into<Vector<3>>) -> Self {
+        Self::rotation(axis_angle) * self
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -62,6 +162,32 @@ impl Transform {
         Vector::from(self.0.transform_vector(&vector.to_na()))
     }
 
+    /// Transform the given normal vector
+    ///
+    /// Unlike [`Transform::transform_vector`], this keeps the normal
+    /// perpendicular to any tangent vectors that are transformed along with it,
+    /// by applying the inverse-transpose of the transform's linear part. For a
+    /// transform that only rotates and translates, that is the same as just
+    /// rotating the normal.
+    ///
+    /// The returned normal is not normalized, if the transform involves
+    /// scaling.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform is not invertible.
+    pub fn transform_normal(&self, normal: &Vector<3>) -> Vector<3> {
+        let linear = self
+            .0
+            .matrix()
+            .fixed_resize::<3, 3>(0.)
+            .try_inverse()
+            .expect("Can't transform normal with non-invertible transform")
+            .transpose();
+
+        Vector::from(linear * normal.to_na())
+    }
+
     /// Transform the given line
     pub fn transform_line(&self, line: &Line<3>) -> Line<3> {
         Line::from_origin_and_direction(
@@ -86,6 +212,13 @@ impl Transform {
         ])
     }
 
+    /// Transform the given polygonal chain
+    pub fn transform_poly_chain(&self, chain: &PolyChain<3>) -> PolyChain<3> {
+        PolyChain::from_points(
+            chain.points().map(|point| self.transform_point(&point)),
+        )
+    }
+
     /// Transform the given circle
     pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
         Circle::new(
@@ -95,6 +228,24 @@ impl Transform {
         )
     }
 
+    /// Transform the given ellipse
+    pub fn transform_ellipse(&self, ellipse: &Ellipse<3>) -> Ellipse<3> {
+        Ellipse::new(
+            self.transform_point(&ellipse.center()),
+            self.transform_vector(&ellipse.a()),
+            self.transform_vector(&ellipse.b()),
+        )
+    }
+
+    /// Convert the transform into a homogeneous matrix
+    ///
+    /// The matrix is in row-major order, meaning `matrix[i][j]` is the element
+    /// in row `i` and column `j`. The translation is in the last column.
+    pub fn to_matrix(&self) -> [[f64; 4]; 4] {
+        let matrix = self.0.matrix();
+        [0, 1, 2, 3].map(|i| [0, 1, 2, 3].map(|j| matrix[(i, j)]))
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -165,13 +316,62 @@ impl ops::Mul<Self> for Transform {
     }
 }
 
+/// Error constructing a [`Transform`] from a basis
+///
+/// Returned by [`Transform::from_basis`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FromBasisError {
+    /// The basis vectors don't span the whole space
+    Degenerate,
+
+    /// The basis is left-handed
+    LeftHanded,
+}
+
+impl fmt::Display for FromBasisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Degenerate => write!(
+                f,
+                "Invalid basis: vectors are zero or linearly dependent"
+            ),
+            Self::LeftHanded => {
+                write!(f, "Invalid basis: expected right-handed basis")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromBasisError {}
+
+/// Error constructing a [`Transform`] from a matrix
+///
+/// Returned by [`Transform::from_matrix`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonIsometry {
+    /// The matrix that doesn't describe an isometry
+    pub matrix: Box<[[f64; 4]; 4]>,
+}
+
+impl fmt::Display for NonIsometry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid matrix: expected rotation and translation only: {:?}",
+            self.matrix
+        )
+    }
+}
+
+impl std::error::Error for NonIsometry {}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Circle, Line, Point, PolyChain, Scalar, Vector};
 
-    use super::Transform;
+    use super::{FromBasisError, NonIsometry, Transform};
 
     #[test]
     fn transform() {
@@ -194,6 +394,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transform_normal() {
+        let origin = Point::from([1., 2., 3.]);
+        let [u, v] = [[1., 0., 0.], [1., 1., 1.]].map(Vector::from);
+        let normal = u.cross(&v);
+
+        let transform = Transform::translation([1., 2., 3.])
+            * Transform::rotation(Vector::from([1., 1., 0.]))
+            * Transform::scale(2.);
+
+        let u = transform.transform_vector(&u);
+        let v = transform.transform_vector(&v);
+        let normal = transform.transform_normal(&normal);
+
+        // The normal stays perpendicular to the plane, and keeps pointing to
+        // the same side of it.
+        assert_abs_diff_eq!(
+            normal.dot(&u),
+            Scalar::ZERO,
+            epsilon = Scalar::from(1e-8)
+        );
+        assert_abs_diff_eq!(
+            normal.dot(&v),
+            Scalar::ZERO,
+            epsilon = Scalar::from(1e-8)
+        );
+        assert!(normal.dot(&u.cross(&v)) > Scalar::ZERO);
+
+        // The translation doesn't affect the normal.
+        let translated = Transform::translation(origin.coords)
+            .transform_normal(&Vector::unit_z());
+        assert_eq!(translated, Vector::unit_z());
+    }
+
+    #[test]
+    fn transform_poly_chain() {
+        let chain =
+            PolyChain::from_points([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]])
+                .close();
+
+        let transform =
+            Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.));
+        let transformed = transform.transform_poly_chain(&chain);
+
+        assert_eq!(transformed.len(), chain.len());
+        assert!(transformed.is_closed());
+
+        let expected =
+            [[0., 1., 0.], [-1., 0., 0.], [0., 0., 1.], [0., 1., 0.]];
+        for (point, expected) in transformed.points().zip(expected) {
+            assert_abs_diff_eq!(
+                point,
+                Point::from(expected),
+                epsilon = Scalar::from(1e-8),
+            );
+        }
+    }
+
+    #[test]
+    fn transform_circle() {
+        let circle = Circle::from_center_and_radius([1., 0., 0.], 2.);
+
+        let transform = Transform::translation([0., 0., 3.])
+            * Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.));
+        let transformed = transform.transform_circle(&circle);
+
+        assert_abs_diff_eq!(
+            transformed.center(),
+            Point::from([0., 1., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(transformed.radius(), Scalar::from(2.));
+        for t in [0., 1., 2.] {
+            assert_abs_diff_eq!(
+                transformed.point_from_circle_coords([t]),
+                transform
+                    .transform_point(&circle.point_from_circle_coords([t])),
+                epsilon = Scalar::from(1e-8),
+            );
+        }
+    }
+
+    #[test]
+    fn then_translate_then_rotate() {
+        let axis_angle = Vector::unit_z() * (Scalar::PI / 2.);
+        let offset = [1., 2., 3.];
+
+        let chained = Transform::identity()
+            .then_rotate(axis_angle)
+            .then_translate(offset);
+        let manual =
+            Transform::translation(offset) * Transform::rotation(axis_angle);
+
+        assert_abs_diff_eq!(chained.data(), manual.data(), epsilon = 1e-8);
+
+        // The rotation is applied first, then the translation.
+        assert_abs_diff_eq!(
+            chained.transform_point(&Point::from([1., 0., 0.])),
+            Point::from([1., 3., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
@@ -224,4 +527,91 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn from_basis() {
+        let origin = Point::from([1., 2., 3.]);
+        let x = Vector::from([0., 1., 0.]);
+        let y = Vector::from([-1., 0., 0.]);
+        let z = Vector::from([0., 0., 1.]);
+
+        let transform = Transform::from_basis(origin, x, y, z).unwrap();
+
+        assert_abs_diff_eq!(
+            transform.transform_vector(&Vector::unit_x()),
+            x,
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([1., 0., 0.])),
+            origin + x,
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            transform.data(),
+            (Transform::translation(origin.coords)
+                * Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.)))
+            .data(),
+            epsilon = 1e-8,
+        );
+
+        // Non-orthonormal bases are orthonormalized.
+        let transform =
+            Transform::from_basis(origin, x * 2., [-1., 1., 0.], [1., 1., 3.])
+                .unwrap();
+        assert_abs_diff_eq!(
+            transform.transform_vector(&Vector::unit_y()),
+            y,
+            epsilon = Scalar::from(1e-8),
+        );
+
+        assert_eq!(
+            Transform::from_basis(origin, x, x * 2., z).unwrap_err(),
+            FromBasisError::Degenerate,
+        );
+        assert_eq!(
+            Transform::from_basis(origin, x, [0., 0., 0.], z).unwrap_err(),
+            FromBasisError::Degenerate,
+        );
+        assert_eq!(
+            Transform::from_basis(origin, x, y, -z).unwrap_err(),
+            FromBasisError::LeftHanded,
+        );
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let transform = Transform::translation([1., 2., 3.])
+            * Transform::rotation(Vector::from([1., 2., 3.]).normalize() * 0.5);
+
+        let matrix = transform.to_matrix();
+        assert_eq!(matrix[3], [0., 0., 0., 1.]);
+        assert_eq!([matrix[0][3], matrix[1][3], matrix[2][3]], [1., 2., 3.]);
+
+        let round_tripped = Transform::from_matrix(matrix).unwrap();
+        let point = Point::from([3., -1., 2.]);
+        assert_eq!(
+            round_tripped.transform_point(&point),
+            transform.transform_point(&point),
+        );
+    }
+
+    #[test]
+    fn matrix_non_isometry() {
+        let scale = Transform::scale(2.).to_matrix();
+        assert_eq!(
+            Transform::from_matrix(scale).unwrap_err(),
+            NonIsometry {
+                matrix: Box::new(scale)
+            }
+        );
+
+        let mut reflection = Transform::identity().to_matrix();
+        reflection[0][0] = -1.;
+        assert!(Transform::from_matrix(reflection).is_err());
+
+        let mut projection = Transform::identity().to_matrix();
+        projection[3][2] = 1.;
+        assert!(Transform::from_matrix(projection).is_err());
+    }
 }
@@ -1,4 +1,6 @@
-use parry3d_f64::query::{Ray, RayCast as _};
+use std::fmt;
+
+use parry3d_f64::query::{PointQuery as _, Ray, RayCast as _};
 
 use crate::Vector;
 
@@ -41,6 +43,14 @@ impl<const D: usize> Triangle<D> {
         self.points
     }
 
+    /// Compute the centroid of the triangle
+    ///
+    /// This is the average of the triangle's points.
+    pub fn centroid(&self) -> Point<D> {
+        let [a, b, c] = self.points;
+        a + ((b - a) + (c - a)) / 3.
+    }
+
     /// Normalize the triangle
     ///
     /// Returns a new `Triangle` instance with the same points, but the points
@@ -53,11 +63,26 @@ impl<const D: usize> Triangle<D> {
         self.points.sort();
         self
     }
+
+    /// Reverse the triangle
+    ///
+    /// Returns a new `Triangle` instance with the same points, but in reverse
+    /// winding order.
+    pub fn reverse(mut self) -> Self {
+        self.points.swap(1, 2);
+        self
+    }
 }
 
 impl Triangle<2> {
     /// Returns the direction of the line through the points of the triangle.
-    pub fn winding(&self) -> Winding {
+    ///
+    /// Returns `None`, if the points of the triangle are collinear. The
+    /// constructor rejects triangles that don't span any area, but it can't
+    /// catch all cases where rounding errors hide the collinearity. This method
+    /// uses robust orientation predicates, so the result is exact for the
+    /// provided coordinates.
+    pub fn winding(&self) -> Option<Winding> {
         let [pa, pb, pc] = self.points.map(|point| robust::Coord {
             x: point.u,
             y: point.v,
@@ -65,16 +90,82 @@ impl Triangle<2> {
         let orient2d = robust::orient2d(pa, pb, pc);
 
         if orient2d < 0. {
-            return Winding::Cw;
+            return Some(Winding::Cw);
         }
         if orient2d > 0. {
-            return Winding::Ccw;
+            return Some(Winding::Ccw);
+        }
+
+        None
+    }
+
+    /// Compute the barycentric coordinates of a point
+    ///
+    /// The barycentric coordinates are the weights of the triangle's points
+    /// that, when summed, reproduce the provided point.
+    ///
+    /// Returns `None`, if the triangle is too degenerate for the coordinates to
+    /// be computed.
+    pub fn barycentric(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Option<[Scalar; 3]> {
+        let point = point.into();
+        let [a, b, c] = self.points;
+
+        let area = (b - a).cross2d(&(c - a));
+        if area == Scalar::ZERO {
+            return None;
+        }
+
+        let weight_a = (b - point).cross2d(&(c - point)) / area;
+        let weight_b = (c - point).cross2d(&(a - point)) / area;
+        let weight_c = Scalar::ONE - weight_a - weight_b;
+
+        Some([weight_a, weight_b, weight_c])
+    }
+
+    /// Compute the center of the triangle's circumscribed circle
+    ///
+    /// Returns `None`, if the triangle is too degenerate for the center to be
+    /// computed.
+    pub fn circumcenter(&self) -> Option<Point<2>> {
+        let [a, b, c] = self.points;
+        let [ab, ac] = [b - a, c - a];
+
+        let denominator = ab.cross2d(&ac) * 2.;
+        if denominator == Scalar::ZERO {
+            return None;
         }
 
-        unreachable!(
-            "Points don't form a triangle, but this was verified in the \
-            constructor."
-        )
+        let [ab_squared, ac_squared] = [ab.dot(&ab), ac.dot(&ac)];
+        let offset = Vector::from([
+            (ac.v * ab_squared - ab.v * ac_squared) / denominator,
+            (ab.u * ac_squared - ac.u * ab_squared) / denominator,
+        ]);
+
+        Some(a + offset)
+    }
+
+    /// Indicate whether the triangle contains the provided point
+    ///
+    /// Points on the boundary of the triangle are considered to be contained.
+    /// Uses robust orientation predicates, so the result is exact for the
+    /// provided coordinates.
+    pub fn contains(&self, point: impl Into<Point<2>>) -> bool {
+        let point = point.into();
+        let [a, b, c] = self.points;
+
+        let orientations = [[a, b], [b, c], [c, a]].map(|[p, q]| {
+            let [p, q, point] = [p, q, point].map(|point| robust::Coord {
+                x: point.u,
+                y: point.v,
+            });
+            robust::orient2d(p, q, point)
+        });
+
+        orientations.iter().all(|&orientation| orientation >= 0.)
+            || orientations.iter().all(|&orientation| orientation <= 0.)
     }
 }
 
@@ -110,6 +201,85 @@ impl Triangle<3> {
             .into_inner()
             .into()
     }
+
+    /// Returns the winding of the triangle, as seen from the provided normal
+    ///
+    /// The winding is counter-clockwise, if the triangle's normal points in the
+    /// same direction as `normal`, and clockwise if it points the other way.
+    ///
+    /// Returns `None`, if the triangle's normal is perpendicular to `normal`,
+    /// meaning the winding can't be determined.
+    pub fn winding_relative_to(&self, normal: &Vector<3>) -> Option<Winding> {
+        let [a, b, c] = self.points;
+        let dot = (b - a).cross(&(c - a)).dot(normal);
+
+        if dot < Scalar::ZERO {
+            return Some(Winding::Cw);
+        }
+        if dot > Scalar::ZERO {
+            return Some(Winding::Ccw);
+        }
+
+        None
+    }
+
+    /// Compute the point on the triangle that is closest to the provided point
+    pub fn closest_point(&self, point: impl Into<Point<3>>) -> Point<3> {
+        let point = point.into();
+
+        let projection =
+            self.to_parry().project_local_point(&point.to_na(), true);
+        if projection.is_inside {
+            return point;
+        }
+
+        Point::from_na(projection.point)
+    }
+
+    /// Indicate whether the point is contained in the triangle
+    ///
+    /// First checks whether the point is within `tolerance` of the triangle's
+    /// plane. If it is, the point and the triangle are projected into 2D, and
+    /// [`Triangle::<2>::contains`] is used for the rest of the check.
+    pub fn project_and_contains(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        let normal = self.normal();
+        let [a, _, _] = self.points;
+
+        let distance_to_plane = (point - a).dot(&normal).abs();
+        if distance_to_plane > tolerance {
+            return false;
+        }
+
+        // Project into 2D by dropping the coordinate where the normal is
+        // largest. This is the projection that distorts the triangle the least,
+        // and it guarantees that the projected triangle is not degenerate.
+        let [x, y, z] = normal.components.map(|component| component.abs());
+        let project = |point: Point<3>| -> Point<2> {
+            let [px, py, pz] = point.coords.components;
+
+            if x >= y && x >= z {
+                Point::from([py, pz])
+            } else if y >= z {
+                Point::from([pz, px])
+            } else {
+                Point::from([px, py])
+            }
+        };
+
+        let Ok(triangle) = Triangle::from_points(self.points.map(project))
+        else {
+            return false;
+        };
+
+        triangle.contains(project(point))
+    }
 }
 
 impl<P, const D: usize> From<[P; 3]> for Triangle<D>
@@ -121,6 +291,13 @@ where
     }
 }
 
+impl<const D: usize> fmt::Display for Triangle<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c] = self.points;
+        write!(f, "[{a}, {b}, {c}]")
+    }
+}
+
 /// Returned by [`Triangle::from_points`], if the points don't form a triangle
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct NotATriangle<const D: usize> {
@@ -138,9 +315,9 @@ pub enum Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use crate::{assert_abs_diff, Point, Scalar, Vector};
 
-    use super::Triangle;
+    use super::{Triangle, Winding};
 
     #[test]
     fn valid_triangle_2d() {
@@ -176,10 +353,157 @@ mod tests {
         let _triangle = Triangle::from([a, b, c]);
     }
 
+    #[test]
+    fn centroid() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [3., 0., 3.], [0., 6., -3.]]);
+        assert_eq!(triangle.centroid(), Point::from([1., 2., 0.]));
+    }
+
+    #[test]
+    fn circumcenter() {
+        // The circumcenter of a right triangle lies on the midpoint of its
+        // hypotenuse.
+        let triangle = Triangle::from([[1., 1.], [5., 1.], [1., 4.]]);
+        assert_eq!(triangle.circumcenter(), Some(Point::from([3., 2.5])));
+
+        let triangle = Triangle::from([[0., 0.], [2., 0.], [1., 3.]]);
+        let center = triangle.circumcenter().unwrap();
+        let [a, b, c] =
+            triangle.points().map(|point| (point - center).magnitude());
+        assert_abs_diff!(a, b);
+        assert_abs_diff!(a, c);
+    }
+
     #[test]
     fn normal() {
         let triangle =
             Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
         assert_eq!(triangle.normal(), Vector::from([0.0, 0.0, -1.0]));
     }
+
+    #[test]
+    fn winding() {
+        let triangle = Triangle::from([[0., 0.], [1., 0.], [0., 1.]]);
+        assert_eq!(triangle.winding(), Some(Winding::Ccw));
+
+        let triangle = Triangle::from([[0., 0.], [0., 1.], [1., 0.]]);
+        assert_eq!(triangle.winding(), Some(Winding::Cw));
+    }
+
+    #[test]
+    fn winding_collinear() {
+        // These points are exactly collinear, but a naive computation of the
+        // area has a rounding error, so the constructor accepts them.
+        let triangle = Triangle::from([
+            [1.6872533960792668, 3.2206891640755995],
+            [-1.6847161656785703, 4.673102367853501],
+            [-8.428655289194245, 7.577928775409303],
+        ]);
+        assert_eq!(triangle.winding(), None);
+    }
+
+    #[test]
+    fn winding_relative_to() {
+        let points = [[0., 0.], [2., 1.], [0., 1.]];
+
+        let triangle_2d = Triangle::<2>::from(points);
+        let triangle_3d = Triangle::<3>::from(
+            points.map(|point| Point::from(point).to_xyz()),
+        );
+
+        assert_eq!(
+            triangle_3d.winding_relative_to(&Vector::unit_z()),
+            triangle_2d.winding(),
+        );
+        assert_eq!(
+            triangle_3d.winding_relative_to(&-Vector::unit_z()),
+            Some(Winding::Cw),
+        );
+        assert_eq!(triangle_3d.winding_relative_to(&Vector::unit_x()), None);
+    }
+
+    #[test]
+    fn barycentric() {
+        let triangle = Triangle::from([[0., 0.], [1., 0.], [0., 1.]]);
+
+        let [a, b, c] = triangle.barycentric([0.25, 0.5]).unwrap();
+        assert_eq!([a, b, c], [0.25, 0.25, 0.5].map(Scalar::from));
+
+        let [a, b, c] = triangle.barycentric([0., 0.]).unwrap();
+        assert_eq!([a, b, c], [1., 0., 0.].map(Scalar::from));
+    }
+
+    #[test]
+    fn contains() {
+        let triangle = Triangle::from([[0., 0.], [2., 0.], [0., 2.]]);
+
+        // interior
+        assert!(triangle.contains([0.5, 0.5]));
+
+        // exterior
+        assert!(!triangle.contains([2., 2.]));
+        assert!(!triangle.contains([-0.5, 0.5]));
+
+        // on edge
+        assert!(triangle.contains([1., 0.]));
+        assert!(triangle.contains([1., 1.]));
+
+        // on vertex
+        assert!(triangle.contains([0., 0.]));
+        assert!(triangle.contains([2., 0.]));
+    }
+
+    #[test]
+    fn contains_sliver() {
+        let triangle = Triangle::from([[0.1, 0.1], [12.3, 4.7], [6.2, 2.5]]);
+
+        // This point is very slightly to the left of the triangle's first
+        // edge, which means it is inside the triangle. A naive computation of
+        // the cross product gets the sign wrong.
+        let point = Point::from([5.379758228441651, 2.0907285123632455]);
+
+        let [a, b, _] = triangle.points();
+        let naive = (b - a).cross2d(&(point - a));
+        assert!(naive < Scalar::ZERO);
+
+        assert!(triangle.contains(point));
+    }
+
+    #[test]
+    fn project_and_contains() {
+        let triangle =
+            Triangle::from([[0., 0., 1.], [2., 0., 1.], [0., 2., 1.]]);
+
+        assert!(triangle.project_and_contains([0.5, 0.5, 1.], 1e-9));
+        assert!(triangle.project_and_contains([0.5, 0.5, 1. + 1e-12], 1e-9));
+        assert!(!triangle.project_and_contains([0.5, 0.5, 1.1], 1e-9));
+        assert!(!triangle.project_and_contains([2., 2., 1.], 1e-9));
+    }
+
+    #[test]
+    fn closest_point() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]]);
+
+        assert_eq!(
+            triangle.closest_point([0.5, 0.5, 2.5]),
+            Point::from([0.5, 0.5, 0.])
+        );
+        assert_eq!(
+            triangle.closest_point([-1., -1., 0.]),
+            Point::from([0., 0., 0.])
+        );
+        assert_eq!(
+            triangle.closest_point([0.5, 0.5, 0.]),
+            Point::from([0.5, 0.5, 0.])
+        );
+    }
+
+    #[test]
+    fn display() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        assert_eq!(format!("{triangle}"), "[(0, 0, 0), (1, 0, 0), (0, 1, 0)]");
+    }
 }
@@ -24,6 +24,32 @@ impl<const D: usize> Vector<D> {
         }
     }
 
+    /// Construct a vector whose components are all zero
+    pub fn zero() -> Self {
+        Self::from_component(Scalar::ZERO)
+    }
+
+    /// Construct a vector whose components are all one
+    pub fn ones() -> Self {
+        Self::from_component(Scalar::ONE)
+    }
+
+    /// Construct the `i`-th basis vector
+    ///
+    /// The `i`-th component of the returned vector is one, all others are
+    /// zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `i` is not smaller than the dimension of the vector.
+    pub fn unit(i: usize) -> Self {
+        assert!(i < D, "Basis vector {i} doesn't exist in {D} dimensions");
+
+        let mut vector = Self::zero();
+        vector.components[i] = Scalar::ONE;
+        vector
+    }
+
     /// Convert the vector into an nalgebra vector
     pub fn to_na(self) -> nalgebra::SVector<f64, D> {
         self.components.map(Scalar::into_f64).into()
@@ -98,6 +124,86 @@ impl<const D: usize> Vector<D> {
 
         self.dot(&other.normalize())
     }
+
+    /// Compute the sum of the provided vectors, compensating for rounding
+    ///
+    /// Sums each component using [`Scalar::sum_stable`].
+    pub fn sum_stable(vectors: impl IntoIterator<Item = Self>) -> Self {
+        let vectors = vectors.into_iter().collect::<Vec<_>>();
+
+        let components = std::array::from_fn(|i| {
+            Scalar::sum_stable(vectors.iter().map(|v| v.components[i]))
+        });
+
+        Self { components }
+    }
+
+    /// Reflect the vector across the plane defined by the provided normal
+    ///
+    /// `normal` is expected to be of unit length. Passing a non-normalized
+    /// vector leads to a result that is scaled incorrectly.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (self.dot(normal) * 2.)
+    }
+
+    /// Compute a vector with the absolute values of this vector's components
+    pub fn abs(&self) -> Self {
+        Self {
+            components: self.components.map(Scalar::abs),
+        }
+    }
+
+    /// Snap the vector to a grid
+    ///
+    /// Rounds each component to the nearest multiple of `resolution`. See
+    /// [`Scalar::round_to_multiple`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `resolution` is zero.
+    pub fn snap_to_grid(&self, resolution: impl Into<Scalar>) -> Self {
+        let resolution = resolution.into();
+
+        Self {
+            components: self
+                .components
+                .map(|component| component.round_to_multiple(resolution)),
+        }
+    }
+
+    /// Find the largest component of the vector
+    ///
+    /// Returns the index of the component, along with its value. If multiple
+    /// components share the largest value, the first one is returned. To find
+    /// the component with the largest magnitude, use this method on the result
+    /// of [`Vector::abs`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the vector is 0-dimensional.
+    pub fn max_component(&self) -> (usize, Scalar) {
+        self.components
+            .into_iter()
+            .enumerate()
+            .reduce(|max, c| if c.1 > max.1 { c } else { max })
+            .expect("0-dimensional vector has no components")
+    }
+
+    /// Find the smallest component of the vector
+    ///
+    /// Returns the index of the component, along with its value. If multiple
+    /// components share the smallest value, the first one is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the vector is 0-dimensional.
+    pub fn min_component(&self) -> (usize, Scalar) {
+        self.components
+            .into_iter()
+            .enumerate()
+            .reduce(|min, c| if c.1 < min.1 { c } else { min })
+            .expect("0-dimensional vector has no components")
+    }
 }
 
 impl Vector<1> {
@@ -128,6 +234,23 @@ impl Vector<2> {
         let [a, b] = others.map(Into::into);
         a.cross2d(self) * b.cross2d(self) < Scalar::ZERO
     }
+
+    /// Compute the signed angle from this vector to another
+    ///
+    /// The angle is positive, if `other` is reached by turning counter-
+    /// clockwise, negative otherwise. It is in the range `(-π, π]`, meaning
+    /// antiparallel vectors result in `π`.
+    pub fn signed_angle_to(&self, other: &Self) -> Scalar {
+        let angle = self.cross2d(other).atan2(self.dot(other));
+
+        // `atan2` returns `-π` for a negative zero as its first argument. Make
+        // sure to stay within the documented range.
+        if angle == -Scalar::PI {
+            return Scalar::PI;
+        }
+
+        angle
+    }
 }
 
 impl Vector<3> {
@@ -151,6 +274,28 @@ impl Vector<3> {
         self.to_na().cross(&other.to_na()).into()
     }
 
+    /// Compute two vectors that form an orthonormal basis with this one
+    ///
+    /// Returns two unit vectors that are orthogonal to each other and to this
+    /// vector. Together with the normalized vector, they form a right-handed
+    /// basis. The result is deterministic, with no special cases for vectors
+    /// that are aligned with an axis.
+    ///
+    /// Uses the method from "Building an Orthonormal Basis, Revisited" by
+    /// Duff et al.
+    pub fn orthonormal_basis(&self) -> (Vector<3>, Vector<3>) {
+        let [x, y, z] = self.normalize().components.map(Scalar::into_f64);
+
+        let sign = 1_f64.copysign(z);
+        let a = -1. / (sign + z);
+        let b = x * y * a;
+
+        let u = Self::from([1. + sign * x * x * a, sign * b, -sign * x]);
+        let v = Self::from([b, sign + y * y * a, -y]);
+
+        (u, v)
+    }
+
     /// Construct a new vector from this vector's x and y components
     pub fn xy(&self) -> Vector<2> {
         Vector::from([self.x, self.y])
@@ -336,6 +481,21 @@ impl<const D: usize> fmt::Debug for Vector<D> {
     }
 }
 
+impl<const D: usize> fmt::Display for Vector<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{component}")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Vector<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
@@ -350,7 +510,37 @@ impl<const D: usize> approx::AbsDiffEq for Vector<D> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Scalar, Vector};
+    use crate::{assert_abs_diff, Scalar, Vector};
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Vector::<3>::zero(), Vector::from([0., 0., 0.]));
+        assert_eq!(Vector::<2>::ones(), Vector::from([1., 1.]));
+
+        assert_eq!(Vector::<1>::unit(0), Vector::unit_t());
+        assert_eq!(Vector::<2>::unit(1), Vector::unit_v());
+        assert_eq!(Vector::<3>::unit(0), Vector::unit_x());
+        assert_eq!(Vector::<3>::unit(2), Vector::unit_z());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unit_out_of_bounds() {
+        Vector::<3>::unit(5);
+    }
+
+    #[test]
+    fn signed_angle_to() {
+        let u = Vector::unit_u();
+        let v = Vector::unit_v();
+
+        assert_abs_diff!(u.signed_angle_to(&v), Scalar::PI / 2.);
+        assert_abs_diff!(v.signed_angle_to(&u), -Scalar::PI / 2.);
+        assert_abs_diff!(u.signed_angle_to(&(u * 2.)), Scalar::ZERO);
+
+        assert_eq!(u.signed_angle_to(&-u), Scalar::PI);
+        assert_eq!(u.signed_angle_to(&Vector::from([-1., -0.])), Scalar::PI);
+    }
 
     #[test]
     fn to_uv() {
@@ -393,6 +583,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sum_stable() {
+        let vectors = [[1., -1.], [1e100, 1e100], [1., -1.], [-1e100, -1e100]]
+            .into_iter()
+            .cycle()
+            .take(4000)
+            .map(Vector::from);
+
+        assert_eq!(Vector::sum_stable(vectors), Vector::from([2000., -2000.]));
+    }
+
+    #[test]
+    fn reflect() {
+        let v = Vector::from([1., -1., 0.]);
+
+        assert_eq!(v.reflect(&Vector::unit_y()), Vector::from([1., 1., 0.]));
+        assert_eq!(v.reflect(&Vector::unit_x()), Vector::from([-1., -1., 0.]));
+        assert_eq!(v.reflect(&Vector::unit_z()), v);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(
+            Vector::from([-1., 2., -0.5]).abs(),
+            Vector::from([1., 2., 0.5]),
+        );
+    }
+
+    #[test]
+    fn max_min_component() {
+        let v = Vector::from([0.1, -0.3, 0.9]);
+        assert_eq!(v.max_component(), (2, Scalar::from(0.9)));
+        assert_eq!(v.min_component(), (1, Scalar::from(-0.3)));
+
+        // The dominant axis of a vector is the one with the largest magnitude.
+        let normal = Vector::from([0.2, -0.9, 0.3]);
+        assert_eq!(normal.abs().max_component(), (1, Scalar::from(0.9)));
+
+        // Ties are resolved in favor of the first component.
+        let v = Vector::from([1., 1., 0.]);
+        assert_eq!(v.max_component(), (0, Scalar::ONE));
+        assert_eq!(Vector::from([1., 0., 0.]).min_component().0, 1);
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        let directions = [
+            [0., 0., 1.],
+            [0., 0., -1.],
+            [1., 0., 0.],
+            [0., -1., 0.],
+            [1e-9, 0., -1.],
+            [0., 1e-9, 1.],
+            [1., 2., 3.],
+            [-3., 0.5, -2.],
+        ];
+
+        for direction in directions {
+            let n = Vector::from(direction);
+            let (u, v) = n.orthonormal_basis();
+            let n = n.normalize();
+
+            for vector in [u, v] {
+                assert_abs_diff!(vector.magnitude(), Scalar::ONE);
+                assert_abs_diff!(vector.dot(&n), Scalar::ZERO);
+            }
+            assert_abs_diff!(u.dot(&v), Scalar::ZERO);
+            assert_abs_diff!(u.cross(&v), n);
+        }
+    }
+
     #[test]
     fn is_between() {
         let v = Vector::from([1., 1.]);
@@ -401,4 +662,10 @@ mod tests {
         assert!(!v.is_between([[1., 0.], [0., -1.]]));
         assert!(!v.is_between([[-1., 0.], [0., 1.]]));
     }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Vector::from([1., 2.5])), "(1, 2.5)");
+        assert_eq!(format!("{}", Vector::from([1., 2., 3.])), "(1, 2, 3)");
+    }
 }
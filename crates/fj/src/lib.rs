@@ -13,10 +13,12 @@
 
 mod args;
 mod handle_model;
+mod to_mesh;
 
 pub use self::{
     args::Args,
     handle_model::{handle_model, Error, Result},
+    to_mesh::to_mesh,
 };
 
 pub use fj_core as core;
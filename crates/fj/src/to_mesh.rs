@@ -0,0 +1,81 @@
+use fj_core::{
+    algorithms::{
+        approx::{InvalidTolerance, Tolerance},
+        triangulate::Triangulate,
+    },
+    validate::ValidationConfig,
+};
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+/// Convert a model into a triangle mesh
+///
+/// Approximates the model using the provided tolerance, and triangulates the
+/// approximation. Vertices that are nearly coincident are welded, so adjacent
+/// faces of the model result in a mesh without cracks, that is ready to be
+/// exported.
+///
+/// Returns an error, if the tolerance is not valid.
+pub fn to_mesh<M>(
+    model: &M,
+    tolerance: impl Into<Scalar>,
+) -> Result<Mesh<Point<3>>, InvalidTolerance>
+where
+    for<'r> (&'r M, Tolerance): Triangulate,
+{
+    let tolerance = Tolerance::from_scalar(tolerance)?;
+
+    let weld_tolerance = ValidationConfig::default().identical_max_distance;
+    let mut mesh = Mesh::with_weld_tolerance(weld_tolerance);
+    (model, tolerance).triangulate_into_mesh(&mut mesh);
+
+    Ok(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{
+        algorithms::sweep::Sweep,
+        objects::{Cycle, Region, Sketch},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, Reverse,
+            UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+    };
+    use fj_math::Point;
+
+    use super::to_mesh;
+
+    #[test]
+    fn spacer() {
+        let mut services = Services::new();
+
+        // This is the same as the `spacer` model.
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .add_interiors([Cycle::circle(
+                        Point::origin(),
+                        0.5,
+                        &mut services,
+                    )
+                    .reverse(&mut services)
+                    .insert(&mut services)])
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let spacer = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let mesh = to_mesh(&*spacer, 0.01).unwrap();
+        assert!(mesh.triangles().next().is_some());
+
+        let half_edges = mesh.build_half_edge_structure().unwrap();
+        assert!(half_edges.boundary_loops().is_empty());
+
+        assert!(to_mesh(&*spacer, 0.).is_err());
+
+        services.drop_and_validate().unwrap();
+    }
+}